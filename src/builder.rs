@@ -0,0 +1,178 @@
+use crate::meeting::{Meeting, MeetingError};
+use crate::model::EmployeeCategory;
+
+/// Chainable construction of a [`Meeting`] with attendees and settings.
+///
+/// Every value is validated by [`MeetingBuilder::build`], which reports the
+/// first problem instead of silently dropping or altering data.
+///
+/// ## Example
+/// ```
+/// use meeting_cost_tracker::{EmployeeCategory, MeetingBuilder};
+/// let dev = EmployeeCategory::new("Dev", 120_000).unwrap();
+/// let pm = EmployeeCategory::new("PM", 150_000).unwrap();
+/// let meeting = MeetingBuilder::new()
+///     .attendee(&dev, 4)
+///     .attendee(&pm, 1)
+///     .overhead(0.3)
+///     .work_hours(1_800.0)
+///     .build()
+///     .unwrap();
+/// assert_eq!(meeting.total_attendees(), 5);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MeetingBuilder {
+    attendees: Vec<(EmployeeCategory, u32)>,
+    overhead: Option<f64>,
+    work_hours: Option<f64>,
+}
+
+impl MeetingBuilder {
+    /// Creates a builder for an empty meeting with default settings.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `count` attendees of `category`.
+    ///
+    /// Calling this repeatedly with the same category adds up the counts.
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - The [`EmployeeCategory`] to add.
+    /// * `count` - Number of attendees, at least one.
+    ///
+    /// # Returns
+    ///
+    /// The builder, for chaining.
+    ///
+    /// # See Also
+    /// * [`Meeting::try_add_attendee`]
+    #[must_use]
+    pub fn attendee(mut self, category: &EmployeeCategory, count: u32) -> Self {
+        self.attendees.push((category.clone(), count));
+        self
+    }
+
+    /// Sets the overhead fraction; see [`Meeting::set_overhead`].
+    #[must_use]
+    pub fn overhead(mut self, overhead: f64) -> Self {
+        self.overhead = Some(overhead);
+        self
+    }
+
+    /// Sets the work hours per year; see [`Meeting::set_work_hours`].
+    #[must_use]
+    pub fn work_hours(mut self, hours: f64) -> Self {
+        self.work_hours = Some(hours);
+        self
+    }
+
+    /// Builds the meeting.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, MeetingBuilder, MeetingError};
+    /// let cheap = EmployeeCategory::new("Dev", 1).unwrap();
+    /// let dear = EmployeeCategory::new("Dev", 2).unwrap();
+    /// let result = MeetingBuilder::new().attendee(&cheap, 1).attendee(&dear, 1).build();
+    /// assert_eq!(result.unwrap_err(), MeetingError::SalaryMismatch("Dev".into()));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// A stopped [`Meeting`] with the configured attendees and settings.
+    ///
+    /// # Errors
+    ///
+    /// * [`MeetingError::InvalidWorkHours`] or [`MeetingError::InvalidOverhead`]
+    ///   for out-of-range settings.
+    /// * [`MeetingError::InvalidCount`] for an attendee count of zero.
+    /// * [`MeetingError::SalaryMismatch`] if one title is given with two
+    ///   different salaries, since the meeting would keep only one.
+    /// * [`MeetingError::TooManyAttendees`] if a title's counts overflow.
+    pub fn build(self) -> Result<Meeting, MeetingError> {
+        let mut meeting = Meeting::new();
+        if let Some(hours) = self.work_hours {
+            meeting.set_work_hours(hours)?;
+        }
+        if let Some(overhead) = self.overhead {
+            meeting.set_overhead(overhead)?;
+        }
+        for (i, (category, _)) in self.attendees.iter().enumerate() {
+            if self.attendees[..i]
+                .iter()
+                .any(|(c, _)| c.title() == category.title() && c.salary() != category.salary())
+            {
+                return Err(MeetingError::SalaryMismatch(category.title().to_string()));
+            }
+        }
+        for (category, count) in &self.attendees {
+            meeting.try_add_attendee(category, *count)?;
+        }
+        Ok(meeting)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_cost_eq;
+
+    #[test]
+    fn built_meeting_matches_manual_construction() {
+        let dev = EmployeeCategory::new("Dev", 120_000).unwrap();
+        let pm = EmployeeCategory::new("PM", 150_000).unwrap();
+        let built = MeetingBuilder::new()
+            .attendee(&dev, 3)
+            .attendee(&pm, 1)
+            .attendee(&dev, 1)
+            .overhead(0.2)
+            .work_hours(1_800.0)
+            .build()
+            .unwrap();
+
+        let mut manual = Meeting::with_work_hours(1_800.0).unwrap();
+        manual.set_overhead(0.2).unwrap();
+        manual.add_attendee(&dev, 4);
+        manual.add_attendee(&pm, 1);
+
+        assert_eq!(built.attendee_count("Dev"), Some(4));
+        assert_cost_eq(
+            built.burn_rate_per_minute(),
+            manual.burn_rate_per_minute(),
+            1e-9,
+        );
+        assert_cost_eq(built.total_cost(), manual.total_cost(), 1e-9);
+    }
+
+    #[test]
+    fn invalid_input_is_reported() {
+        let dev = EmployeeCategory::new("Dev", 120_000).unwrap();
+        assert_eq!(
+            MeetingBuilder::new().attendee(&dev, 0).build().unwrap_err(),
+            MeetingError::InvalidCount
+        );
+        assert_eq!(
+            MeetingBuilder::new().work_hours(0.0).build().unwrap_err(),
+            MeetingError::InvalidWorkHours(0.0)
+        );
+        assert_eq!(
+            MeetingBuilder::new().overhead(-1.0).build().unwrap_err(),
+            MeetingError::InvalidOverhead(-1.0)
+        );
+        assert_eq!(
+            MeetingBuilder::new()
+                .attendee(&dev, u32::MAX)
+                .attendee(&dev, 1)
+                .build()
+                .unwrap_err(),
+            MeetingError::TooManyAttendees("Dev".into())
+        );
+    }
+}
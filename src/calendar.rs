@@ -0,0 +1,250 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::meeting::Meeting;
+
+const SECS_PER_DAY: u64 = 86_400;
+
+/// One entry of a calendar, e.g. an event imported from an ICS file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CalendarEvent {
+    /// Title of the event.
+    pub summary: String,
+    /// Wall-clock time the event starts.
+    pub start: SystemTime,
+    /// Wall-clock time the event ends.
+    pub end: SystemTime,
+}
+
+impl CalendarEvent {
+    /// Creates an event titled `summary` running from `start` to `end`.
+    ///
+    /// # Arguments
+    ///
+    /// * `summary` - Title of the event.
+    /// * `start` - Time the event starts.
+    /// * `end` - Time the event ends.
+    ///
+    /// # Returns
+    ///
+    /// The new [`CalendarEvent`].
+    #[must_use]
+    pub fn new<T: Into<String>>(summary: T, start: SystemTime, end: SystemTime) -> Self {
+        Self {
+            summary: summary.into(),
+            start,
+            end,
+        }
+    }
+
+    /// Whether the event is under way at `now`.
+    fn covers(&self, now: SystemTime) -> bool {
+        self.start <= now && now < self.end
+    }
+}
+
+/// Whole days and seconds into the day of `time` since the Unix epoch, in
+/// local time `utc_offset_minutes` ahead of UTC.
+fn day_and_second(time: SystemTime, utc_offset_minutes: i32) -> (u64, u64) {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs());
+    let local = i64::try_from(secs)
+        .unwrap_or(i64::MAX)
+        .saturating_add(i64::from(utc_offset_minutes) * 60);
+    let secs = u64::try_from(local).unwrap_or(0);
+    (secs / SECS_PER_DAY, secs % SECS_PER_DAY)
+}
+
+/// Formats `time` as `YYYY-MM-DD HH:MM` in local time `utc_offset_minutes`
+/// ahead of UTC.
+fn format_minute(time: SystemTime, utc_offset_minutes: i32) -> String {
+    let (days, second) = day_and_second(time, utc_offset_minutes);
+    // Gregorian date from a day count; see Howard Hinnant's `civil_from_days`.
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z % 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}",
+        second / 3600,
+        second % 3600 / 60
+    )
+}
+
+impl Meeting {
+    /// Suggests a name for a meeting started at `now` without a title.
+    ///
+    /// With a calendar, the name is the summary of the event under way at
+    /// `now`, the one that started last if several overlap, or else of the
+    /// next event starting later the same local day. Otherwise it is the local
+    /// start time as `Meeting YYYY-MM-DD HH:MM`. Events with a blank summary
+    /// are ignored.
+    ///
+    /// Without a known offset, days and the start time are taken in UTC and
+    /// the name ends in ` UTC`, so it is not mistaken for local time.
+    ///
+    /// ## Example
+    /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
+    /// use meeting_cost_tracker::{CalendarEvent, Meeting};
+    /// let now = UNIX_EPOCH + Duration::from_secs(1_717_423_200);
+    /// assert_eq!(Meeting::default_name(now, None, None), "Meeting 2024-06-03 14:00 UTC");
+    /// assert_eq!(Meeting::default_name(now, None, Some(120)), "Meeting 2024-06-03 16:00");
+    ///
+    /// let review = CalendarEvent::new(
+    ///     "Design review",
+    ///     now + Duration::from_mins(5),
+    ///     now + Duration::from_mins(65),
+    /// );
+    /// assert_eq!(Meeting::default_name(now, Some(&[review]), None), "Design review");
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - Wall-clock time the meeting starts.
+    /// * `events` - Calendar to take the name from, if one was imported.
+    /// * `utc_offset_minutes` - Minutes local time is ahead of UTC, e.g.
+    ///   `-300` for UTC-5, or `None` if unknown.
+    ///
+    /// # Returns
+    ///
+    /// The suggested title, for [`Meeting::set_title`].
+    #[must_use]
+    pub fn default_name(
+        now: SystemTime,
+        events: Option<&[CalendarEvent]>,
+        utc_offset_minutes: Option<i32>,
+    ) -> String {
+        let offset = utc_offset_minutes.unwrap_or(0);
+        let events = events
+            .unwrap_or_default()
+            .iter()
+            .filter(|event| !event.summary.trim().is_empty());
+        let (today, _) = day_and_second(now, offset);
+        let current = events
+            .clone()
+            .filter(|event| event.covers(now))
+            .max_by_key(|event| event.start);
+        let next = || {
+            events
+                .clone()
+                .filter(|event| event.start > now && day_and_second(event.start, offset).0 == today)
+                .min_by_key(|event| event.start)
+        };
+        match current.or_else(next) {
+            Some(event) => event.summary.trim().to_string(),
+            None if utc_offset_minutes.is_none() => {
+                format!("Meeting {} UTC", format_minute(now, 0))
+            }
+            None => format!("Meeting {}", format_minute(now, offset)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn at(secs: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(secs)
+    }
+
+    fn event(summary: &str, start: SystemTime, minutes: u64) -> CalendarEvent {
+        CalendarEvent::new(summary, start, start + Duration::from_mins(minutes))
+    }
+
+    #[test]
+    fn names_from_utc_start_without_calendar() {
+        assert_eq!(
+            Meeting::default_name(at(1_717_423_200), None, None),
+            "Meeting 2024-06-03 14:00 UTC"
+        );
+        assert_eq!(
+            Meeting::default_name(at(1_709_251_140 + 59), Some(&[]), None),
+            "Meeting 2024-02-29 23:59 UTC"
+        );
+        assert_eq!(
+            Meeting::default_name(at(951_868_800), None, None),
+            "Meeting 2000-03-01 00:00 UTC"
+        );
+        assert_eq!(
+            Meeting::default_name(at(0), None, None),
+            "Meeting 1970-01-01 00:00 UTC"
+        );
+    }
+
+    #[test]
+    fn names_from_local_start_with_offset() {
+        let now = at(1_717_423_200);
+        assert_eq!(
+            Meeting::default_name(now, None, Some(-300)),
+            "Meeting 2024-06-03 09:00"
+        );
+        assert_eq!(
+            Meeting::default_name(now, None, Some(11 * 60)),
+            "Meeting 2024-06-04 01:00"
+        );
+        assert_eq!(
+            Meeting::default_name(now, None, Some(0)),
+            "Meeting 2024-06-03 14:00"
+        );
+        // Before the epoch in local time clamps to it.
+        assert_eq!(
+            Meeting::default_name(at(0), None, Some(-60)),
+            "Meeting 1970-01-01 00:00"
+        );
+    }
+
+    #[test]
+    fn next_event_uses_the_local_day() {
+        // 18:00 at UTC-5 is 23:00 UTC, so the UTC day ends before the event.
+        let now = at(1_717_423_200 + 9 * 3600);
+        let events = [event("Wrap-up", now + Duration::from_hours(2), 30)];
+        assert_eq!(
+            Meeting::default_name(now, Some(&events), Some(-300)),
+            "Wrap-up"
+        );
+        assert_eq!(
+            Meeting::default_name(now, Some(&events), None),
+            "Meeting 2024-06-03 23:00 UTC"
+        );
+    }
+
+    #[test]
+    fn prefers_the_latest_overlapping_event() {
+        let now = at(1_717_423_200);
+        let events = [
+            event("All-hands", now - Duration::from_hours(1), 120),
+            event("Standup", now - Duration::from_mins(5), 15),
+            event("Retro", now + Duration::from_mins(10), 30),
+            event("Finished", now - Duration::from_mins(30), 30),
+        ];
+        assert_eq!(Meeting::default_name(now, Some(&events), None), "Standup");
+    }
+
+    #[test]
+    fn falls_back_to_next_event_the_same_day() {
+        let now = at(1_717_423_200);
+        let events = [
+            event("Earlier", now - Duration::from_hours(2), 30),
+            event("Planning", now + Duration::from_hours(2), 60),
+            event("  Sync  ", now + Duration::from_mins(20), 30),
+            event(" ", now + Duration::from_mins(10), 30),
+        ];
+        assert_eq!(Meeting::default_name(now, Some(&events), None), "Sync");
+
+        let tomorrow = [event("Tomorrow", now + Duration::from_hours(12), 30)];
+        assert_eq!(
+            Meeting::default_name(now, Some(&tomorrow), None),
+            "Meeting 2024-06-03 14:00 UTC"
+        );
+    }
+}
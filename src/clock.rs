@@ -0,0 +1,44 @@
+use std::fmt::Debug;
+use std::time::Instant;
+
+/// Source of the current time for a [`Meeting`](crate::Meeting).
+///
+/// Meetings read the time only through their clock, so tests can swap in a
+/// `testing::ManualClock`, from the `test-util` feature, and advance time
+/// instantly instead of sleeping.
+///
+/// ## Example
+/// ```
+/// use meeting_cost_tracker::{Clock, Meeting, Monotonic};
+/// let meeting = Meeting::with_clock(Monotonic);
+/// assert!(Monotonic.now() <= std::time::Instant::now());
+/// assert!(!meeting.is_running());
+/// ```
+///
+/// # See Also
+/// * [`Meeting::with_clock`](crate::Meeting::with_clock)
+pub trait Clock: Debug + Send + Sync {
+    /// Returns the current instant.
+    fn now(&self) -> Instant;
+}
+
+/// The system's monotonic clock, used by [`Meeting::new`](crate::Meeting::new).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Monotonic;
+
+impl Clock for Monotonic {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn monotonic_never_goes_backwards() {
+        let first = Monotonic.now();
+        assert!(Monotonic.now() >= first);
+    }
+}
@@ -0,0 +1,200 @@
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+
+use crate::meeting::{CategoryCost, Meeting};
+use crate::shared::SharedMeeting;
+
+/// Largest request body accepted by [`serve`].
+const MAX_BODY_BYTES: usize = 64 * 1024;
+
+/// How long a client may take to send its request.
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// JSON document returned by `GET /status`.
+#[derive(Serialize)]
+struct Status {
+    running: bool,
+    duration_secs: f64,
+    total_cost: f64,
+    burn_rate_per_minute: f64,
+    headcount: u32,
+    categories: Vec<CategoryCost>,
+    started_at: Option<SystemTime>,
+    ended_at: Option<SystemTime>,
+}
+
+impl Status {
+    fn of(meeting: &Meeting) -> Self {
+        Self {
+            running: meeting.is_running(),
+            duration_secs: meeting.duration().as_secs_f64(),
+            total_cost: meeting.total_cost(),
+            burn_rate_per_minute: meeting.burn_rate_per_minute(),
+            headcount: meeting.total_attendees(),
+            categories: meeting.breakdown(),
+            started_at: meeting.started_at(),
+            ended_at: meeting.ended_at(),
+        }
+    }
+}
+
+/// Body accepted by `POST /control`.
+#[derive(Deserialize)]
+struct Control {
+    action: Action,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Action {
+    Start,
+    Stop,
+    Reset,
+}
+
+/// Serves status and control of `meeting` over HTTP until `listener` fails.
+///
+/// Two endpoints are available:
+///
+/// * `GET /status` returns the meeting as JSON: whether it is running, its
+///   duration in seconds, total cost, burn rate, headcount and per-category
+///   breakdown.
+/// * `POST /control` with a body of `{"action":"start"}`, `"stop"` or
+///   `"reset"` controls the meeting and responds with the new status.
+///
+/// Connections are handled one at a time on the calling thread, which keeps
+/// the daemon light enough for small devices. Errors on a single connection
+/// are ignored so one misbehaving client cannot stop the daemon.
+///
+/// ## Example
+/// ```no_run
+/// use std::net::TcpListener;
+/// use meeting_cost_tracker::{serve, SharedMeeting};
+/// let listener = TcpListener::bind("127.0.0.1:7878").unwrap();
+/// serve(&listener, &SharedMeeting::default()).unwrap();
+/// ```
+///
+/// # Arguments
+///
+/// * `listener` - Socket to accept connections on.
+/// * `meeting` - Meeting to report on and control. Other clones of it can
+///   keep using the meeting while the daemon runs.
+///
+/// # Returns
+///
+/// Only returns on error.
+///
+/// # Errors
+///
+/// Returns the error if accepting a connection fails.
+pub fn serve(listener: &TcpListener, meeting: &SharedMeeting) -> io::Result<()> {
+    loop {
+        let (stream, _) = listener.accept()?;
+        let _ = handle_connection(stream, meeting);
+    }
+}
+
+/// Reads one request from `stream`, answers it and closes the connection.
+fn handle_connection(stream: TcpStream, meeting: &SharedMeeting) -> io::Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let mut reader = BufReader::new(stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let path = parts.next().unwrap_or_default().to_string();
+
+    let mut content_length = 0usize;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap_or(0);
+            }
+        }
+    }
+
+    let (code, body) = if content_length > MAX_BODY_BYTES {
+        (413, error_body("request body too large"))
+    } else {
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body)?;
+        respond(&method, &path, &body, meeting)
+    };
+    write_response(reader.get_mut(), code, &body)
+}
+
+/// Routes a request and returns the status code and JSON body to send.
+fn respond(method: &str, path: &str, body: &[u8], meeting: &SharedMeeting) -> (u16, String) {
+    match (method, path) {
+        ("GET", "/status") => (200, meeting.with(|m| status_body(m))),
+        ("POST", "/control") => match serde_json::from_slice::<Control>(body) {
+            Ok(control) => meeting.with(|m| {
+                match control.action {
+                    Action::Start => m.start(),
+                    Action::Stop => m.stop(),
+                    Action::Reset => m.reset(),
+                }
+                (200, status_body(m))
+            }),
+            Err(err) => (400, error_body(&err.to_string())),
+        },
+        (_, "/status" | "/control") => (405, error_body("method not allowed")),
+        _ => (404, error_body("not found")),
+    }
+}
+
+fn status_body(meeting: &Meeting) -> String {
+    serde_json::to_string(&Status::of(meeting)).unwrap_or_default()
+}
+
+fn error_body(message: &str) -> String {
+    serde_json::json!({ "error": message }).to_string()
+}
+
+fn write_response(stream: &mut TcpStream, code: u16, body: &str) -> io::Result<()> {
+    let reason = match code {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        _ => "Payload Too Large",
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {code} {reason}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+    stream.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::EmployeeCategory;
+
+    #[test]
+    fn routes_requests() {
+        let meeting = SharedMeeting::default();
+        meeting.add_attendee(&EmployeeCategory::new("Dev", 120_000).unwrap(), 2);
+
+        let (code, body) = respond("GET", "/status", b"", &meeting);
+        assert_eq!(code, 200);
+        let status: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(status["headcount"], 2);
+        assert_eq!(status["categories"][0]["title"], "Dev");
+
+        assert_eq!(
+            respond("POST", "/control", b"{\"action\":\"pause\"}", &meeting).0,
+            400
+        );
+        assert_eq!(respond("GET", "/control", b"", &meeting).0, 405);
+        assert_eq!(respond("GET", "/", b"", &meeting).0, 404);
+    }
+}
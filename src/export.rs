@@ -0,0 +1,289 @@
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::meeting::Meeting;
+use crate::report::APPROXIMATE_NOTE;
+use crate::storage::StorageError;
+
+/// Default file name used when exporting a meeting as TSV.
+pub const DEFAULT_TSV_FILE: &str = "meeting.tsv";
+
+/// Picks the directory that exports are written to.
+///
+/// A directory given on the command line wins over one from the settings
+/// file. Without either, the platform documents directory is used, falling
+/// back to the current working directory.
+///
+/// ## Example
+/// ```
+/// use std::path::Path;
+/// use meeting_cost_tracker::resolve_export_dir;
+/// let dir = resolve_export_dir(Some(Path::new("cli")), Some(Path::new("configured")));
+/// assert_eq!(dir, Path::new("cli"));
+/// ```
+///
+/// # Arguments
+///
+/// * `cli` - Directory passed via `--export-dir`, if any.
+/// * `configured` - Directory from [`Settings::export_dir`](crate::Settings::export_dir), if any.
+///
+/// # Returns
+///
+/// The directory exports should be written to. It may not exist yet.
+///
+/// # See Also
+/// * [`default_export_dir`]
+#[must_use]
+pub fn resolve_export_dir(cli: Option<&Path>, configured: Option<&Path>) -> PathBuf {
+    cli.or(configured)
+        .map_or_else(default_export_dir, Path::to_path_buf)
+}
+
+/// Returns the platform documents directory, or the current directory if
+/// there is none.
+///
+/// # Returns
+///
+/// The fallback export directory.
+#[must_use]
+pub fn default_export_dir() -> PathBuf {
+    dirs::document_dir()
+        .filter(|dir| dir.is_dir())
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+/// Returns a path in `dir` for `file_name` that does not overwrite an
+/// existing file.
+///
+/// When `file_name` is taken, `-1`, `-2`, … is appended to the file stem
+/// until a free name is found.
+///
+/// ## Example
+/// ```
+/// use meeting_cost_tracker::unique_path;
+/// let dir = tempfile::tempdir().unwrap();
+/// std::fs::write(dir.path().join("meeting.tsv"), "").unwrap();
+/// assert_eq!(unique_path(dir.path(), "meeting.tsv"), dir.path().join("meeting-1.tsv"));
+/// ```
+///
+/// # Arguments
+///
+/// * `dir` - Directory the file will be written to.
+/// * `file_name` - Preferred file name.
+///
+/// # Returns
+///
+/// `dir.join(file_name)` if free, otherwise the first free numbered variant.
+#[must_use]
+pub fn unique_path(dir: &Path, file_name: &str) -> PathBuf {
+    let candidate = dir.join(file_name);
+    if !candidate.exists() {
+        return candidate;
+    }
+    let name = Path::new(file_name);
+    let stem = name
+        .file_stem()
+        .map_or_else(String::new, |s| s.to_string_lossy().into_owned());
+    let extension = name.extension().map(|e| e.to_string_lossy().into_owned());
+    let mut n = 1u32;
+    loop {
+        let numbered = match &extension {
+            Some(ext) => format!("{stem}-{n}.{ext}"),
+            None => format!("{stem}-{n}"),
+        };
+        let candidate = dir.join(numbered);
+        if !candidate.exists() {
+            return candidate;
+        }
+        n += 1;
+    }
+}
+
+/// Exports a meeting as TSV into `dir` without overwriting earlier exports.
+///
+/// The directory is created if it does not exist.
+///
+/// # Arguments
+///
+/// * `meeting` - Meeting to export.
+/// * `dir` - Export directory, usually from [`resolve_export_dir`].
+/// * `file_name` - Preferred file name; numbered if already taken.
+///
+/// # Returns
+///
+/// The path that was written.
+///
+/// # Errors
+///
+/// Returns [`StorageError::Io`] if the directory or file cannot be written.
+///
+/// # See Also
+/// * [`export_meeting_tsv`]
+/// * [`unique_path`]
+pub fn export_meeting_tsv_to_dir(
+    meeting: &Meeting,
+    dir: &Path,
+    file_name: &str,
+) -> Result<PathBuf, StorageError> {
+    fs::create_dir_all(dir)?;
+    let path = unique_path(dir, file_name);
+    export_meeting_tsv(meeting, &path)?;
+    Ok(path)
+}
+
+/// Replaces characters that would break a tab-separated row with spaces.
+fn sanitize_tsv_field(field: &str) -> String {
+    field.replace(['\t', '\n', '\r'], " ")
+}
+
+/// Renders the cost breakdown of `meeting` as tab-separated values.
+///
+/// The first row is a header, followed by one row per attendee group and a
+/// `Total` row. Approximate meetings end with a row holding
+/// [`APPROXIMATE_NOTE`].
+fn meeting_tsv(meeting: &Meeting) -> String {
+    let mut out = String::from("Title\tCount\tSalary\tCost\n");
+    for row in meeting.breakdown() {
+        let _ = writeln!(
+            out,
+            "{}\t{}\t{}\t{:.2}",
+            sanitize_tsv_field(&row.title),
+            row.count,
+            row.salary,
+            row.cost
+        );
+    }
+    let _ = writeln!(
+        out,
+        "Total\t{}\t\t{:.2}",
+        meeting.total_attendees(),
+        meeting.total_cost()
+    );
+    if meeting.is_approximate() {
+        let _ = writeln!(out, "{APPROXIMATE_NOTE}\t\t\t");
+    }
+    out
+}
+
+/// Writes the cost breakdown of a meeting to a tab-separated file.
+///
+/// The output pastes directly into spreadsheet tools such as Google Sheets.
+/// Tabs and line breaks inside titles are replaced with spaces so every
+/// attendee group stays on a single row.
+///
+/// ## Example
+/// ```no_run
+/// use meeting_cost_tracker::{export_meeting_tsv, EmployeeCategory, Meeting};
+/// let mut meeting = Meeting::new();
+/// meeting.add_attendee(&EmployeeCategory::new("Engineer", 120_000).unwrap(), 3);
+/// export_meeting_tsv(&meeting, "meeting.tsv").unwrap();
+/// ```
+///
+/// # Arguments
+///
+/// * `meeting` - Meeting to export.
+/// * `path` - Destination file, overwritten if it exists.
+///
+/// # Returns
+///
+/// `Ok(())` once the file has been written.
+///
+/// # Errors
+///
+/// Returns [`StorageError::Io`] if the file cannot be written.
+///
+/// # See Also
+/// * [`Meeting::breakdown`]
+pub fn export_meeting_tsv<P: AsRef<Path>>(meeting: &Meeting, path: P) -> Result<(), StorageError> {
+    fs::write(path, meeting_tsv(meeting))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::EmployeeCategory;
+    use tempfile::tempdir;
+
+    #[test]
+    fn tsv_uses_tabs_and_sanitizes_titles() {
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&EmployeeCategory::new("Dev\tLead", 120_000).unwrap(), 2);
+        meeting.add_attendee(&EmployeeCategory::new("QA", 60_000).unwrap(), 1);
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("meeting.tsv");
+        export_meeting_tsv(&meeting, &path).unwrap();
+
+        let contents = fs::read_to_string(path).unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines[0], "Title\tCount\tSalary\tCost");
+        assert_eq!(lines[1], "Dev Lead\t2\t120000\t0.00");
+        assert_eq!(lines[2], "QA\t1\t60000\t0.00");
+        assert_eq!(lines[3], "Total\t3\t\t0.00");
+        assert!(lines.iter().all(|l| l.split('\t').count() == 4));
+    }
+
+    #[test]
+    fn tsv_ends_with_note_for_approximate_meetings() {
+        let mut pm = EmployeeCategory::new("PM", 112_500).unwrap();
+        pm.set_approximate(true);
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&pm, 1);
+        let tsv = meeting_tsv(&meeting);
+        let last = tsv.lines().last().unwrap();
+        assert_eq!(last.split('\t').next(), Some(APPROXIMATE_NOTE));
+        assert_eq!(last.split('\t').count(), 4);
+    }
+
+    #[test]
+    fn tsv_total_matches_cents_at_half_cent() {
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&EmployeeCategory::new("Dev", 120_000).unwrap(), 1);
+        meeting.set_elapsed(std::time::Duration::from_millis(7_500));
+        assert_eq!(meeting.total_cost_cents(), 13);
+        let tsv = meeting_tsv(&meeting);
+        let mut lines = tsv.lines().skip(1);
+        assert_eq!(lines.next(), Some("Dev\t1\t120000\t0.13"));
+        assert_eq!(lines.next(), Some("Total\t1\t\t0.13"));
+    }
+
+    #[test]
+    fn unique_path_appends_counter() {
+        let dir = tempdir().unwrap();
+        assert_eq!(unique_path(dir.path(), "a.tsv"), dir.path().join("a.tsv"));
+        fs::write(dir.path().join("a.tsv"), "").unwrap();
+        fs::write(dir.path().join("a-1.tsv"), "").unwrap();
+        assert_eq!(unique_path(dir.path(), "a.tsv"), dir.path().join("a-2.tsv"));
+        fs::write(dir.path().join("report"), "").unwrap();
+        assert_eq!(
+            unique_path(dir.path(), "report"),
+            dir.path().join("report-1")
+        );
+    }
+
+    #[test]
+    fn export_dir_precedence() {
+        let cli = Path::new("from-cli");
+        let configured = Path::new("from-settings");
+        assert_eq!(resolve_export_dir(Some(cli), Some(configured)), cli);
+        assert_eq!(resolve_export_dir(None, Some(configured)), configured);
+        assert_eq!(resolve_export_dir(None, None), default_export_dir());
+    }
+
+    #[test]
+    fn export_to_dir_creates_and_numbers() {
+        let dir = tempdir().unwrap();
+        let target = dir.path().join("exports");
+        let meeting = Meeting::new();
+        let first = export_meeting_tsv_to_dir(&meeting, &target, DEFAULT_TSV_FILE).unwrap();
+        let second = export_meeting_tsv_to_dir(&meeting, &target, DEFAULT_TSV_FILE).unwrap();
+        assert_eq!(first, target.join("meeting.tsv"));
+        assert_eq!(second, target.join("meeting-1.tsv"));
+    }
+
+    #[test]
+    fn tsv_strips_line_breaks() {
+        assert_eq!(sanitize_tsv_field("a\r\nb\tc"), "a  b c");
+    }
+}
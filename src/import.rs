@@ -0,0 +1,153 @@
+use std::fmt;
+
+use crate::model::EmployeeCategory;
+
+/// Salary change of a category that exists both before and after an import.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SalaryChange {
+    /// Title of the category.
+    pub title: String,
+    /// Salary before the import.
+    pub old: u64,
+    /// Salary after the import.
+    pub new: u64,
+}
+
+/// What replacing one category list with another would change.
+///
+/// Computed without touching either list, so importers can show it as a
+/// preview before applying anything.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportDiff {
+    /// Categories only present in the incoming list.
+    pub added: Vec<EmployeeCategory>,
+    /// Categories present in both lists with different salaries.
+    pub salary_changed: Vec<SalaryChange>,
+    /// Categories only present in the current list.
+    pub removed: Vec<EmployeeCategory>,
+    /// Number of categories identical in both lists.
+    pub unchanged: usize,
+}
+
+impl ImportDiff {
+    /// Compares the `current` category list with an `incoming` one.
+    ///
+    /// Categories are matched by title. Each change class is sorted by title.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, ImportDiff};
+    /// let current = vec![EmployeeCategory::new("Dev", 100_000).unwrap()];
+    /// let incoming = vec![
+    ///     EmployeeCategory::new("Dev", 110_000).unwrap(),
+    ///     EmployeeCategory::new("QA", 80_000).unwrap(),
+    /// ];
+    /// let diff = ImportDiff::between(&current, &incoming);
+    /// assert_eq!(diff.added[0].title(), "QA");
+    /// assert_eq!(diff.salary_changed[0].new, 110_000);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `current` - Categories as they are now.
+    /// * `incoming` - Categories as they would be after the import.
+    ///
+    /// # Returns
+    ///
+    /// The differences between the two lists.
+    ///
+    /// # See Also
+    /// * [`ImportDiff::is_empty`]
+    #[must_use]
+    pub fn between(current: &[EmployeeCategory], incoming: &[EmployeeCategory]) -> Self {
+        let find = |list: &[EmployeeCategory], title: &str| {
+            list.iter().find(|c| c.title() == title).cloned()
+        };
+        let mut diff = Self::default();
+        for category in incoming {
+            match find(current, category.title()) {
+                None => diff.added.push(category.clone()),
+                Some(old) if old.salary() != category.salary() => {
+                    diff.salary_changed.push(SalaryChange {
+                        title: category.title().to_string(),
+                        old: old.salary(),
+                        new: category.salary(),
+                    });
+                }
+                Some(_) => diff.unchanged += 1,
+            }
+        }
+        diff.removed = current
+            .iter()
+            .filter(|c| find(incoming, c.title()).is_none())
+            .cloned()
+            .collect();
+        diff.added.sort_by(|a, b| a.title().cmp(b.title()));
+        diff.salary_changed.sort_by(|a, b| a.title.cmp(&b.title));
+        diff.removed.sort_by(|a, b| a.title().cmp(b.title()));
+        diff
+    }
+
+    /// Returns `true` if the import would not change anything.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.salary_changed.is_empty() && self.removed.is_empty()
+    }
+}
+
+impl fmt::Display for ImportDiff {
+    /// Renders one line per change followed by the unchanged count.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for category in &self.added {
+            writeln!(f, "+ {} (${})", category.title(), category.salary())?;
+        }
+        for change in &self.salary_changed {
+            writeln!(f, "~ {}: ${} -> ${}", change.title, change.old, change.new)?;
+        }
+        for category in &self.removed {
+            writeln!(f, "- {} (${})", category.title(), category.salary())?;
+        }
+        write!(f, "{} unchanged", self.unchanged)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cat(title: &str, salary: u64) -> EmployeeCategory {
+        EmployeeCategory::new(title, salary).unwrap()
+    }
+
+    #[test]
+    fn classifies_every_change() {
+        let current = vec![cat("Dev", 100_000), cat("PM", 120_000), cat("QA", 80_000)];
+        let incoming = vec![cat("Ops", 90_000), cat("PM", 130_000), cat("Dev", 100_000)];
+        let diff = ImportDiff::between(&current, &incoming);
+        assert_eq!(diff.added, vec![cat("Ops", 90_000)]);
+        assert_eq!(
+            diff.salary_changed,
+            vec![SalaryChange {
+                title: "PM".into(),
+                old: 120_000,
+                new: 130_000,
+            }]
+        );
+        assert_eq!(diff.removed, vec![cat("QA", 80_000)]);
+        assert_eq!(diff.unchanged, 1);
+        assert!(!diff.is_empty());
+        assert_eq!(
+            diff.to_string(),
+            "+ Ops ($90000)\n~ PM: $120000 -> $130000\n- QA ($80000)\n1 unchanged"
+        );
+    }
+
+    #[test]
+    fn identical_lists_give_empty_diff() {
+        let list = vec![cat("Dev", 100_000), cat("PM", 120_000)];
+        let diff = ImportDiff::between(&list, &list);
+        assert!(diff.is_empty());
+        assert_eq!(diff.unchanged, 2);
+        assert_eq!(diff.to_string(), "2 unchanged");
+    }
+}
@@ -0,0 +1,140 @@
+use thiserror::Error;
+
+/// Errors from interpreting a typed attendee count.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum CountError {
+    /// The input is not a whole number.
+    #[error("'{0}' is not a whole number")]
+    NotANumber(String),
+
+    /// A count of zero was entered.
+    #[error("Count must be at least 1")]
+    Zero,
+
+    /// The count is larger than allowed.
+    #[error("Count must be at most {0}")]
+    TooLarge(u32),
+}
+
+/// Interprets text typed into a count prompt.
+///
+/// Every flow that asks for a number of attendees uses the same rules:
+///
+/// * Empty or whitespace-only input means `default`, which must meet the
+///   same limits as a typed count.
+/// * `_` and `,` may separate digit groups, so `1_000` and `1,000` are
+///   both one thousand.
+/// * Zero is rejected, as is anything above `max`.
+///
+/// ## Example
+/// ```
+/// use meeting_cost_tracker::{interpret_count_input, CountError};
+/// assert_eq!(interpret_count_input("  ", 1, 100), Ok(1));
+/// assert_eq!(interpret_count_input("12", 1, 100), Ok(12));
+/// assert_eq!(interpret_count_input("0", 1, 100), Err(CountError::Zero));
+/// ```
+///
+/// # Arguments
+///
+/// * `input` - Text as typed by the user.
+/// * `default` - Count used when the input is empty.
+/// * `max` - Largest count accepted.
+///
+/// # Returns
+///
+/// The interpreted count.
+///
+/// # Errors
+///
+/// * [`CountError::NotANumber`] if the input is not a whole number.
+/// * [`CountError::Zero`] if the input, or the default it falls back to, is
+///   zero.
+/// * [`CountError::TooLarge`] if the input, or the default it falls back to,
+///   exceeds `max`.
+pub fn interpret_count_input(input: &str, default: u32, max: u32) -> Result<u32, CountError> {
+    let trimmed = input.trim();
+    let count = if trimmed.is_empty() {
+        u64::from(default)
+    } else {
+        let digits: String = trimmed
+            .chars()
+            .filter(|c| !matches!(c, '_' | ','))
+            .collect();
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err(CountError::NotANumber(trimmed.to_string()));
+        }
+        // Anything too long for a u64 is certainly above `max`.
+        digits.parse::<u64>().unwrap_or(u64::MAX)
+    };
+    if count == 0 {
+        Err(CountError::Zero)
+    } else if count > u64::from(max) {
+        Err(CountError::TooLarge(max))
+    } else {
+        Ok(u32::try_from(count).unwrap_or(max))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count_input_rules() {
+        let cases: &[(&str, u32, Result<u32, CountError>)] = &[
+            ("", 1, Ok(1)),
+            ("   ", 1, Ok(1)),
+            ("\t", 1, Ok(1)),
+            (" 7 ", 1, Ok(7)),
+            ("0", 1, Err(CountError::Zero)),
+            ("000", 1, Err(CountError::Zero)),
+            ("1_000", 1, Ok(1_000)),
+            ("1,000", 1, Ok(1_000)),
+            ("1000", 1, Ok(1_000)),
+            ("1001", 1, Err(CountError::TooLarge(1_000))),
+            (
+                "99999999999999999999999",
+                1,
+                Err(CountError::TooLarge(1_000)),
+            ),
+            ("-1", 1, Err(CountError::NotANumber("-1".into()))),
+            ("+1", 1, Err(CountError::NotANumber("+1".into()))),
+            ("_", 1, Err(CountError::NotANumber("_".into()))),
+            ("two", 1, Err(CountError::NotANumber("two".into()))),
+            ("1.5", 1, Err(CountError::NotANumber("1.5".into()))),
+            ("", 1_000, Ok(1_000)),
+            ("", 1_001, Err(CountError::TooLarge(1_000))),
+            (" ", 0, Err(CountError::Zero)),
+            ("7", 1_001, Ok(7)),
+        ];
+        for (input, default, expected) in cases {
+            assert_eq!(
+                &interpret_count_input(input, *default, 1_000),
+                expected,
+                "input {input:?}, default {default}"
+            );
+        }
+    }
+
+    #[test]
+    fn max_boundary_is_inclusive() {
+        assert_eq!(interpret_count_input("5", 1, 5), Ok(5));
+        assert_eq!(
+            interpret_count_input("6", 1, 5),
+            Err(CountError::TooLarge(5))
+        );
+        assert_eq!(
+            interpret_count_input("4294967295", 1, u32::MAX),
+            Ok(u32::MAX)
+        );
+        assert_eq!(
+            interpret_count_input("4294967296", 1, u32::MAX),
+            Err(CountError::TooLarge(u32::MAX))
+        );
+    }
+
+    #[test]
+    fn empty_uses_given_default() {
+        assert_eq!(interpret_count_input("", 3, 10), Ok(3));
+    }
+}
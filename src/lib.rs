@@ -16,16 +16,97 @@
 //! meeting.stop();
 //! println!("Cost: ${:.2}", meeting.total_cost());
 //! ```
+//!
+//! ## Thread safety
+//!
+//! [`Meeting`] is `Send` and `Sync`. Everything that only reads a meeting,
+//! such as `duration`, `total_cost`, `attendees`, `summary`, `breakdown` and
+//! the report helpers, takes `&self` and keeps no cache, so many readers can
+//! share an `Arc<RwLock<Meeting>>` under read locks while one writer changes
+//! the roster. Methods taking `&mut self` change what the meeting bills for,
+//! or, like `tick` and `check_thresholds`, record which notifications were
+//! already sent. [`SharedMeeting`] is a ready-made handle that locks a mutex
+//! around each call.
 
 #![warn(clippy::pedantic)]
 
+mod builder;
+mod calendar;
+mod clock;
+#[cfg(feature = "http")]
+mod daemon;
+mod export;
+mod import;
+mod input;
+mod macros;
 mod meeting;
+mod meeting_set;
 mod model;
+mod report;
+mod resilient;
+mod settings;
+mod shared;
+mod snapshot;
 mod storage;
+#[cfg(any(test, feature = "test-util"))]
+pub mod testing;
+mod usage;
+mod warnings;
 
+/// Chainable construction of meetings.
+pub use builder::MeetingBuilder;
+/// Calendar events used to name meetings.
+pub use calendar::CalendarEvent;
+/// Time sources for meetings.
+pub use clock::{Clock, Monotonic};
+/// Headless HTTP status and control of a meeting.
+#[cfg(feature = "http")]
+pub use daemon::serve;
+/// Spreadsheet-friendly exports of meeting costs.
+pub use export::{
+    default_export_dir, export_meeting_tsv, export_meeting_tsv_to_dir, resolve_export_dir,
+    unique_path, DEFAULT_TSV_FILE,
+};
+/// Previews of what a category import would change.
+pub use import::{ImportDiff, SalaryChange};
+/// Shared rules for interpreting typed input.
+pub use input::{interpret_count_input, CountError};
+/// Recording and replay of keyboard macros.
+pub use macros::{MacroEvent, MacroRecorder, RECORD_KEY, REPLAY_KEY};
 /// Core meeting functionality including timers and cost computation.
-pub use meeting::Meeting;
+pub use meeting::{
+    annualized_recurring_cost, CategoryCost, CostModel, CostSnapshot, CostUpdate, Meeting,
+    MeetingError, MeetingSummary, RemovalReport, RosterSnapshot, SegmentCost, TimelineEvent, Trend,
+};
+/// Several named meetings tracked side by side.
+pub use meeting_set::MeetingSet;
 /// Represents an employee salary category.
-pub use model::EmployeeCategory;
+pub use model::{
+    EmployeeCategory, WorkYear, DEFAULT_WORK_HOURS_PER_YEAR, HOURS_PER_WORK_DAY,
+    MILLIS_PER_WORK_YEAR,
+};
+/// Text rendering of cost series and breakdown tables.
+pub use report::{
+    breakdown_rows, breakdown_rows_with, format_duration, mini_sparkline, APPROXIMATE_NOTE,
+    BREAKDOWN_HEADER,
+};
+/// Retrying and buffering saves for unreliable storage.
+pub use resilient::{fallback_dump_dir, ResilientStore, RetryPolicy};
+/// User preferences for the terminal application.
+pub use settings::Settings;
+/// Thread-safe handle to a meeting.
+pub use shared::SharedMeeting;
+/// Serializable copy of a meeting's state and its roster history.
+pub use snapshot::{MeetingSnapshot, RosterChange};
 /// Persistence helpers for reading and writing categories as TOML.
-pub use storage::{load_attendees, load_categories, save_attendees, save_categories, AttendeeInfo};
+pub use storage::{
+    categories_from_employee_csv, find_duplicate_rosters, load_attendees, load_categories,
+    load_categories_banded, load_categories_merged, load_people, load_settings, roster_from_code,
+    roster_to_code, save_attendees, save_categories, save_categories_banded, save_people,
+    save_settings, AttendeeInfo, CategoryStore, FileStore, MemoryStore, PersonInfo, RosterStore,
+    StorageError, CATEGORIES_FILE, SETTINGS_FILE,
+};
+/// Least-recently-used tracking for keeping the category list short.
+pub use usage::CategoryUsage;
+/// Sanity checks for unexpectedly large or expensive rosters.
+pub use warnings::{flag_outlier_categories, RosterWarnings, Thresholds};
@@ -0,0 +1,277 @@
+use std::collections::BTreeMap;
+
+/// Key that starts and stops recording a macro.
+pub const RECORD_KEY: &str = "F2";
+/// Key that replays a recorded macro.
+pub const REPLAY_KEY: &str = "F3";
+/// Key that cancels choosing a slot.
+const CANCEL_KEY: &str = "Esc";
+
+/// What a frontend should do with a key after [`MacroRecorder::handle`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MacroEvent {
+    /// The key is not for the recorder; process it as usual. It has been
+    /// recorded if a recording is in progress.
+    Pass,
+    /// [`RECORD_KEY`] was pressed; the next key names the slot to record.
+    ChoosingRecordSlot,
+    /// [`REPLAY_KEY`] was pressed; the next key names the slot to replay.
+    ChoosingReplaySlot,
+    /// Recording into the named slot has begun.
+    RecordingStarted(String),
+    /// Recording stopped and the macro was stored in the named slot with
+    /// this many keys; an empty recording removes the slot instead.
+    Recorded {
+        /// Slot the macro was stored in.
+        slot: String,
+        /// Number of keys recorded.
+        keys: usize,
+    },
+    /// Process these keys in order, without passing them to the recorder.
+    Replay {
+        /// Slot the macro came from.
+        slot: String,
+        /// Key specs to process.
+        keys: Vec<String>,
+    },
+    /// No macro is stored in the named slot.
+    UnknownSlot(String),
+    /// Choosing a slot was cancelled with `Esc`.
+    Cancelled,
+    /// [`REPLAY_KEY`] was pressed while recording; macros cannot contain
+    /// other macros, so it was ignored.
+    RecordingInProgress,
+}
+
+/// Recording state of a [`MacroRecorder`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+enum State {
+    #[default]
+    Idle,
+    ChoosingRecordSlot,
+    Recording {
+        slot: String,
+        keys: Vec<String>,
+    },
+    ChoosingReplaySlot,
+}
+
+/// Records sequences of key presses into named slots and hands them back for
+/// replay.
+///
+/// Keys are key specs such as `"a"`, `"Enter"` or `"F2"`, so the recorder
+/// does not depend on a terminal library; the frontend converts its key
+/// events to and from specs. Pressing [`RECORD_KEY`] and then a slot key
+/// starts recording; every key passed on from then is stored until
+/// [`RECORD_KEY`] is pressed again. [`REPLAY_KEY`] and a slot key return the
+/// stored keys.
+///
+/// Replayed keys are meant to be processed directly, not passed back to
+/// [`MacroRecorder::handle`], and never include [`RECORD_KEY`] or
+/// [`REPLAY_KEY`], so a replay cannot start a recording or another replay.
+///
+/// ## Example
+/// ```
+/// use meeting_cost_tracker::{MacroEvent, MacroRecorder};
+/// let mut recorder = MacroRecorder::default();
+/// for key in ["F2", "1", "s", "F2"] {
+///     recorder.handle(key);
+/// }
+/// assert_eq!(recorder.handle("F3"), MacroEvent::ChoosingReplaySlot);
+/// assert_eq!(
+///     recorder.handle("1"),
+///     MacroEvent::Replay { slot: "1".into(), keys: vec!["s".into()] }
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MacroRecorder {
+    macros: BTreeMap<String, Vec<String>>,
+    state: State,
+}
+
+impl MacroRecorder {
+    /// Creates a recorder holding previously saved `macros`.
+    ///
+    /// # Arguments
+    ///
+    /// * `macros` - Key specs by slot, e.g. from
+    ///   [`Settings::macros`](crate::Settings::macros).
+    #[must_use]
+    pub fn new(macros: BTreeMap<String, Vec<String>>) -> Self {
+        Self {
+            macros,
+            state: State::Idle,
+        }
+    }
+
+    /// Returns the stored macros by slot.
+    #[must_use]
+    pub fn macros(&self) -> &BTreeMap<String, Vec<String>> {
+        &self.macros
+    }
+
+    /// Returns the slot being recorded into, if a recording is in progress.
+    #[must_use]
+    pub fn recording_slot(&self) -> Option<&str> {
+        match &self.state {
+            State::Recording { slot, .. } => Some(slot),
+            _ => None,
+        }
+    }
+
+    /// Whether the next key names a slot rather than being processed.
+    #[must_use]
+    pub fn is_choosing_slot(&self) -> bool {
+        matches!(
+            self.state,
+            State::ChoosingRecordSlot | State::ChoosingReplaySlot
+        )
+    }
+
+    /// Feeds one key press to the recorder.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Spec of the key pressed.
+    ///
+    /// # Returns
+    ///
+    /// What to do with the key; see [`MacroEvent`].
+    pub fn handle(&mut self, key: &str) -> MacroEvent {
+        match std::mem::take(&mut self.state) {
+            State::Idle if key == RECORD_KEY => {
+                self.state = State::ChoosingRecordSlot;
+                MacroEvent::ChoosingRecordSlot
+            }
+            State::Idle if key == REPLAY_KEY => {
+                self.state = State::ChoosingReplaySlot;
+                MacroEvent::ChoosingReplaySlot
+            }
+            State::Idle => MacroEvent::Pass,
+            State::ChoosingRecordSlot | State::ChoosingReplaySlot
+                if matches!(key, CANCEL_KEY | RECORD_KEY | REPLAY_KEY) =>
+            {
+                MacroEvent::Cancelled
+            }
+            State::ChoosingRecordSlot => {
+                self.state = State::Recording {
+                    slot: key.to_string(),
+                    keys: Vec::new(),
+                };
+                MacroEvent::RecordingStarted(key.to_string())
+            }
+            State::ChoosingReplaySlot => match self.macros.get(key) {
+                Some(keys) => MacroEvent::Replay {
+                    slot: key.to_string(),
+                    keys: keys
+                        .iter()
+                        .filter(|k| !matches!(k.as_str(), RECORD_KEY | REPLAY_KEY))
+                        .cloned()
+                        .collect(),
+                },
+                None => MacroEvent::UnknownSlot(key.to_string()),
+            },
+            State::Recording { slot, keys } if key == RECORD_KEY => {
+                let count = keys.len();
+                if keys.is_empty() {
+                    self.macros.remove(&slot);
+                } else {
+                    self.macros.insert(slot.clone(), keys);
+                }
+                MacroEvent::Recorded { slot, keys: count }
+            }
+            State::Recording { slot, keys } if key == REPLAY_KEY => {
+                self.state = State::Recording { slot, keys };
+                MacroEvent::RecordingInProgress
+            }
+            State::Recording { slot, mut keys } => {
+                keys.push(key.to_string());
+                self.state = State::Recording { slot, keys };
+                MacroEvent::Pass
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn feed(recorder: &mut MacroRecorder, keys: &[&str]) -> Vec<MacroEvent> {
+        keys.iter().map(|key| recorder.handle(key)).collect()
+    }
+
+    #[test]
+    fn records_only_keys_between_record_presses() {
+        let mut recorder = MacroRecorder::default();
+        assert_eq!(recorder.handle("a"), MacroEvent::Pass);
+        assert_eq!(recorder.handle("F2"), MacroEvent::ChoosingRecordSlot);
+        assert!(recorder.is_choosing_slot());
+        assert_eq!(
+            recorder.handle("1"),
+            MacroEvent::RecordingStarted("1".into())
+        );
+        assert_eq!(recorder.recording_slot(), Some("1"));
+        assert_eq!(
+            feed(&mut recorder, &["l", "Down", "Enter"]),
+            vec![MacroEvent::Pass; 3]
+        );
+        assert_eq!(recorder.handle("F3"), MacroEvent::RecordingInProgress);
+        assert_eq!(
+            recorder.handle("F2"),
+            MacroEvent::Recorded {
+                slot: "1".into(),
+                keys: 3
+            }
+        );
+        assert_eq!(recorder.recording_slot(), None);
+        assert_eq!(recorder.handle("x"), MacroEvent::Pass);
+        assert_eq!(recorder.macros()["1"], ["l", "Down", "Enter"]);
+    }
+
+    #[test]
+    fn empty_recording_clears_the_slot() {
+        let mut recorder =
+            MacroRecorder::new(BTreeMap::from([("q".to_string(), vec!["s".to_string()])]));
+        feed(&mut recorder, &["F2", "q"]);
+        assert_eq!(
+            recorder.handle("F2"),
+            MacroEvent::Recorded {
+                slot: "q".into(),
+                keys: 0
+            }
+        );
+        assert!(recorder.macros().is_empty());
+    }
+
+    #[test]
+    fn replay_skips_macro_keys_and_reports_unknown_slots() {
+        let saved = vec!["b".to_string(), "F2".to_string(), "Enter".to_string()];
+        let mut recorder = MacroRecorder::new(BTreeMap::from([("1".to_string(), saved)]));
+        assert_eq!(recorder.handle("F3"), MacroEvent::ChoosingReplaySlot);
+        assert_eq!(
+            recorder.handle("1"),
+            MacroEvent::Replay {
+                slot: "1".into(),
+                keys: vec!["b".into(), "Enter".into()]
+            }
+        );
+        assert_eq!(
+            feed(&mut recorder, &["F3", "9"]),
+            [
+                MacroEvent::ChoosingReplaySlot,
+                MacroEvent::UnknownSlot("9".into())
+            ]
+        );
+        assert_eq!(
+            feed(&mut recorder, &["F3", "Esc", "F2", "F3"]),
+            [
+                MacroEvent::ChoosingReplaySlot,
+                MacroEvent::Cancelled,
+                MacroEvent::ChoosingRecordSlot,
+                MacroEvent::Cancelled
+            ]
+        );
+        assert!(!recorder.is_choosing_slot());
+    }
+}
@@ -3,10 +3,12 @@
 #![warn(clippy::pedantic)]
 
 use std::{
+    collections::HashMap,
     error::Error,
+    ffi::OsString,
     fs,
     path::{Path, PathBuf},
-    time::Duration,
+    time::{Duration, SystemTime},
 };
 
 use crossterm::event::{
@@ -16,26 +18,606 @@ use crossterm::terminal::{
     disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
 };
 use meeting_cost_tracker::{
-    load_attendees, load_categories, save_attendees, save_categories, AttendeeInfo,
-    EmployeeCategory, Meeting,
+    annualized_recurring_cost, export_meeting_tsv_to_dir, fallback_dump_dir,
+    flag_outlier_categories, format_duration, interpret_count_input, load_settings, mini_sparkline,
+    resolve_export_dir, save_settings, CategoryStore, CategoryUsage, EmployeeCategory, FileStore,
+    MacroEvent, MacroRecorder, Meeting, MeetingSummary, ResilientStore, RosterSnapshot,
+    RosterStore, RosterWarnings, Settings, StorageError, Trend, DEFAULT_TSV_FILE, SETTINGS_FILE,
 };
+#[cfg(feature = "http")]
+use meeting_cost_tracker::{load_attendees, serve, SharedMeeting};
 use ratatui::backend::CrosstermBackend;
 use ratatui::layout::{Alignment, Constraint, Direction, Layout, Rect};
 use ratatui::style::{Color, Modifier, Style};
+use ratatui::symbols;
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::widgets::{Axis, Block, Borders, Chart, Clear, Dataset, GraphType, Paragraph};
 use ratatui::Terminal;
+use serde::Serialize;
+
+/// Replaces the category at `idx` with one parsed from `Title:Salary` or
+/// `Title:Salary:Source` input.
+///
+/// The salary is parsed like [`parse_category_input`] and rounded per
+/// `settings`. Without a source the old one is kept, and an empty source
+/// clears it; everything else not typed is carried over per
+/// [`carry_over_category`]. If the title is unchanged, attendees of the
+/// category already in the meeting are repriced; a renamed category only
+/// affects attendees added from then on.
+///
+/// Returns a status message, or an error if the input is invalid or the new
+/// title is taken by another category.
+fn apply_category_edit(
+    categories: &mut [EmployeeCategory],
+    idx: usize,
+    input: &str,
+    settings: &Settings,
+    meeting: &mut Meeting,
+) -> Result<String, String> {
+    let (title, rest) = input
+        .split_once(':')
+        .ok_or_else(|| "Enter Title:Salary".to_string())?;
+    let (salary, source) = match rest.split_once(':') {
+        Some((salary, source)) => (salary, Some(source.trim().to_string())),
+        None => (rest, None),
+    };
+    let edited = parse_category_input(&format!("{title}:{salary}"), settings)?;
+    if categories
+        .iter()
+        .enumerate()
+        .any(|(i, c)| i != idx && c.title() == edited.title())
+    {
+        return Err(format!(
+            "A category named {} already exists",
+            edited.title()
+        ));
+    }
+    let existing = categories
+        .get_mut(idx)
+        .ok_or_else(|| "No category selected".to_string())?;
+    if existing.salary() != edited.salary() && existing.title() == edited.title() {
+        let _ = meeting.update_salary(edited.title(), edited.salary());
+    }
+    let mut edited = carry_over_category(existing, edited);
+    if let Some(source) = source {
+        edited.set_source(Some(source).filter(|s| !s.is_empty()));
+    }
+    let status = format!("Updated category {}", edited.title());
+    *existing = edited;
+    Ok(status)
+}
+
+/// Returns `edited`, which replaces `existing`, with what the user did not
+/// type carried over: the source if none was given, the default count and
+/// the last-used time.
+///
+/// The update time and the approximate flag are kept only if the salary is
+/// unchanged, and the salary range only while it still contains the salary,
+/// like [`EmployeeCategory::set_salary`] does.
+fn carry_over_category(existing: &EmployeeCategory, edited: EmployeeCategory) -> EmployeeCategory {
+    let mut edited = match existing.salary_range() {
+        Some((min, max)) => edited.clone().with_salary_range(min, max).unwrap_or(edited),
+        None => edited,
+    };
+    if edited.source().is_none() {
+        edited.set_source(existing.source().map(str::to_string));
+    }
+    edited.set_default_count(existing.default_count());
+    edited.set_last_used(existing.last_used());
+    if existing.salary() == edited.salary() {
+        edited.set_updated_at(existing.updated_at());
+        edited.set_approximate(existing.is_approximate());
+    }
+    edited
+}
+
+/// Environment variable that overrides [`data_dir`].
+const DATA_DIR_ENV: &str = "MCT_DATA_DIR";
 
 /// Returns the directory where persistent data should be stored.
+///
+/// See [`resolve_data_dir`] for how it is chosen.
 fn data_dir() -> PathBuf {
     let exe_path = std::env::current_exe().unwrap_or_else(|_| PathBuf::from("."));
-    let mut dir = exe_path
+    resolve_data_dir(std::env::var_os(DATA_DIR_ENV), &exe_path)
+}
+
+/// Chooses the data directory from the value of `MCT_DATA_DIR` and the path
+/// of the executable.
+///
+/// `env` wins if set and non-empty; otherwise a `data` directory next to
+/// `exe` is used.
+fn resolve_data_dir(env: Option<OsString>, exe: &Path) -> PathBuf {
+    if let Some(dir) = env.filter(|d| !d.is_empty()) {
+        return PathBuf::from(dir);
+    }
+    let mut dir = exe
         .parent()
         .map_or_else(|| PathBuf::from("."), Path::to_path_buf);
     dir.push("data");
     dir
 }
 
+/// Command-line options of the TUI.
+#[derive(Debug, Default, PartialEq)]
+struct CliOptions {
+    /// Overrides the export directory from the settings.
+    export_dir: Option<PathBuf>,
+    /// Where to write the JSON summary of the run on exit.
+    exit_summary_file: Option<PathBuf>,
+}
+
+/// Parses the TUI's command-line arguments.
+///
+/// Each option takes a path, given as either `--option PATH` or
+/// `--option=PATH`.
+fn parse_cli_args<I: IntoIterator<Item = String>>(args: I) -> Result<CliOptions, String> {
+    let mut options = CliOptions::default();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let (name, inline) = match arg.split_once('=') {
+            Some((name, value)) => (name.to_string(), Some(value.to_string())),
+            None => (arg, None),
+        };
+        let slot = match name.as_str() {
+            "--export-dir" => &mut options.export_dir,
+            "--exit-summary-file" => &mut options.exit_summary_file,
+            _ => return Err(format!("unrecognized argument: {name}")),
+        };
+        let value = match inline {
+            Some(value) => value,
+            None => args
+                .next()
+                .ok_or_else(|| format!("{name} requires a path"))?,
+        };
+        *slot = Some(PathBuf::from(value));
+    }
+    Ok(options)
+}
+
+/// What happened during a run, for the `--exit-summary-file` report.
+///
+/// Filled in by the code that shows results in the status line, so wrapper
+/// scripts learn about errors the user may have missed.
+#[derive(Debug, Default, PartialEq, Serialize)]
+struct AppEvents {
+    /// Files written during the run, such as exports.
+    files_written: Vec<PathBuf>,
+    /// Errors shown in the status line.
+    errors: Vec<String>,
+}
+
+impl AppEvents {
+    /// Shows `message` in the status line and records it as an error.
+    fn error(&mut self, status: &mut Option<String>, message: impl Into<String>) {
+        let message = message.into();
+        self.errors.push(message.clone());
+        *status = Some(message);
+    }
+}
+
+/// Document written to `--exit-summary-file` when the TUI exits.
+#[derive(Debug, Serialize)]
+struct ExitSummary<'a> {
+    /// The meeting as it stood at exit.
+    summary: MeetingSummary,
+    /// Files written and errors seen during the run.
+    #[serde(flatten)]
+    events: &'a AppEvents,
+}
+
+/// Writes the meeting summary and `events` to `path` as JSON.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be written.
+fn write_exit_summary(path: &Path, meeting: &Meeting, events: &AppEvents) -> std::io::Result<()> {
+    let summary = ExitSummary {
+        summary: meeting.summary(),
+        events,
+    };
+    let json = serde_json::to_string_pretty(&summary).map_err(std::io::Error::other)?;
+    fs::write(path, json)
+}
+
+/// Options of the `daemon` subcommand.
+#[cfg(feature = "http")]
+#[derive(Debug, PartialEq)]
+struct DaemonOptions {
+    /// Attendee roster to load before serving.
+    load: Option<PathBuf>,
+    /// Address to listen on.
+    listen: String,
+}
+
+/// Parses the arguments following `daemon`.
+///
+/// Accepts `--load PATH` and `--listen ADDR`, with `ADDR` defaulting to
+/// `127.0.0.1:7878`.
+#[cfg(feature = "http")]
+fn parse_daemon_args<I: IntoIterator<Item = String>>(args: I) -> Result<DaemonOptions, String> {
+    let mut options = DaemonOptions {
+        load: None,
+        listen: "127.0.0.1:7878".to_string(),
+    };
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--load" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| "--load requires a path".to_string())?;
+                options.load = Some(PathBuf::from(value));
+            }
+            "--listen" => {
+                options.listen = args
+                    .next()
+                    .ok_or_else(|| "--listen requires an address".to_string())?;
+            }
+            _ => return Err(format!("unrecognized argument: {arg}")),
+        }
+    }
+    Ok(options)
+}
+
+/// Runs a meeting without a TUI, serving its status over HTTP.
+#[cfg(feature = "http")]
+fn run_daemon<I: IntoIterator<Item = String>>(args: I) -> Result<(), Box<dyn Error>> {
+    use std::net::TcpListener;
+
+    let options = parse_daemon_args(args)?;
+    let categories = FileStore::new(data_dir()).load_categories()?;
+    let mut meeting = Meeting::new();
+    if let Some(path) = &options.load {
+        for entry in load_attendees(path)? {
+            let Some(cat) = categories.iter().find(|c| c.title() == entry.title) else {
+                eprintln!("Skipping unknown category {}", entry.title);
+                continue;
+            };
+            meeting.add_roster_entry(cat, &entry)?;
+        }
+    }
+    let listener = TcpListener::bind(&options.listen)?;
+    eprintln!("Listening on http://{}", listener.local_addr()?);
+    serve(&listener, &SharedMeeting::new(meeting))?;
+    Ok(())
+}
+
+#[cfg(not(feature = "http"))]
+fn run_daemon<I: IntoIterator<Item = String>>(_args: I) -> Result<(), Box<dyn Error>> {
+    Err("mct daemon requires building with the `http` feature".into())
+}
+
+/// Largest attendee count accepted in one entry.
+const MAX_COUNT_INPUT: u32 = 10_000;
+
+/// Characters in the cost sparkline next to each attendee group.
+const SPARKLINE_WIDTH: usize = 10;
+
+/// How far Left and Right move the cursor in [`Mode::Review`].
+const REVIEW_STEP: Duration = Duration::from_secs(30);
+
+/// Characters in the scrubber bar of [`Mode::Review`].
+const SCRUBBER_WIDTH: usize = 40;
+
+/// Longest delay accepted in [`Mode::ArmStart`].
+const MAX_ARM_DELAY: Duration = Duration::from_hours(24);
+
+/// Count added for an empty entry in the attendee count prompt for the
+/// category at `idx`.
+fn empty_count_default(
+    settings: &Settings,
+    categories: &[EmployeeCategory],
+    idx: Option<usize>,
+) -> u32 {
+    idx.and_then(|idx| categories.get(idx))
+        .map_or(1, |cat| settings.attendee_count_default(cat))
+}
+
+/// Title of the attendee count prompt, showing how the input will be read.
+fn count_input_preview(input: &str, default: u32) -> String {
+    match interpret_count_input(input, default, MAX_COUNT_INPUT) {
+        Ok(count) => format!("Enter attendee count (Enter adds {count})"),
+        Err(err) => format!("Enter attendee count ({err})"),
+    }
+}
+
+/// Applies a clock correction typed as `+N`, `-N` or `N` minutes.
+///
+/// Returns `false` without changing the meeting if the input is invalid.
+fn apply_elapsed_adjustment(meeting: &mut Meeting, input: &str) -> bool {
+    let input = input.trim();
+    let (sign, digits) = match input.chars().next() {
+        Some(c @ ('+' | '-')) => (Some(c), &input[1..]),
+        _ => (None, input),
+    };
+    let Ok(minutes) = digits.trim().parse::<u64>() else {
+        return false;
+    };
+    let amount = Duration::from_secs(minutes.saturating_mul(60));
+    match sign {
+        Some('+') => meeting.add_elapsed(amount),
+        Some(_) => meeting.subtract_elapsed(amount),
+        None => meeting.set_elapsed(amount),
+    }
+    true
+}
+
+/// Status-bar warning about people named under more than one group.
+fn duplicate_warning(meeting: &Meeting) -> Option<String> {
+    let duplicates = meeting.duplicate_names();
+    if duplicates.is_empty() {
+        return None;
+    }
+    let names: Vec<String> = duplicates
+        .iter()
+        .map(|(name, titles)| format!("{name} ({})", titles.join(", ")))
+        .collect();
+    let action = if meeting.strict_duplicate_names() {
+        "billed once"
+    } else {
+        "counted twice"
+    };
+    Some(format!(
+        "Listed under several groups, {action}: {}",
+        names.join("; ")
+    ))
+}
+
+/// Arrow shown next to the cost, with its color, for the burn rate's trend.
+fn trend_arrow(trend: Trend) -> Option<(&'static str, Color)> {
+    match trend {
+        Trend::Increasing => Some(("▲", Color::Red)),
+        Trend::Decreasing => Some(("▼", Color::Green)),
+        Trend::Steady => None,
+    }
+}
+
+/// Parses the add-category prompt: `Title:Salary`, or `Title:observer` for
+/// guests such as customers who cost nothing.
+///
+/// A salary of zero is rejected with a hint to use the observer syntax, so a
+/// typo does not silently add a free category.
+fn parse_category_input(input: &str, settings: &Settings) -> Result<EmployeeCategory, String> {
+    let Some((title, salary)) = input.split_once(':') else {
+        return Err("Enter Title:Salary or Title:observer".to_string());
+    };
+    let (title, salary) = (title.trim(), salary.trim());
+    if salary.eq_ignore_ascii_case("observer") {
+        return EmployeeCategory::observer(title).map_err(|err| err.to_string());
+    }
+    let salary: u64 = salary
+        .parse()
+        .map_err(|_| format!("Invalid salary: {salary}"))?;
+    if salary == 0 {
+        return Err(format!(
+            "Salary must be greater than zero; enter {title}:observer for a guest"
+        ));
+    }
+    EmployeeCategory::new(title, settings.round_salary(salary)).map_err(|err| err.to_string())
+}
+
+/// Indices into `categories` listed by the add-attendee picker, leaving out
+/// categories already in `meeting` if `hide_used` is set.
+fn attendee_choices(
+    meeting: &Meeting,
+    categories: &[EmployeeCategory],
+    hide_used: bool,
+) -> Vec<usize> {
+    if !hide_used {
+        return (0..categories.len()).collect();
+    }
+    let unused = meeting.unused_categories(categories);
+    categories
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| unused.iter().any(|u| u.title() == c.title()))
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+/// Titles an untitled meeting when it is first started, so reports and
+/// exports name it; see [`Meeting::default_name`]. No calendar is imported
+/// yet, so the name comes from `start`, in local time if `settings` has a UTC
+/// offset.
+fn name_if_untitled(meeting: &mut Meeting, start: SystemTime, settings: &Settings) {
+    if meeting.title().is_none() {
+        meeting.set_title(Meeting::default_name(
+            start,
+            None,
+            settings.utc_offset_minutes,
+        ));
+    }
+}
+
+/// Text of the header bar, followed by the meeting's title once it has one.
+fn header_text(meeting: &Meeting) -> String {
+    match meeting.title() {
+        Some(title) => format!("Meeting Cost Tracker — {title}"),
+        None => "Meeting Cost Tracker".to_string(),
+    }
+}
+
+/// Keys other than characters and function keys that macros can record, by
+/// their spec.
+const NAMED_KEYS: [(&str, KeyCode); 14] = [
+    ("Space", KeyCode::Char(' ')),
+    ("Enter", KeyCode::Enter),
+    ("Esc", KeyCode::Esc),
+    ("Backspace", KeyCode::Backspace),
+    ("Tab", KeyCode::Tab),
+    ("Up", KeyCode::Up),
+    ("Down", KeyCode::Down),
+    ("Left", KeyCode::Left),
+    ("Right", KeyCode::Right),
+    ("Home", KeyCode::Home),
+    ("End", KeyCode::End),
+    ("PageUp", KeyCode::PageUp),
+    ("PageDown", KeyCode::PageDown),
+    ("Delete", KeyCode::Delete),
+];
+
+/// Spec of `code` for keyboard macros, e.g. `a`, `Enter` or `F2`, or `None`
+/// for keys macros cannot record.
+fn key_spec(code: KeyCode) -> Option<String> {
+    if let Some((spec, _)) = NAMED_KEYS.iter().find(|(_, key)| *key == code) {
+        return Some((*spec).to_string());
+    }
+    match code {
+        KeyCode::Char(c) => Some(c.to_string()),
+        KeyCode::F(n) => Some(format!("F{n}")),
+        _ => None,
+    }
+}
+
+/// Key described by a macro key spec; the inverse of [`key_spec`].
+fn parse_key_spec(spec: &str) -> Option<KeyCode> {
+    if let Some((_, key)) = NAMED_KEYS.iter().find(|(name, _)| *name == spec) {
+        return Some(*key);
+    }
+    let mut chars = spec.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(KeyCode::Char(c)),
+        _ => spec
+            .strip_prefix('F')
+            .and_then(|n| n.parse().ok())
+            .map(KeyCode::F),
+    }
+}
+
+/// Passes a key press to the macro recorder and then, unless the recorder
+/// used it, to [`process_key`].
+///
+/// Replayed keys go straight to [`process_key`], so they are neither
+/// recorded again nor able to start another recording or replay.
+fn handle_key<S: CategoryStore + RosterStore>(
+    key_event: crossterm::event::KeyEvent,
+    app: &mut App<S>,
+) {
+    let Some(spec) = key_spec(key_event.code) else {
+        process_key(key_event, app);
+        return;
+    };
+    let message = match app.macros.handle(&spec) {
+        MacroEvent::Pass => {
+            process_key(key_event, app);
+            return;
+        }
+        MacroEvent::ChoosingRecordSlot => "Press a key to name the macro to record".to_string(),
+        MacroEvent::ChoosingReplaySlot => "Press the key of the macro to replay".to_string(),
+        MacroEvent::RecordingStarted(slot) => {
+            format!("Recording macro {slot}; press F2 to stop")
+        }
+        MacroEvent::Recorded { slot, keys } => {
+            app.settings.macros = app.macros.macros().clone();
+            if let Some(path) = &app.settings_file {
+                if let Err(e) = save_settings(path, &app.settings) {
+                    app.events
+                        .error(&mut app.status, format!("Failed to save macro: {e}"));
+                    return;
+                }
+            }
+            match keys {
+                0 => format!("Cleared macro {slot}"),
+                1 => format!("Saved macro {slot} (1 key)"),
+                keys => format!("Saved macro {slot} ({keys} keys)"),
+            }
+        }
+        MacroEvent::Replay { slot, keys } => {
+            for code in keys.iter().filter_map(|spec| parse_key_spec(spec)) {
+                process_key(
+                    crossterm::event::KeyEvent::new(code, crossterm::event::KeyModifiers::NONE),
+                    app,
+                );
+            }
+            if app.status.is_some() {
+                return;
+            }
+            format!("Replayed macro {slot}")
+        }
+        MacroEvent::UnknownSlot(slot) => format!("No macro recorded as {slot}"),
+        MacroEvent::Cancelled => "Macro cancelled".to_string(),
+        MacroEvent::RecordingInProgress => {
+            "Stop recording with F2 before replaying a macro".to_string()
+        }
+    };
+    app.status = Some(message);
+}
+
+/// Headcount for the current meeting panel, e.g. `8 + 2 observers`.
+fn headcount_label(meeting: &Meeting) -> String {
+    let paid = meeting.total_attendees();
+    match meeting.observer_count() {
+        0 => paid.to_string(),
+        1 => format!("{paid} + 1 observer"),
+        observers => format!("{paid} + {observers} observers"),
+    }
+}
+
+/// Formats the meeting's total cost, prefixed with `~` when it is computed
+/// from approximate salaries.
+fn total_cost_label(cost: f64, approximate: bool) -> String {
+    let prefix = if approximate { "~" } else { "" };
+    format!("{prefix}${cost:.2}")
+}
+
+/// Line showing the average cost per paid attendee, or `None` while the
+/// meeting has none.
+fn per_attendee_line(meeting: &Meeting) -> Option<String> {
+    (meeting.total_attendees() > 0)
+        .then(|| format!("${:.2} per attendee", meeting.cost_per_attendee()))
+}
+
+/// Weeks in a year, for the recurring cost shown below the meeting cost.
+const WEEKS_PER_YEAR: u32 = 52;
+
+/// Line projecting the meeting's cost if it were held every week, or `None`
+/// while it has cost nothing.
+fn weekly_cost_line(cost: f64) -> Option<String> {
+    (cost > 0.0).then(|| {
+        format!(
+            "This weekly meeting costs ~${:.0}/year.",
+            annualized_recurring_cost(cost, WEEKS_PER_YEAR)
+        )
+    })
+}
+
+/// Bar of `width` characters with a cursor at `offset` of `total`.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+fn scrubber_bar(offset: Duration, total: Duration, width: usize) -> String {
+    let last = width.saturating_sub(1);
+    let cursor = if total.is_zero() {
+        0
+    } else {
+        let fraction = (offset.as_secs_f64() / total.as_secs_f64()).clamp(0.0, 1.0);
+        (fraction * last as f64).round() as usize
+    };
+    (0..width)
+        .map(|i| match i.cmp(&cursor) {
+            std::cmp::Ordering::Less => '━',
+            std::cmp::Ordering::Equal => '●',
+            std::cmp::Ordering::Greater => '─',
+        })
+        .collect()
+}
+
+/// Corner points of the burn rate step chart, in minutes and dollars per minute.
+///
+/// Each step is held flat until the next change, and the last one until `now`.
+fn rate_chart_points(steps: &[(Duration, f64)], now: Duration) -> Vec<(f64, f64)> {
+    let mut points = Vec::with_capacity(steps.len() * 2);
+    for (i, &(at, rate)) in steps.iter().enumerate() {
+        let until = steps.get(i + 1).map_or(now.max(at), |next| next.0);
+        points.push((at.as_secs_f64() / 60.0, rate));
+        points.push((until.as_secs_f64() / 60.0, rate));
+    }
+    points
+}
+
 /// Calculates a centered rectangle taking up the given percentage of the parent area.
 fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
@@ -56,14 +638,6 @@ fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         .split(popup_layout[1])[1]
 }
 
-fn format_duration(d: Duration) -> String {
-    let secs = d.as_secs();
-    let hours = secs / 3600;
-    let minutes = (secs % 3600) / 60;
-    let seconds = secs % 60;
-    format!("{hours:02}:{minutes:02}:{seconds:02}")
-}
-
 /// UI modes controlling user interaction.
 enum Mode {
     /// Normal viewing mode where meeting stats are displayed.
@@ -72,6 +646,11 @@ enum Mode {
     AddCategory,
     /// Mode for deleting an existing [`EmployeeCategory`].
     DeleteCategory,
+    /// Mode for selecting a category to edit.
+    EditCategorySelect,
+    /// Mode for editing the `Title:Salary` of the category chosen in
+    /// [`Mode::EditCategorySelect`].
+    EditCategory,
     /// Mode for selecting a category when adding attendees.
     AddAttendeeSelect,
     /// Mode for entering the attendee count after selecting a category.
@@ -82,19 +661,133 @@ enum Mode {
     SaveAttendees,
     /// Mode for loading attendees from disk.
     LoadAttendees,
+    /// Mode for entering a countdown in seconds before the meeting starts.
+    ArmStart,
+    /// Mode for entering the meeting budget in dollars.
+    SetBudget,
+    /// Mode for correcting the meeting clock in minutes.
+    AdjustElapsed,
+    /// Mode for confirming a freshly loaded roster that exceeded a warning threshold.
+    ConfirmRoster,
+    /// Mode for confirming that the meeting should be reset.
+    ConfirmReset,
+    /// Mode for confirming removal of the groups marked in [`Mode::RemoveAttendee`].
+    ConfirmRemoveGroups,
+    /// Mode for scrubbing through a stopped meeting's timeline.
+    Review,
 }
 
-#[allow(clippy::too_many_arguments, clippy::too_many_lines)]
-fn render_ui(
-    terminal: &mut Terminal<CrosstermBackend<std::io::Stdout>>,
-    meeting: &Meeting,
-    categories: &[EmployeeCategory],
-    mode: &Mode,
-    input_text: &str,
+/// State shared between the event loop, key handling, and rendering.
+struct App<S> {
+    /// Backend holding categories and saved rosters.
+    store: S,
+    /// The meeting being tracked.
+    meeting: Meeting,
+    /// Known employee categories, persisted on exit.
+    categories: Vec<EmployeeCategory>,
+    /// Preferences loaded at startup.
+    settings: Settings,
+    /// Current interaction mode.
+    mode: Mode,
+    /// Text typed into the current input prompt.
+    input_text: String,
+    /// Whether salaries are shown next to category titles.
     show_salaries: bool,
-    files: &[String],
+    /// Whether the burn rate chart replaces the category list.
+    show_rate_chart: bool,
+    /// Whether the add-attendee picker hides categories already in the meeting.
+    hide_used_categories: bool,
+    /// Attendee files listed by the load picker.
+    files: Vec<String>,
+    /// Highlighted row in the active popup list.
     selected: usize,
+    /// Category chosen in [`Mode::AddAttendeeSelect`].
+    add_attendee_idx: Option<usize>,
+    /// Category chosen in [`Mode::EditCategorySelect`].
+    edit_category_idx: Option<usize>,
+    /// Attendee groups marked for removal in [`Mode::RemoveAttendee`].
+    marked: Vec<String>,
+    /// Roster to revert to from [`Mode::ConfirmRoster`].
+    roster_backup: Option<RosterSnapshot>,
+    /// Message shown in the status line until the next key press.
+    status: Option<String>,
+    /// Directory that exports are written to.
+    export_dir: PathBuf,
+    /// Recency of category use, for evicting categories past the limit.
+    category_usage: CategoryUsage,
+    /// Files written and errors seen, for the exit summary.
+    events: AppEvents,
+    /// Meeting time shown in [`Mode::Review`].
+    review_offset: Duration,
+    /// Keyboard macros, recorded with F2 and replayed with F3.
+    macros: MacroRecorder,
+    /// File that recorded macros are saved to, along with the other settings.
+    settings_file: Option<PathBuf>,
+}
+
+impl<S: CategoryStore + RosterStore> App<S> {
+    /// Creates the application state, loading categories from `store`.
+    fn new(store: S, settings: Settings, export_dir: PathBuf) -> Result<Self, Box<dyn Error>> {
+        let categories = store.load_categories()?;
+        let mut meeting = Meeting::new();
+        if let Some(minutes) = settings.max_meeting_minutes {
+            meeting.set_max_duration(Duration::from_mins(minutes));
+        }
+        meeting.set_strict_duplicate_names(settings.strict_duplicate_names);
+        let macros = MacroRecorder::new(settings.macros.clone());
+        let category_usage = CategoryUsage::from_categories(&categories);
+        Ok(Self {
+            store,
+            meeting,
+            categories,
+            settings,
+            mode: Mode::View,
+            input_text: String::new(),
+            show_salaries: false,
+            show_rate_chart: false,
+            hide_used_categories: false,
+            files: Vec::new(),
+            selected: 0,
+            add_attendee_idx: None,
+            edit_category_idx: None,
+            marked: Vec::new(),
+            roster_backup: None,
+            status: None,
+            export_dir,
+            category_usage,
+            events: AppEvents::default(),
+            review_offset: Duration::ZERO,
+            macros,
+            settings_file: None,
+        })
+    }
+}
+
+#[allow(clippy::too_many_lines)]
+fn render_ui<S>(
+    terminal: &mut Terminal<CrosstermBackend<std::io::Stdout>>,
+    app: &App<S>,
 ) -> std::io::Result<()> {
+    let App {
+        meeting,
+        categories,
+        settings,
+        mode,
+        input_text,
+        show_salaries,
+        show_rate_chart,
+        hide_used_categories,
+        files,
+        selected,
+        add_attendee_idx,
+        marked,
+        status,
+        review_offset,
+        macros,
+        ..
+    } = app;
+    let count_default = empty_count_default(settings, categories, *add_attendee_idx);
+    let (input_text, show_salaries, selected) = (input_text.as_str(), *show_salaries, *selected);
     terminal.draw(|f| {
         let size = f.area();
         let chunks = Layout::default()
@@ -109,7 +802,7 @@ fn render_ui(
             ])
             .split(size);
 
-        let title = Paragraph::new("Meeting Cost Tracker")
+        let title = Paragraph::new(header_text(meeting))
             .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
         f.render_widget(title, chunks[0]);
 
@@ -118,27 +811,84 @@ fn render_ui(
         let cost = meeting.total_cost();
         let cost_display = if cost == 0.0 { 0.0 } else { cost };
 
-        let status = Paragraph::new(Line::from(vec![
+        let mut status_spans = vec![if let Some(countdown) = meeting.time_until_start() {
+            Span::styled(
+                format!("[Armed] Starts in {}", format_duration(countdown)),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )
+        } else {
             Span::styled(
                 format!(
                     "[{}] Duration: {}",
-                    if running { "Running" } else { "Stopped" },
-                    format_duration(duration)
+                    if running {
+                        "Running"
+                    } else if meeting.is_auto_stopped() {
+                        "Auto-stopped (max reached)"
+                    } else {
+                        "Stopped"
+                    },
+                    meeting.elapsed_formatted()
                 ),
                 Style::default()
                     .fg(if running { Color::Green } else { Color::Red })
                     .add_modifier(Modifier::BOLD),
-            ),
-        ]));
-        f.render_widget(status, chunks[1]);
+            )
+        }];
+        if let Some(eta) = meeting.time_until_budget_exhausted() {
+            let text = if eta.is_zero() {
+                "  budget spent".to_string()
+            } else {
+                format!("  budget lasts another {}", format_duration(eta))
+            };
+            status_spans.push(Span::styled(text, Style::default().fg(Color::Cyan)));
+        }
+        if let Some(slot) = macros.recording_slot() {
+            status_spans.push(Span::styled(
+                format!("  ● REC {slot}"),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ));
+        }
+        if let Some(message) = status {
+            status_spans.push(Span::styled(
+                format!("  {message}"),
+                Style::default().fg(Color::Yellow),
+            ));
+        }
+        f.render_widget(Paragraph::new(Line::from(status_spans)), chunks[1]);
 
-        let cost_widget = Paragraph::new(Line::from(Span::styled(
-            format!("${cost_display:.2}"),
+        let cost_color = if meeting.is_over_budget() {
+            Color::Red
+        } else {
+            Color::Green
+        };
+        let mut cost_spans = vec![Span::styled(
+            total_cost_label(cost_display, meeting.is_approximate()),
             Style::default()
-                .fg(Color::Green)
+                .fg(cost_color)
                 .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
-        )))
-        .alignment(Alignment::Center);
+        )];
+        if let Some((arrow, color)) = trend_arrow(meeting.rate_trend()) {
+            cost_spans.push(Span::styled(
+                format!(" {arrow}"),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            ));
+        }
+        let mut cost_lines = vec![Line::from(cost_spans)];
+        if let Some(line) = per_attendee_line(meeting) {
+            cost_lines.push(Line::from(Span::styled(
+                line,
+                Style::default().fg(Color::Gray),
+            )));
+        }
+        if let Some(line) = weekly_cost_line(cost) {
+            cost_lines.push(Line::from(Span::styled(
+                line,
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+        let cost_widget = Paragraph::new(cost_lines).alignment(Alignment::Center);
         f.render_widget(cost_widget, chunks[2]);
 
         match mode {
@@ -150,7 +900,7 @@ fn render_ui(
             Mode::View => {
                 let help = Paragraph::new(Line::from(vec![
                     Span::styled(
-                        "[s] Start/Stop  [c] Reset  [a] Add Category  [d] Delete Category  [e] Add Employee  [r] Remove Employee  [w] Save Attendees  [l] Load Attendees  [p] Toggle Salaries [q] Quit",
+                        "[s] Start/Stop  [t] Timed Start  [c] Reset  [b] Budget  [m] Adjust Time  [a] Add Category  [d] Delete Category  [x] Edit Category  [e] Add Employee  [r] Remove Employee  [w] Save Attendees  [l] Load Attendees  [o] Export  [v] Review  [u] Undo  [p] Toggle Salaries  [g] Rate Chart  [F2] Record Macro  [F3] Play Macro  [q] Quit",
                         Style::default().fg(Color::Yellow),
                     ),
                 ]))
@@ -158,7 +908,12 @@ fn render_ui(
                 f.render_widget(help, chunks[4]);
             }
             Mode::AddAttendeeSelect => {
-                let input_widget = Paragraph::new("")
+                let hint = if *hide_used_categories {
+                    "[Enter] Add...  [+] Add one  [h] Show categories already added"
+                } else {
+                    "[Enter] Add...  [+] Add one  [h] Hide categories already added"
+                };
+                let input_widget = Paragraph::new(hint)
                     .block(
                         Block::default()
                             .title("Select category to add")
@@ -170,14 +925,19 @@ fn render_ui(
                 let input_widget = Paragraph::new(input_text)
                     .block(
                         Block::default()
-                            .title("Enter attendee count")
+                            .title(count_input_preview(input_text, count_default))
                             .borders(Borders::ALL),
                     );
                 f.render_widget(input_widget, chunks[4]);
             }
             Mode::RemoveAttendee => {
-                let input_widget = Paragraph::new("")
-                    .block(Block::default().title("Select attendee to remove").borders(Borders::ALL));
+                let input_widget = Paragraph::new("[Space] Mark  [+/-] Adjust Count  [Enter] Remove  [Esc] Cancel")
+                    .block(Block::default().title("Select attendees to remove").borders(Borders::ALL));
+                f.render_widget(input_widget, chunks[4]);
+            }
+            Mode::ConfirmRemoveGroups => {
+                let input_widget = Paragraph::new("[y] Remove  [n] Cancel")
+                    .block(Block::default().title("Confirm removal").borders(Borders::ALL));
                 f.render_widget(input_widget, chunks[4]);
             }
             Mode::SaveAttendees => {
@@ -195,6 +955,48 @@ fn render_ui(
                     .block(Block::default().title("Select category to delete").borders(Borders::ALL));
                 f.render_widget(input_widget, chunks[4]);
             }
+            Mode::EditCategorySelect => {
+                let input_widget = Paragraph::new("")
+                    .block(Block::default().title("Select category to edit").borders(Borders::ALL));
+                f.render_widget(input_widget, chunks[4]);
+            }
+            Mode::EditCategory => {
+                let input_widget = Paragraph::new(input_text)
+                    .block(Block::default().title("Edit: Title:Salary[:Source]").borders(Borders::ALL));
+                f.render_widget(input_widget, chunks[4]);
+            }
+            Mode::SetBudget => {
+                let input_widget = Paragraph::new(input_text)
+                    .block(Block::default().title("Budget in dollars (empty to clear)").borders(Borders::ALL));
+                f.render_widget(input_widget, chunks[4]);
+            }
+            Mode::AdjustElapsed => {
+                let input_widget = Paragraph::new(input_text)
+                    .block(Block::default().title("Minutes: +N add, -N subtract, N set").borders(Borders::ALL));
+                f.render_widget(input_widget, chunks[4]);
+            }
+            Mode::ArmStart => {
+                let input_widget = Paragraph::new(input_text)
+                    .block(Block::default().title("Start in how many seconds?").borders(Borders::ALL));
+                f.render_widget(input_widget, chunks[4]);
+            }
+            Mode::ConfirmRoster => {
+                let input_widget = Paragraph::new("[y] Keep roster  [n] Revert to previous roster")
+                    .block(Block::default().title("Confirm roster").borders(Borders::ALL));
+                f.render_widget(input_widget, chunks[4]);
+            }
+            Mode::ConfirmReset => {
+                let input_widget = Paragraph::new("[y] Reset  [n] Cancel")
+                    .block(Block::default().title("Confirm reset").borders(Borders::ALL));
+                f.render_widget(input_widget, chunks[4]);
+            }
+            Mode::Review => {
+                let input_widget = Paragraph::new(
+                    "[←/→] Move 30s  [Home/End] Start/End  [Esc] Leave review",
+                )
+                .block(Block::default().title("Review").borders(Borders::ALL));
+                f.render_widget(input_widget, chunks[4]);
+            }
         }
 
         let lists = Layout::default()
@@ -202,37 +1004,100 @@ fn render_ui(
             .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
             .split(chunks[3]);
 
+        let stale_age = settings.stale_salary_age();
+        let outliers = settings
+            .salary_outlier_factor
+            .filter(|&factor| factor > 0.0)
+            .map(|factor| flag_outlier_categories(categories, factor))
+            .unwrap_or_default();
         let category_list: Vec<Line> = categories
             .iter()
             .map(|c| {
-                let text = if show_salaries {
+                let text = if c.is_observer() {
+                    format!("{} (observer)", c.title())
+                } else if show_salaries {
                     format!("{}: ${}", c.title(), c.salary())
                 } else {
                     c.title().to_string()
                 };
-                Line::from(Span::styled(text, Style::default().fg(Color::Cyan)))
+                let mut spans = vec![Span::styled(text, Style::default().fg(Color::Cyan))];
+                if stale_age.is_some_and(|age| c.is_stale(age)) {
+                    spans.push(Span::styled(" (stale)", Style::default().fg(Color::Red)));
+                }
+                if outliers.iter().any(|o| o.title() == c.title()) {
+                    spans.push(Span::styled(
+                        " (check salary)",
+                        Style::default().fg(Color::Yellow),
+                    ));
+                }
+                Line::from(spans)
             })
             .collect();
-        let list_widget = Paragraph::new(category_list)
-            .block(Block::default().borders(Borders::ALL).title("Employee Categories"));
-        f.render_widget(list_widget, lists[1]);
+        if *show_rate_chart {
+            let points = rate_chart_points(&meeting.rate_steps(), duration);
+            let max_minutes = points.iter().map(|p| p.0).fold(1.0, f64::max);
+            let max_rate = points.iter().map(|p| p.1).fold(1.0, f64::max);
+            let dataset = Dataset::default()
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(Color::Cyan))
+                .data(&points);
+            let chart = Chart::new(vec![dataset])
+                .block(Block::default().borders(Borders::ALL).title("Burn Rate"))
+                .x_axis(
+                    Axis::default()
+                        .title("min")
+                        .bounds([0.0, max_minutes])
+                        .labels(["0".to_string(), format!("{max_minutes:.0}")]),
+                )
+                .y_axis(
+                    Axis::default()
+                        .title("$/min")
+                        .bounds([0.0, max_rate * 1.1])
+                        .labels(["0".to_string(), format!("{max_rate:.2}")]),
+                );
+            f.render_widget(chart, lists[1]);
+        } else {
+            let list_widget = Paragraph::new(category_list)
+                .block(Block::default().borders(Borders::ALL).title("Employee Categories"));
+            f.render_widget(list_widget, lists[1]);
+        }
 
+        let sparklines: HashMap<String, Vec<f64>> = meeting
+            .category_cost_series(SPARKLINE_WIDTH, settings.sparkline_categories)
+            .into_iter()
+            .collect();
         let meeting_list: Vec<Line> = meeting
-            .attendees()
-            .map(|(title, _salary, count)| {
-                Line::from(Span::styled(
+            .attendees_by_cost()
+            .into_iter()
+            .map(|(title, _salary, count, _share)| {
+                let mut spans = vec![Span::styled(
                     format!("{title} x {count}"),
                     Style::default().fg(Color::Magenta),
-                ))
+                )];
+                if let Some(series) = sparklines.get(title) {
+                    spans.push(Span::styled(
+                        format!(" {}", mini_sparkline(series, SPARKLINE_WIDTH)),
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                }
+                Line::from(spans)
             })
             .collect();
         let meeting_widget = Paragraph::new(meeting_list)
-            .block(Block::default().borders(Borders::ALL).title("Current Meeting"));
+            .block(Block::default().borders(Borders::ALL).title(format!(
+                "Current Meeting ({})",
+                headcount_label(meeting)
+            )));
         f.render_widget(meeting_widget, lists[0]);
 
         if matches!(
             mode,
-            Mode::LoadAttendees | Mode::DeleteCategory | Mode::RemoveAttendee | Mode::AddAttendeeSelect
+            Mode::LoadAttendees
+                | Mode::DeleteCategory
+                | Mode::EditCategorySelect
+                | Mode::RemoveAttendee
+                | Mode::AddAttendeeSelect
         ) {
             let area = centered_rect(50, 50, size);
             let (title, items): (&str, Vec<Line>) = match mode {
@@ -255,8 +1120,8 @@ fn render_ui(
                     };
                     ("Load attendees", items)
                 }
-                Mode::DeleteCategory => {
-                    let items: Vec<Line> = categories
+                Mode::DeleteCategory | Mode::EditCategorySelect => {
+                    let mut items: Vec<Line> = categories
                         .iter()
                         .enumerate()
                         .map(|(i, cat)| {
@@ -268,26 +1133,44 @@ fn render_ui(
                             Line::from(Span::styled(cat.title().to_string(), style))
                         })
                         .collect();
-                    ("Delete category", items)
+                    if let Some(cat) = categories.get(selected) {
+                        items.push(Line::from(""));
+                        items.push(Line::from(Span::styled(
+                            format!(
+                                "${}/year, source: {}",
+                                cat.salary(),
+                                cat.source().unwrap_or("unknown")
+                            ),
+                            Style::default().fg(Color::DarkGray),
+                        )));
+                    }
+                    if matches!(mode, Mode::DeleteCategory) {
+                        ("Delete category", items)
+                    } else {
+                        ("Edit category", items)
+                    }
                 }
                 Mode::RemoveAttendee => {
                     let items: Vec<Line> = meeting
-                        .attendees()
+                        .attendees_by_cost()
+                        .into_iter()
                         .enumerate()
-                        .map(|(i, (title, _salary, count))| {
+                        .map(|(i, (title, _salary, count, _share))| {
                             let style = if i == selected {
                                 Style::default().add_modifier(Modifier::REVERSED)
                             } else {
                                 Style::default()
                             };
-                            Line::from(Span::styled(format!("{title} x {count}"), style))
+                            let mark = if marked.iter().any(|m| m == title) { "[x]" } else { "[ ]" };
+                            Line::from(Span::styled(format!("{mark} {title} x {count}"), style))
                         })
                         .collect();
                     ("Remove attendee", items)
                 }
                 Mode::AddAttendeeSelect => {
-                    let items: Vec<Line> = categories
-                        .iter()
+                    let items: Vec<Line> = attendee_choices(meeting, categories, *hide_used_categories)
+                        .into_iter()
+                        .map(|idx| &categories[idx])
                         .enumerate()
                         .map(|(i, cat)| {
                             let style = if i == selected {
@@ -307,22 +1190,133 @@ fn render_ui(
             f.render_widget(Clear, area);
             f.render_widget(popup, area);
         }
+
+        if matches!(mode, Mode::ConfirmRoster) {
+            let warnings = RosterWarnings::evaluate(meeting, &settings.roster_warnings);
+            let area = centered_rect(60, 30, size);
+            let popup = Paragraph::new(vec![
+                Line::from(Span::styled(
+                    format!(
+                        "This roster burns ${:.2}/min with {} attendees — keep it?",
+                        warnings.burn_rate_per_minute, warnings.headcount
+                    ),
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                )),
+                Line::from(""),
+                Line::from("[y] Keep  [n] Revert"),
+            ])
+            .alignment(Alignment::Center)
+            .block(Block::default().title("Large roster loaded").borders(Borders::ALL));
+            f.render_widget(Clear, area);
+            f.render_widget(popup, area);
+        }
+
+        if matches!(mode, Mode::ConfirmRemoveGroups) {
+            let preview = meeting.preview_remove_groups(marked);
+            let area = centered_rect(50, 20, size);
+            let popup = Paragraph::new(vec![
+                Line::from(Span::styled(
+                    format!(
+                        "Remove {} groups: {} attendees, -${:.2}/min?",
+                        preview.removed.len(),
+                        preview.headcount,
+                        preview.burn_rate_reduction
+                    ),
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                )),
+                Line::from(""),
+                Line::from("[y] Remove  [n] Cancel"),
+            ])
+            .alignment(Alignment::Center)
+            .block(Block::default().title("Remove attendees").borders(Borders::ALL));
+            f.render_widget(Clear, area);
+            f.render_widget(popup, area);
+        }
+
+        if matches!(mode, Mode::Review) {
+            let state = meeting.state_at(*review_offset);
+            let at = Duration::from_millis(state.elapsed_millis);
+            let mut lines = vec![
+                Line::from(Span::styled(
+                    scrubber_bar(at, duration, SCRUBBER_WIDTH),
+                    Style::default().fg(Color::Cyan),
+                )),
+                Line::from(format!(
+                    "{} of {}",
+                    format_duration(at),
+                    format_duration(duration)
+                )),
+                Line::from(Span::styled(
+                    format!(
+                        "Burn rate ${:.2}/min  Cost so far {}",
+                        state.burn_rate_per_minute,
+                        total_cost_label(state.total_cost, meeting.is_approximate())
+                    ),
+                    Style::default().add_modifier(Modifier::BOLD),
+                )),
+                Line::from(""),
+            ];
+            lines.extend(state.attendees.iter().map(|a| {
+                Line::from(Span::styled(
+                    format!("{} x {}", a.title, a.count),
+                    Style::default().fg(Color::Magenta),
+                ))
+            }));
+            let area = centered_rect(60, 60, size);
+            let popup = Paragraph::new(lines)
+                .alignment(Alignment::Center)
+                .block(Block::default().title("Review").borders(Borders::ALL));
+            f.render_widget(Clear, area);
+            f.render_widget(popup, area);
+        }
+
+        if matches!(mode, Mode::ConfirmReset) {
+            let area = centered_rect(40, 20, size);
+            let popup = Paragraph::new(vec![
+                Line::from(Span::styled(
+                    "Reset meeting? [y/n]",
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                )),
+            ])
+            .alignment(Alignment::Center)
+            .block(Block::default().title("Reset").borders(Borders::ALL));
+            f.render_widget(Clear, area);
+            f.render_widget(popup, area);
+        }
     })?;
     Ok(())
 }
 
-#[allow(clippy::too_many_arguments, clippy::too_many_lines)]
-fn process_key(
+#[allow(clippy::too_many_lines)]
+fn process_key<S: CategoryStore + RosterStore>(
     key_event: crossterm::event::KeyEvent,
-    mode: &mut Mode,
-    input_text: &mut String,
-    show_salaries: &mut bool,
-    categories: &mut Vec<EmployeeCategory>,
-    meeting: &mut Meeting,
-    files: &mut Vec<String>,
-    selected: &mut usize,
-    add_attendee_idx: &mut Option<usize>,
+    app: &mut App<S>,
 ) {
+    let App {
+        store,
+        meeting,
+        categories,
+        settings,
+        mode,
+        input_text,
+        show_salaries,
+        show_rate_chart,
+        hide_used_categories,
+        files,
+        selected,
+        add_attendee_idx,
+        edit_category_idx,
+        marked,
+        roster_backup,
+        status,
+        export_dir,
+        category_usage,
+        events,
+        review_offset,
+        macros: _,
+        settings_file: _,
+    } = app;
+    *status = None;
     match *mode {
         Mode::View => match key_event.code {
             KeyCode::Char('q') => *mode = Mode::View, // handled in loop
@@ -330,10 +1324,23 @@ fn process_key(
                 if meeting.is_running() {
                     meeting.stop();
                 } else {
+                    name_if_untitled(meeting, SystemTime::now(), settings);
                     meeting.start();
                 }
             }
-            KeyCode::Char('c') => meeting.reset(),
+            KeyCode::Char('c') => *mode = Mode::ConfirmReset,
+            KeyCode::Char('t') => {
+                input_text.clear();
+                *mode = Mode::ArmStart;
+            }
+            KeyCode::Char('b') => {
+                input_text.clear();
+                *mode = Mode::SetBudget;
+            }
+            KeyCode::Char('m') => {
+                input_text.clear();
+                *mode = Mode::AdjustElapsed;
+            }
             KeyCode::Char('a') => {
                 input_text.clear();
                 *mode = Mode::AddCategory;
@@ -347,8 +1354,13 @@ fn process_key(
                 *selected = 0;
                 *mode = Mode::AddAttendeeSelect;
             }
+            KeyCode::Char('x') => {
+                *selected = 0;
+                *mode = Mode::EditCategorySelect;
+            }
             KeyCode::Char('r') => {
                 input_text.clear();
+                marked.clear();
                 *mode = Mode::RemoveAttendee;
             }
             KeyCode::Char('w') => {
@@ -357,64 +1369,157 @@ fn process_key(
             }
             KeyCode::Char('l') => {
                 *selected = 0;
-                files.clear();
-                if let Ok(read) = fs::read_dir(data_dir()) {
-                    for entry in read.flatten() {
-                        if let Ok(ft) = entry.file_type() {
-                            if ft.is_file() {
-                                if let Some(name) = entry.file_name().to_str() {
-                                    if name != "categories.toml" {
-                                        files.push(name.to_string());
-                                    }
-                                }
-                            }
-                        }
+                match store.list_rosters() {
+                    Ok(names) => *files = names,
+                    Err(err) => {
+                        files.clear();
+                        events.error(status, format!("Failed to list attendee files: {err}"));
                     }
                 }
                 *mode = Mode::LoadAttendees;
             }
             KeyCode::Char('p') => *show_salaries = !*show_salaries,
+            KeyCode::Char('g') => *show_rate_chart = !*show_rate_chart,
+            KeyCode::Char('u') => {
+                let undone = meeting.undo_last_attendee_change();
+                if !undone {
+                    *status = Some("Nothing to undo".to_string());
+                }
+            }
+            KeyCode::Char('v') => {
+                if meeting.is_running() || meeting.is_armed() {
+                    *status = Some("Stop the meeting to review it".to_string());
+                } else {
+                    *review_offset = meeting.duration();
+                    *mode = Mode::Review;
+                }
+            }
+            KeyCode::Char('o') => {
+                match export_meeting_tsv_to_dir(meeting, export_dir, DEFAULT_TSV_FILE) {
+                    Ok(path) => {
+                        *status = Some(format!("Exported to {}", path.display()));
+                        events.files_written.push(path);
+                    }
+                    Err(err) => events.error(status, format!("Failed to export: {err}")),
+                }
+            }
             _ => {}
         },
-        Mode::AddCategory | Mode::AddAttendeeCount | Mode::SaveAttendees => match key_event.code {
+        Mode::AddCategory
+        | Mode::AddAttendeeCount
+        | Mode::SaveAttendees
+        | Mode::ArmStart
+        | Mode::SetBudget
+        | Mode::AdjustElapsed
+        | Mode::EditCategory => match key_event.code {
             KeyCode::Enter => {
                 match *mode {
-                    Mode::AddCategory => {
-                        if let Some((title, salary_str)) = input_text.split_once(':') {
-                            if let Ok(salary) = salary_str.trim().parse::<u64>() {
-                                if let Ok(cat) = EmployeeCategory::new(title.trim(), salary) {
-                                    if !categories.iter().any(|c| c.title() == cat.title()) {
-                                        categories.push(cat);
+                    Mode::AddCategory => match parse_category_input(input_text, settings) {
+                        Err(message) => events.error(status, message),
+                        Ok(cat) => {
+                            if let Some(existing) =
+                                categories.iter_mut().find(|c| c.title() == cat.title())
+                            {
+                                if existing.salary() != cat.salary() {
+                                    // Only groups already in the meeting need repricing.
+                                    let _ = meeting.update_salary(cat.title(), cat.salary());
+                                    *status = Some(format!("Updated salary for {}", cat.title()));
+                                    *existing = carry_over_category(existing, cat);
+                                }
+                            } else {
+                                category_usage.touch(cat.title());
+                                categories.push(cat);
+                                if let Some(max) = settings.max_categories {
+                                    let evicted = category_usage.evict(categories, max, meeting);
+                                    if !evicted.is_empty() {
+                                        let titles: Vec<&str> =
+                                            evicted.iter().map(EmployeeCategory::title).collect();
+                                        *status = Some(format!(
+                                            "Removed unused categories: {}",
+                                            titles.join(", ")
+                                        ));
                                     }
                                 }
                             }
                         }
-                    }
+                    },
                     Mode::AddAttendeeCount => {
-                        let count = if input_text.trim().is_empty() {
-                            1
-                        } else if let Ok(c) = input_text.trim().parse::<u32>() {
-                            c
-                        } else {
-                            return;
-                        };
+                        let default = empty_count_default(settings, categories, *add_attendee_idx);
+                        let count =
+                            match interpret_count_input(input_text, default, MAX_COUNT_INPUT) {
+                                Ok(count) => count,
+                                Err(err) => {
+                                    events.error(status, err.to_string());
+                                    return;
+                                }
+                            };
                         if let Some(idx) = add_attendee_idx.take() {
                             if let Some(cat) = categories.get(idx) {
-                                meeting.add_attendee(cat, count);
+                                match meeting.try_add_attendee(cat, count) {
+                                    Ok(_) => category_usage.touch(cat.title()),
+                                    Err(err) => events.error(status, err.to_string()),
+                                }
                             }
                         }
                     }
                     Mode::SaveAttendees => {
-                        let path = data_dir().join(input_text.trim());
-                        let data: Vec<AttendeeInfo> = meeting
-                            .attendees()
-                            .map(|(t, _s, c)| AttendeeInfo {
-                                title: t.to_string(),
-                                count: *c,
-                            })
-                            .collect();
-                        if let Err(err) = save_attendees(&path, &data) {
-                            let _ = err;
+                        let data = meeting.roster_entries();
+                        if let Err(err) = store.save_roster(input_text.trim(), &data) {
+                            events.error(
+                                status,
+                                format!(
+                                    "Failed to save attendees: {err}; kept in memory and retried at exit"
+                                ),
+                            );
+                        }
+                    }
+                    Mode::ArmStart => match input_text.trim().parse::<u64>() {
+                        Ok(secs) if Duration::from_secs(secs) <= MAX_ARM_DELAY => {
+                            let delay = Duration::from_secs(secs);
+                            match meeting.arm_start_in(delay) {
+                                Ok(()) => {
+                                    name_if_untitled(meeting, SystemTime::now() + delay, settings);
+                                }
+                                Err(err) => {
+                                    events.error(status, format!("Cannot arm start: {err}"));
+                                }
+                            }
+                        }
+                        Ok(_) => events.error(status, "Start delay can be at most 24 hours"),
+                        Err(_) => events.error(status, "Enter a whole number of seconds"),
+                    },
+                    Mode::SetBudget => {
+                        let input = input_text.trim();
+                        if input.is_empty() {
+                            meeting.clear_budget();
+                        } else {
+                            let result = input
+                                .parse::<f64>()
+                                .map_err(|err| err.to_string())
+                                .and_then(|budget| {
+                                    meeting.set_budget(budget).map_err(|err| err.to_string())
+                                });
+                            if let Err(err) = result {
+                                events.error(status, format!("Invalid budget: {err}"));
+                            }
+                        }
+                    }
+                    Mode::EditCategory => {
+                        if let Some(idx) = edit_category_idx.take() {
+                            match apply_category_edit(
+                                categories, idx, input_text, settings, meeting,
+                            ) {
+                                Ok(message) => {
+                                    category_usage.touch(categories[idx].title());
+                                    *status = Some(message);
+                                }
+                                Err(err) => events.error(status, err),
+                            }
+                        }
+                    }
+                    Mode::AdjustElapsed => {
+                        if !apply_elapsed_adjustment(meeting, input_text) {
+                            events.error(status, "Enter minutes as +N, -N or N");
                         }
                     }
                     _ => unreachable!(),
@@ -429,16 +1534,8 @@ fn process_key(
             _ => {}
         },
         Mode::DeleteCategory => match key_event.code {
-            KeyCode::Up => {
-                if *selected > 0 {
-                    *selected -= 1;
-                }
-            }
-            KeyCode::Down => {
-                if *selected + 1 < categories.len() {
-                    *selected += 1;
-                }
-            }
+            KeyCode::Up if *selected > 0 => *selected -= 1,
+            KeyCode::Down if *selected + 1 < categories.len() => *selected += 1,
             KeyCode::Enter => {
                 if let Some(cat) = categories.get(*selected) {
                     let title = cat.title().to_string();
@@ -449,108 +1546,298 @@ fn process_key(
             KeyCode::Esc => *mode = Mode::View,
             _ => {}
         },
-        Mode::AddAttendeeSelect => match key_event.code {
-            KeyCode::Up => {
-                if *selected > 0 {
-                    *selected -= 1;
+        Mode::EditCategorySelect => match key_event.code {
+            KeyCode::Up if *selected > 0 => *selected -= 1,
+            KeyCode::Down if *selected + 1 < categories.len() => *selected += 1,
+            KeyCode::Enter => {
+                if let Some(cat) = categories.get(*selected) {
+                    *edit_category_idx = Some(*selected);
+                    let salary = if cat.is_observer() {
+                        "observer".to_string()
+                    } else {
+                        cat.salary().to_string()
+                    };
+                    *input_text = match cat.source() {
+                        Some(source) => format!("{}:{salary}:{source}", cat.title()),
+                        None => format!("{}:{salary}", cat.title()),
+                    };
+                    *mode = Mode::EditCategory;
+                } else {
+                    *mode = Mode::View;
                 }
             }
-            KeyCode::Down => {
-                if *selected + 1 < categories.len() {
-                    *selected += 1;
+            KeyCode::Esc => *mode = Mode::View,
+            _ => {}
+        },
+        Mode::AddAttendeeSelect => match key_event.code {
+            KeyCode::Up if *selected > 0 => *selected -= 1,
+            KeyCode::Down
+                if *selected + 1
+                    < attendee_choices(meeting, categories, *hide_used_categories).len() =>
+            {
+                *selected += 1;
+            }
+            KeyCode::Char('h') => {
+                *hide_used_categories = !*hide_used_categories;
+                *selected = 0;
+            }
+            KeyCode::Char('+') => {
+                // Adds one attendee without the count prompt and stays in the
+                // picker, so a roster can be built with a few key presses.
+                let choices = attendee_choices(meeting, categories, *hide_used_categories);
+                let Some(cat) = choices.get(*selected).map(|&idx| &categories[idx]) else {
+                    return;
+                };
+                match meeting.try_add_attendee(cat, 1) {
+                    Ok(count) => {
+                        category_usage.touch(cat.title());
+                        *status = Some(format!("Added 1 {} ({count} now)", cat.title()));
+                    }
+                    Err(err) => events.error(status, err.to_string()),
                 }
+                let remaining = attendee_choices(meeting, categories, *hide_used_categories).len();
+                *selected = (*selected).min(remaining.saturating_sub(1));
             }
             KeyCode::Enter => {
-                *add_attendee_idx = Some(*selected);
-                input_text.clear();
-                *mode = Mode::AddAttendeeCount;
+                let choices = attendee_choices(meeting, categories, *hide_used_categories);
+                if let Some(&idx) = choices.get(*selected) {
+                    *add_attendee_idx = Some(idx);
+                    input_text.clear();
+                    *mode = Mode::AddAttendeeCount;
+                } else {
+                    *mode = Mode::View;
+                }
             }
             KeyCode::Esc => *mode = Mode::View,
             _ => {}
         },
         Mode::RemoveAttendee => match key_event.code {
-            KeyCode::Up => {
-                if *selected > 0 {
-                    *selected -= 1;
-                }
-            }
+            KeyCode::Up if *selected > 0 => *selected -= 1,
             KeyCode::Down => {
                 let count = meeting.attendees().count();
                 if *selected + 1 < count {
                     *selected += 1;
                 }
             }
+            KeyCode::Char(' ') => {
+                if let Some((title, ..)) = meeting.attendees_by_cost().get(*selected) {
+                    if let Some(pos) = marked.iter().position(|m| m == title) {
+                        marked.remove(pos);
+                    } else {
+                        marked.push((*title).to_string());
+                    }
+                }
+            }
+            KeyCode::Char(c @ ('+' | '-')) => {
+                let Some((title, salary)) = meeting
+                    .attendees_by_cost()
+                    .get(*selected)
+                    .map(|(t, salary, ..)| ((*t).to_string(), *salary))
+                else {
+                    return;
+                };
+                if c == '+' {
+                    let category = if salary == 0 {
+                        EmployeeCategory::observer(&title)
+                    } else {
+                        EmployeeCategory::new(&title, salary)
+                    };
+                    if let Ok(category) = category {
+                        if let Err(err) = meeting.try_add_attendee(&category, 1) {
+                            events.error(status, err.to_string());
+                        }
+                    }
+                } else {
+                    meeting.remove_attendee(&title, 1);
+                }
+                let order = meeting.attendees_by_cost();
+                if let Some(pos) = order.iter().position(|(t, ..)| *t == title) {
+                    *selected = pos;
+                } else {
+                    marked.retain(|m| *m != title);
+                    *selected = (*selected).min(order.len().saturating_sub(1));
+                }
+            }
+            KeyCode::Enter if !marked.is_empty() => *mode = Mode::ConfirmRemoveGroups,
             KeyCode::Enter => {
-                let names: Vec<String> =
-                    meeting.attendees().map(|(t, _, _)| t.to_string()).collect();
+                let names: Vec<String> = meeting
+                    .attendees_by_cost()
+                    .into_iter()
+                    .map(|(t, ..)| t.to_string())
+                    .collect();
                 if let Some(title) = names.get(*selected) {
-                    let remove_count = meeting
-                        .attendees()
-                        .find(|(t, _, _)| *t == title.as_str())
-                        .map_or(0, |(_, _, c)| *c);
-                    meeting.remove_attendee(title, remove_count);
+                    let remove_count = meeting.attendee_count(title).unwrap_or(0);
+                    if let Err(err) = meeting.try_remove_attendee(title, remove_count) {
+                        events.error(status, err.to_string());
+                    }
                 }
                 *mode = Mode::View;
             }
-            KeyCode::Esc => *mode = Mode::View,
+            KeyCode::Esc => {
+                marked.clear();
+                *mode = Mode::View;
+            }
             _ => {}
         },
-        Mode::LoadAttendees => match key_event.code {
-            KeyCode::Up => {
-                if *selected > 0 {
-                    *selected -= 1;
-                }
+        Mode::ConfirmRemoveGroups => match key_event.code {
+            KeyCode::Char('y') | KeyCode::Enter => {
+                let report = meeting.remove_groups(marked);
+                *status = Some(if report.unknown.is_empty() {
+                    format!(
+                        "Removed {} attendees (-${:.2}/min)",
+                        report.headcount, report.burn_rate_reduction
+                    )
+                } else {
+                    format!(
+                        "Removed {} attendees; not in meeting: {}",
+                        report.headcount,
+                        report.unknown.join(", ")
+                    )
+                });
+                marked.clear();
+                *mode = Mode::View;
             }
-            KeyCode::Down => {
-                if *selected + 1 < files.len() {
-                    *selected += 1;
-                }
+            KeyCode::Char('n') | KeyCode::Esc => {
+                marked.clear();
+                *mode = Mode::View;
             }
+            _ => {}
+        },
+        Mode::LoadAttendees => match key_event.code {
+            KeyCode::Up if *selected > 0 => *selected -= 1,
+            KeyCode::Down if *selected + 1 < files.len() => *selected += 1,
             KeyCode::Enter => {
+                *mode = Mode::View;
                 if let Some(name) = files.get(*selected) {
-                    let path = data_dir().join(name);
-                    if let Ok(entries) = load_attendees(&path) {
+                    if let Ok(entries) = store.load_roster(name) {
+                        let backup = meeting.roster_snapshot();
                         meeting.clear_attendees();
                         for entry in entries {
                             if let Some(cat) = categories.iter().find(|c| c.title() == entry.title)
                             {
-                                meeting.add_attendee(cat, entry.count);
+                                if let Err(err) = meeting.add_roster_entry(cat, &entry) {
+                                    events.error(status, err.to_string());
+                                }
+                                category_usage.touch(cat.title());
                             }
                         }
+                        if let Some(warning) = duplicate_warning(meeting) {
+                            *status = Some(match status.take() {
+                                Some(err) => format!("{err}; {warning}"),
+                                None => warning,
+                            });
+                        }
+                        if RosterWarnings::evaluate(meeting, &settings.roster_warnings).any() {
+                            *roster_backup = Some(backup);
+                            *mode = Mode::ConfirmRoster;
+                        }
                     }
                 }
-                *mode = Mode::View;
             }
             KeyCode::Esc => *mode = Mode::View,
             _ => {}
         },
+        Mode::ConfirmRoster => match key_event.code {
+            KeyCode::Char('y') | KeyCode::Enter => {
+                *roster_backup = None;
+                *mode = Mode::View;
+            }
+            KeyCode::Char('n') | KeyCode::Esc => {
+                if let Some(backup) = roster_backup.take() {
+                    meeting.restore_roster(backup);
+                }
+                *mode = Mode::View;
+            }
+            _ => {}
+        },
+        Mode::ConfirmReset => match key_event.code {
+            KeyCode::Char('y') => {
+                meeting.reset();
+                *mode = Mode::View;
+            }
+            KeyCode::Char('n') | KeyCode::Esc => *mode = Mode::View,
+            _ => {}
+        },
+        Mode::Review => match key_event.code {
+            KeyCode::Left => *review_offset = review_offset.saturating_sub(REVIEW_STEP),
+            KeyCode::Right => {
+                *review_offset = (*review_offset + REVIEW_STEP).min(meeting.duration());
+            }
+            KeyCode::Home => *review_offset = Duration::ZERO,
+            KeyCode::End => *review_offset = meeting.duration(),
+            KeyCode::Esc | KeyCode::Char('v') => *mode = Mode::View,
+            _ => {}
+        },
     }
 }
 
+/// Saves the categories and retries earlier failed saves before exiting.
+///
+/// Whatever still cannot be saved is written to `fallback` instead; the
+/// returned message tells the user where it went. The failure and the files
+/// written are added to `events`.
+///
+/// # Errors
+///
+/// Returns a [`StorageError`] if the data cannot be written to `fallback`
+/// either.
+fn save_on_exit<S: CategoryStore + RosterStore>(
+    store: &mut ResilientStore<S>,
+    categories: &[EmployeeCategory],
+    fallback: &Path,
+    events: &mut AppEvents,
+) -> Result<Option<String>, StorageError> {
+    // A failed save stays pending, so flushing retries it along with the rest.
+    let _ = store.save_categories(categories);
+    let Err(err) = store.flush() else {
+        return Ok(None);
+    };
+    events.errors.push(format!("Failed to save: {err}"));
+    let written = store.dump_pending(fallback)?;
+    events.files_written.extend(written.iter().cloned());
+    let files: Vec<String> = written
+        .iter()
+        .map(|path| format!("  {}", path.display()))
+        .collect();
+    Ok(Some(format!(
+        "Could not save your data ({err}).\nIt was written to {} instead:\n{}",
+        fallback.display(),
+        files.join("\n")
+    )))
+}
+
 /// Entry point for the interactive TUI application.
 ///
 /// This function initializes the terminal, loads persisted employee
 /// categories, and enters the main event loop. On exit, updated categories
-/// are saved back to disk.
+/// are saved back to disk; if the data directory cannot be written, they are
+/// saved to a temporary directory and its location is printed. With
+/// `--exit-summary-file`, a JSON summary of the run is written last.
 ///
 /// # Errors
 ///
-/// Returns an error if terminal initialization fails or if the category
-/// database cannot be loaded or saved.
+/// Returns an error if terminal initialization fails, if the category
+/// database cannot be loaded, if data can be saved neither to the data
+/// directory nor to the temporary directory, or if the exit summary cannot be
+/// written.
 #[allow(clippy::too_many_lines)]
 fn main() -> Result<(), Box<dyn Error>> {
+    let mut args = std::env::args().skip(1).peekable();
+    if args.peek().is_some_and(|arg| arg == "daemon") {
+        return run_daemon(args.skip(1));
+    }
+    let options = parse_cli_args(args)?;
     let dir = data_dir();
     fs::create_dir_all(&dir)?;
-    let db_path = dir.join("categories.toml");
-    let mut categories = load_categories(&db_path)?;
-    let mut meeting = Meeting::new();
-
-    let mut mode = Mode::View;
-    let mut input_text = String::new();
-    let mut show_salaries = false;
-    let mut load_files: Vec<String> = Vec::new();
-    let mut selected_idx: usize = 0;
-    let mut add_attendee_idx: Option<usize> = None;
+    let settings_file = dir.join(SETTINGS_FILE);
+    let settings = load_settings(&settings_file)?;
+    let export_dir = resolve_export_dir(
+        options.export_dir.as_deref(),
+        settings.export_dir.as_deref(),
+    );
+    let store = ResilientStore::with_policy(FileStore::new(dir), settings.retry_policy());
+    let mut app = App::new(store, settings, export_dir)?;
+    app.settings_file = Some(settings_file);
 
     enable_raw_mode()?;
     let mut stdout = std::io::stdout();
@@ -562,16 +1849,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     let mut last_tick = std::time::Instant::now();
 
     loop {
-        render_ui(
-            &mut terminal,
-            &meeting,
-            &categories,
-            &mode,
-            &input_text,
-            show_salaries,
-            &load_files,
-            selected_idx,
-        )?;
+        render_ui(&mut terminal, &app)?;
 
         let timeout = tick_rate
             .checked_sub(last_tick.elapsed())
@@ -580,20 +1858,13 @@ fn main() -> Result<(), Box<dyn Error>> {
         if event::poll(timeout)? {
             if let Event::Key(key_event) = event::read()? {
                 if key_event.kind == KeyEventKind::Press {
-                    if matches!(mode, Mode::View) && matches!(key_event.code, KeyCode::Char('q')) {
+                    if matches!(app.mode, Mode::View)
+                        && matches!(key_event.code, KeyCode::Char('q'))
+                        && !app.macros.is_choosing_slot()
+                    {
                         break;
                     }
-                    process_key(
-                        key_event,
-                        &mut mode,
-                        &mut input_text,
-                        &mut show_salaries,
-                        &mut categories,
-                        &mut meeting,
-                        &mut load_files,
-                        &mut selected_idx,
-                        &mut add_attendee_idx,
-                    );
+                    handle_key(key_event, &mut app);
                 }
             }
         }
@@ -611,7 +1882,19 @@ fn main() -> Result<(), Box<dyn Error>> {
     )?;
     terminal.show_cursor()?;
 
-    save_categories(&db_path, &categories)?;
+    app.category_usage.record_in(&mut app.categories);
+    let saved = save_on_exit(
+        &mut app.store,
+        &app.categories,
+        &fallback_dump_dir(),
+        &mut app.events,
+    );
+    if let Some(path) = &options.exit_summary_file {
+        write_exit_summary(path, &app.meeting, &app.events)?;
+    }
+    if let Some(message) = saved? {
+        eprintln!("{message}");
+    }
 
     Ok(())
 }
@@ -619,11 +1902,1007 @@ fn main() -> Result<(), Box<dyn Error>> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use meeting_cost_tracker::testing::assert_cost_eq;
+    use meeting_cost_tracker::MemoryStore;
+
+    #[test]
+    fn rate_chart_holds_each_step() {
+        let steps = [
+            (Duration::ZERO, 2.0),
+            (Duration::from_mins(5), 3.0),
+            (Duration::from_mins(9), 1.0),
+        ];
+        assert_eq!(
+            rate_chart_points(&steps, Duration::from_mins(12)),
+            vec![
+                (0.0, 2.0),
+                (5.0, 2.0),
+                (5.0, 3.0),
+                (9.0, 3.0),
+                (9.0, 1.0),
+                (12.0, 1.0)
+            ]
+        );
+        assert!(rate_chart_points(&[], Duration::from_mins(1)).is_empty());
+    }
+
+    #[test]
+    fn category_input_requires_explicit_observer() {
+        let settings = Settings::default();
+        let guest = parse_category_input("Customer:observer", &settings).unwrap();
+        assert!(guest.is_observer());
+        assert_eq!(guest.title(), "Customer");
+        let err = parse_category_input("Customer:0", &settings).unwrap_err();
+        assert!(err.contains("Customer:observer"));
+        let dev = parse_category_input(" Dev : 120000", &settings).unwrap();
+        assert_eq!((dev.title(), dev.salary()), ("Dev", 120_000));
+        assert!(parse_category_input("Dev", &settings).is_err());
+        assert!(parse_category_input("Dev:lots", &settings).is_err());
+    }
+
+    #[test]
+    fn add_attendee_picker_can_hide_used_categories() {
+        let mut app = sample_app();
+        app.categories
+            .push(EmployeeCategory::new("PM", 110_000).unwrap());
+        let dev = app.categories[0].clone();
+        app.meeting.add_attendee(&dev, 1);
+        process_key(press(KeyCode::Char('e')), &mut app);
+        assert_eq!(
+            attendee_choices(&app.meeting, &app.categories, false),
+            [0, 1]
+        );
+        process_key(press(KeyCode::Char('h')), &mut app);
+        assert!(app.hide_used_categories);
+        process_key(press(KeyCode::Down), &mut app);
+        assert_eq!(app.selected, 0);
+        process_key(press(KeyCode::Enter), &mut app);
+        // The first visible entry is PM, the second category.
+        assert_eq!(app.add_attendee_idx, Some(1));
+        assert!(matches!(app.mode, Mode::AddAttendeeCount));
+    }
+
+    #[test]
+    fn plus_adds_one_attendee_without_count_prompt() {
+        let mut app = sample_app();
+        app.categories
+            .push(EmployeeCategory::new("PM", 110_000).unwrap());
+        process_key(press(KeyCode::Char('e')), &mut app);
+        process_key(press(KeyCode::Down), &mut app);
+        process_key(press(KeyCode::Char('+')), &mut app);
+        assert_eq!(app.meeting.attendee_count("PM"), Some(1));
+        assert_eq!(app.meeting.total_attendees(), 1);
+        assert!(matches!(app.mode, Mode::AddAttendeeSelect));
+        process_key(press(KeyCode::Char('+')), &mut app);
+        assert_eq!(app.meeting.attendee_count("PM"), Some(2));
+        assert_eq!(app.status.as_deref(), Some("Added 1 PM (2 now)"));
+
+        // Hiding used categories empties the list, so `+` does nothing.
+        process_key(press(KeyCode::Char('h')), &mut app);
+        let dev = app.categories[0].clone();
+        app.meeting.add_attendee(&dev, 1);
+        process_key(press(KeyCode::Char('+')), &mut app);
+        assert_eq!(app.meeting.total_attendees(), 3);
+    }
+
+    #[test]
+    fn key_specs_round_trip() {
+        for code in [
+            KeyCode::Char('a'),
+            KeyCode::Char('+'),
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+            KeyCode::Esc,
+            KeyCode::Down,
+            KeyCode::F(2),
+        ] {
+            let spec = key_spec(code).unwrap();
+            assert_eq!(parse_key_spec(&spec), Some(code), "{spec}");
+        }
+        assert_eq!(key_spec(KeyCode::Char(' ')).as_deref(), Some("Space"));
+        assert_eq!(key_spec(KeyCode::F(12)).as_deref(), Some("F12"));
+        assert_eq!(key_spec(KeyCode::Null), None);
+        assert_eq!(parse_key_spec("Fx"), None);
+        assert_eq!(parse_key_spec("ab"), None);
+    }
+
+    #[test]
+    fn macro_replays_keys_through_popups() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut app = sample_app();
+        app.settings_file = Some(dir.path().join(SETTINGS_FILE));
+        let keys = |app: &mut App<MemoryStore>, codes: &[KeyCode]| {
+            for &code in codes {
+                handle_key(press(code), app);
+            }
+        };
+
+        keys(&mut app, &[KeyCode::F(2), KeyCode::Char('1')]);
+        assert_eq!(
+            app.status.as_deref(),
+            Some("Recording macro 1; press F2 to stop")
+        );
+        keys(&mut app, &[KeyCode::Char('b')]);
+        assert!(matches!(app.mode, Mode::SetBudget));
+        keys(
+            &mut app,
+            &[
+                KeyCode::Char('5'),
+                KeyCode::Char('0'),
+                KeyCode::Enter,
+                KeyCode::F(3),
+            ],
+        );
+        assert!(matches!(app.mode, Mode::View));
+        assert_eq!(
+            app.status.as_deref(),
+            Some("Stop recording with F2 before replaying a macro")
+        );
+        keys(&mut app, &[KeyCode::F(2)]);
+        assert_eq!(app.status.as_deref(), Some("Saved macro 1 (4 keys)"));
+        let saved = load_settings(&dir.path().join(SETTINGS_FILE)).unwrap();
+        assert_eq!(saved.macros["1"], ["b", "5", "0", "Enter"]);
+
+        app.meeting.clear_budget();
+        keys(&mut app, &[KeyCode::F(3), KeyCode::Char('1')]);
+        assert!(matches!(app.mode, Mode::View));
+        assert_eq!(app.meeting.budget(), Some(50.0));
+        assert_eq!(app.status.as_deref(), Some("Replayed macro 1"));
+        assert!(app.macros.recording_slot().is_none());
+
+        keys(&mut app, &[KeyCode::F(3), KeyCode::Char('2')]);
+        assert_eq!(app.status.as_deref(), Some("No macro recorded as 2"));
+    }
+
+    #[test]
+    fn first_start_names_untitled_meeting() {
+        let mut app = sample_app();
+        assert_eq!(header_text(&app.meeting), "Meeting Cost Tracker");
+        process_key(press(KeyCode::Char('s')), &mut app);
+        let name = app.meeting.title().unwrap().to_string();
+        assert!(
+            name.starts_with("Meeting 20") && name.ends_with(" UTC"),
+            "{name}"
+        );
+        assert_eq!(
+            header_text(&app.meeting),
+            format!("Meeting Cost Tracker — {name}")
+        );
+
+        let mut local = sample_app();
+        local.settings.utc_offset_minutes = Some(-300);
+        process_key(press(KeyCode::Char('s')), &mut local);
+        let name = local.meeting.title().unwrap();
+        assert!(!name.ends_with(" UTC"), "{name}");
+
+        app.meeting.set_title("Roadmap");
+        process_key(press(KeyCode::Char('s')), &mut app);
+        process_key(press(KeyCode::Char('s')), &mut app);
+        assert_eq!(app.meeting.title(), Some("Roadmap"));
+    }
+
+    #[test]
+    fn arm_start_rejects_delays_over_a_day() {
+        let mut app = sample_app();
+        for input in ["86401", "18446744073709551615"] {
+            process_key(press(KeyCode::Char('t')), &mut app);
+            for c in input.chars() {
+                process_key(press(KeyCode::Char(c)), &mut app);
+            }
+            process_key(press(KeyCode::Enter), &mut app);
+            assert!(!app.meeting.is_armed());
+            assert_eq!(app.meeting.title(), None);
+        }
+        assert_eq!(
+            app.events.errors,
+            ["Start delay can be at most 24 hours"; 2]
+        );
+
+        process_key(press(KeyCode::Char('t')), &mut app);
+        for c in "86400".chars() {
+            process_key(press(KeyCode::Char(c)), &mut app);
+        }
+        process_key(press(KeyCode::Enter), &mut app);
+        assert!(app.meeting.is_armed());
+    }
+
+    #[test]
+    fn review_scrubs_stopped_meeting_only() {
+        let mut app = sample_app();
+        let dev = app.categories[0].clone();
+        app.meeting.add_attendee(&dev, 1);
+        app.meeting.start();
+        process_key(press(KeyCode::Char('v')), &mut app);
+        assert!(matches!(app.mode, Mode::View));
+        assert_eq!(app.status.as_deref(), Some("Stop the meeting to review it"));
+
+        app.meeting.stop();
+        app.meeting.set_elapsed(Duration::from_secs(70));
+        process_key(press(KeyCode::Char('v')), &mut app);
+        assert!(matches!(app.mode, Mode::Review));
+        assert_eq!(app.review_offset, Duration::from_secs(70));
+        process_key(press(KeyCode::Right), &mut app);
+        assert_eq!(app.review_offset, Duration::from_secs(70));
+        process_key(press(KeyCode::Left), &mut app);
+        process_key(press(KeyCode::Left), &mut app);
+        assert_eq!(app.review_offset, Duration::from_secs(10));
+        process_key(press(KeyCode::Left), &mut app);
+        assert_eq!(app.review_offset, Duration::ZERO);
+        process_key(press(KeyCode::End), &mut app);
+        assert_eq!(app.review_offset, Duration::from_secs(70));
+
+        process_key(press(KeyCode::Esc), &mut app);
+        assert!(matches!(app.mode, Mode::View));
+        assert_eq!(app.meeting.duration(), Duration::from_secs(70));
+        assert!(!app.meeting.is_running());
+    }
+
+    #[test]
+    fn scrubber_bar_places_cursor() {
+        let total = Duration::from_mins(10);
+        assert_eq!(scrubber_bar(Duration::ZERO, total, 5), "●────");
+        assert_eq!(scrubber_bar(Duration::from_mins(5), total, 5), "━━●──");
+        assert_eq!(scrubber_bar(total, total, 5), "━━━━●");
+        assert_eq!(scrubber_bar(Duration::ZERO, Duration::ZERO, 3), "●──");
+    }
+
+    #[test]
+    fn headcount_label_counts_observers_apart() {
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&EmployeeCategory::new("Dev", 120_000).unwrap(), 8);
+        assert_eq!(headcount_label(&meeting), "8");
+        meeting.add_attendee(&EmployeeCategory::observer("Customer").unwrap(), 1);
+        assert_eq!(headcount_label(&meeting), "8 + 1 observer");
+        meeting.add_attendee(&EmployeeCategory::observer("Customer").unwrap(), 1);
+        assert_eq!(headcount_label(&meeting), "8 + 2 observers");
+    }
+
+    #[test]
+    fn save_on_exit_saves_to_store() {
+        let mut store = ResilientStore::new(MemoryStore::default());
+        let categories = vec![EmployeeCategory::new("Dev", 120_000).unwrap()];
+        let fallback = tempfile::tempdir().unwrap();
+        let mut events = AppEvents::default();
+        let message = save_on_exit(&mut store, &categories, fallback.path(), &mut events).unwrap();
+        assert_eq!(message, None);
+        assert_eq!(events, AppEvents::default());
+        assert_eq!(store.inner().load_categories().unwrap(), categories);
+        assert_eq!(fs::read_dir(fallback.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn save_on_exit_dumps_when_data_dir_is_gone() {
+        let tmp = tempfile::tempdir().unwrap();
+        let data_dir = tmp.path().join("mount");
+        let policy = meeting_cost_tracker::RetryPolicy {
+            attempts: 2,
+            backoff: Duration::ZERO,
+        };
+        let mut store = ResilientStore::with_policy(FileStore::new(&data_dir), policy);
+        let categories = vec![EmployeeCategory::new("Dev", 120_000).unwrap()];
+        let fallback = tmp.path().join("fallback");
+        let mut events = AppEvents::default();
+        let message = save_on_exit(&mut store, &categories, &fallback, &mut events)
+            .unwrap()
+            .unwrap();
+        assert!(message.contains(&fallback.display().to_string()));
+        assert_eq!(events.errors.len(), 1);
+        assert_eq!(events.files_written, vec![fallback.join("categories.toml")]);
+        let dumped = meeting_cost_tracker::load_categories(&fallback.join("categories.toml"));
+        assert_eq!(dumped.unwrap(), categories);
+    }
+
+    #[test]
+    fn total_cost_label_marks_approximate_costs() {
+        assert_eq!(total_cost_label(12.5, false), "$12.50");
+        assert_eq!(total_cost_label(12.5, true), "~$12.50");
+    }
+
+    #[test]
+    fn per_attendee_line_needs_paid_attendees() {
+        let mut meeting = Meeting::new();
+        assert_eq!(per_attendee_line(&meeting), None);
+        meeting.add_attendee(&EmployeeCategory::observer("Customer").unwrap(), 2);
+        assert_eq!(per_attendee_line(&meeting), None);
+        meeting.add_attendee(&EmployeeCategory::new("Dev", 120_000).unwrap(), 4);
+        meeting.set_elapsed(Duration::from_mins(42));
+        assert_eq!(
+            per_attendee_line(&meeting).as_deref(),
+            Some("$42.00 per attendee")
+        );
+    }
+
+    #[test]
+    fn weekly_cost_line_projects_a_year() {
+        assert_eq!(
+            weekly_cost_line(12.5).as_deref(),
+            Some("This weekly meeting costs ~$650/year.")
+        );
+        assert_eq!(weekly_cost_line(0.0), None);
+    }
+
+    #[test]
+    fn trend_arrow_points_with_burn_rate() {
+        assert_eq!(trend_arrow(Trend::Increasing), Some(("▲", Color::Red)));
+        assert_eq!(trend_arrow(Trend::Decreasing), Some(("▼", Color::Green)));
+        assert_eq!(trend_arrow(Trend::Steady), None);
+    }
+
+    fn press(code: KeyCode) -> crossterm::event::KeyEvent {
+        crossterm::event::KeyEvent::new(code, crossterm::event::KeyModifiers::NONE)
+    }
+
+    fn sample_app() -> App<MemoryStore> {
+        let mut store = MemoryStore::default();
+        store
+            .save_categories(&[EmployeeCategory::new("Dev", 120_000).unwrap()])
+            .unwrap();
+        App::new(store, Settings::default(), PathBuf::from(".")).unwrap()
+    }
+
+    #[test]
+    fn confirm_roster_reverts_on_n() {
+        let mut app = sample_app();
+        let cat = app.categories[0].clone();
+        app.meeting.add_attendee(&cat, 1);
+        app.roster_backup = Some(app.meeting.roster_snapshot());
+        app.meeting.add_attendee(&cat, 39);
+        app.mode = Mode::ConfirmRoster;
+        process_key(press(KeyCode::Char('n')), &mut app);
+        assert!(matches!(app.mode, Mode::View));
+        assert!(app.roster_backup.is_none());
+        assert_eq!(app.meeting.attendee_count("Dev"), Some(1));
+    }
+
+    #[test]
+    fn reset_requires_confirmation() {
+        let mut app = sample_app();
+        let cat = app.categories[0].clone();
+        app.meeting.add_attendee(&cat, 2);
+
+        process_key(press(KeyCode::Char('c')), &mut app);
+        assert!(matches!(app.mode, Mode::ConfirmReset));
+        assert_eq!(app.meeting.attendee_count("Dev"), Some(2));
+        process_key(press(KeyCode::Char('n')), &mut app);
+        assert!(matches!(app.mode, Mode::View));
+        assert_eq!(app.meeting.attendee_count("Dev"), Some(2));
+
+        process_key(press(KeyCode::Char('c')), &mut app);
+        process_key(press(KeyCode::Esc), &mut app);
+        assert_eq!(app.meeting.attendee_count("Dev"), Some(2));
+
+        process_key(press(KeyCode::Char('c')), &mut app);
+        process_key(press(KeyCode::Char('y')), &mut app);
+        assert!(matches!(app.mode, Mode::View));
+        assert_eq!(app.meeting.attendee_count("Dev"), None);
+    }
+
+    #[test]
+    fn confirm_roster_keeps_on_y() {
+        let mut app = sample_app();
+        let cat = app.categories[0].clone();
+        app.roster_backup = Some(app.meeting.roster_snapshot());
+        app.meeting.add_attendee(&cat, 40);
+        app.mode = Mode::ConfirmRoster;
+        process_key(press(KeyCode::Char('y')), &mut app);
+        assert!(matches!(app.mode, Mode::View));
+        assert!(app.roster_backup.is_none());
+        assert_eq!(app.meeting.attendee_count("Dev"), Some(40));
+    }
 
     #[test]
-    fn duration_formatting() {
-        assert_eq!(format_duration(Duration::from_secs(0)), "00:00:00");
-        assert_eq!(format_duration(Duration::from_secs(3661)), "01:01:01");
+    fn save_and_load_roster_through_store() {
+        let mut app = sample_app();
+        let cat = app.categories[0].clone();
+        app.meeting.add_attendee(&cat, 2);
+        process_key(press(KeyCode::Char('w')), &mut app);
+        for c in "standup".chars() {
+            process_key(press(KeyCode::Char(c)), &mut app);
+        }
+        process_key(press(KeyCode::Enter), &mut app);
+        assert_eq!(app.store.list_rosters().unwrap(), vec!["standup"]);
+
+        app.meeting.clear_attendees();
+        process_key(press(KeyCode::Char('l')), &mut app);
+        assert_eq!(app.files, vec!["standup"]);
+        process_key(press(KeyCode::Enter), &mut app);
+        assert!(matches!(app.mode, Mode::View));
+        assert_eq!(app.meeting.attendee_count("Dev"), Some(2));
+    }
+
+    #[test]
+    fn named_attendees_survive_save_and_load() {
+        let mut app = sample_app();
+        let cat = app.categories[0].clone();
+        app.meeting.add_attendee(&cat, 2);
+        app.meeting.add_person("Alice", &cat).unwrap();
+        process_key(press(KeyCode::Char('w')), &mut app);
+        for c in "team".chars() {
+            process_key(press(KeyCode::Char(c)), &mut app);
+        }
+        process_key(press(KeyCode::Enter), &mut app);
+
+        app.meeting.clear_attendees();
+        process_key(press(KeyCode::Char('l')), &mut app);
+        process_key(press(KeyCode::Enter), &mut app);
+        assert_eq!(app.meeting.attendee_count("Dev"), Some(3));
+        assert_eq!(
+            app.meeting.people().collect::<Vec<_>>(),
+            vec![("Alice", "Dev")]
+        );
+    }
+
+    #[test]
+    fn loading_duplicate_names_warns() {
+        let mut store = MemoryStore::default();
+        let dev = EmployeeCategory::new("Dev", 120_000).unwrap();
+        let lead = EmployeeCategory::new("Lead", 180_000).unwrap();
+        store.save_categories(&[dev.clone(), lead.clone()]).unwrap();
+        let mut meeting = Meeting::new();
+        meeting.add_person("Alice", &dev).unwrap();
+        meeting.add_person("alice", &lead).unwrap();
+        store
+            .save_roster("team", &meeting.roster_entries())
+            .unwrap();
+        let settings = Settings {
+            strict_duplicate_names: true,
+            ..Settings::default()
+        };
+        let mut app = App::new(store, settings, PathBuf::from(".")).unwrap();
+
+        process_key(press(KeyCode::Char('l')), &mut app);
+        process_key(press(KeyCode::Enter), &mut app);
+        assert_eq!(
+            app.status.as_deref(),
+            Some("Listed under several groups, billed once: Alice (Dev, Lead)")
+        );
+        assert_eq!(app.meeting.total_attendees(), 1);
+    }
+
+    #[test]
+    fn plus_and_minus_adjust_selected_group() {
+        let mut app = sample_app();
+        let dev = app.categories[0].clone();
+        let qa = EmployeeCategory::new("QA", 100_000).unwrap();
+        app.meeting.add_attendee(&dev, 1);
+        app.meeting.add_attendee(&qa, 2);
+
+        // Rows are ordered by cost: QA, Dev.
+        process_key(press(KeyCode::Char('r')), &mut app);
+        process_key(press(KeyCode::Down), &mut app);
+        process_key(press(KeyCode::Char('+')), &mut app);
+        assert_eq!(app.meeting.attendee_count("Dev"), Some(2));
+        // Dev now costs more than QA, and the selection follows it.
+        assert_eq!(app.selected, 0);
+        assert!(matches!(app.mode, Mode::RemoveAttendee));
+
+        process_key(press(KeyCode::Char('-')), &mut app);
+        process_key(press(KeyCode::Char('-')), &mut app);
+        assert_eq!(app.meeting.attendee_count("Dev"), None);
+        assert_eq!(app.meeting.attendees().count(), 1);
+        assert_eq!(app.selected, 0);
+
+        process_key(press(KeyCode::Char('-')), &mut app);
+        process_key(press(KeyCode::Char('-')), &mut app);
+        assert!(app.meeting.is_empty());
+        process_key(press(KeyCode::Char('-')), &mut app);
+        assert!(app.meeting.is_empty());
+        assert!(matches!(app.mode, Mode::RemoveAttendee));
+    }
+
+    #[test]
+    fn marked_groups_are_removed_after_confirmation() {
+        let mut app = sample_app();
+        let dev = app.categories[0].clone();
+        let pm = EmployeeCategory::new("PM", 240_000).unwrap();
+        let qa = EmployeeCategory::new("QA", 60_000).unwrap();
+        app.meeting.add_attendee(&dev, 1);
+        app.meeting.add_attendee(&pm, 2);
+        app.meeting.add_attendee(&qa, 1);
+
+        // Rows are ordered by cost: PM, Dev, QA.
+        process_key(press(KeyCode::Char('r')), &mut app);
+        process_key(press(KeyCode::Char(' ')), &mut app);
+        process_key(press(KeyCode::Down), &mut app);
+        process_key(press(KeyCode::Down), &mut app);
+        process_key(press(KeyCode::Char(' ')), &mut app);
+        process_key(press(KeyCode::Up), &mut app);
+        process_key(press(KeyCode::Char(' ')), &mut app);
+        process_key(press(KeyCode::Char(' ')), &mut app);
+        assert_eq!(app.marked, vec!["PM".to_string(), "QA".to_string()]);
+
+        process_key(press(KeyCode::Enter), &mut app);
+        assert!(matches!(app.mode, Mode::ConfirmRemoveGroups));
+        assert_eq!(app.meeting.total_attendees(), 4);
+        process_key(press(KeyCode::Char('y')), &mut app);
+        assert!(matches!(app.mode, Mode::View));
+        assert_eq!(app.meeting.total_attendees(), 1);
+        assert_eq!(
+            app.status.as_deref(),
+            Some("Removed 3 attendees (-$4.50/min)")
+        );
+        assert!(app.marked.is_empty());
+
+        process_key(press(KeyCode::Char('r')), &mut app);
+        process_key(press(KeyCode::Char(' ')), &mut app);
+        process_key(press(KeyCode::Enter), &mut app);
+        process_key(press(KeyCode::Esc), &mut app);
+        assert_eq!(app.meeting.attendee_count("Dev"), Some(1));
+    }
+
+    #[test]
+    fn data_dir_honors_environment() {
+        let exe = Path::new("/opt/mct/bin/mct");
+        let custom = PathBuf::from("/srv/share/meeting_cost_tracker");
+        assert_eq!(
+            resolve_data_dir(Some(custom.clone().into_os_string()), exe),
+            custom
+        );
+        assert_eq!(
+            resolve_data_dir(Some(OsString::new()), exe),
+            Path::new("/opt/mct/bin/data")
+        );
+        assert_eq!(resolve_data_dir(None, exe), Path::new("/opt/mct/bin/data"));
+        assert_eq!(resolve_data_dir(None, Path::new("/")), Path::new("./data"));
+    }
+
+    #[test]
+    fn category_edit_replaces_and_rejects_collisions() {
+        let mut categories = vec![
+            EmployeeCategory::new("Dev", 120_000).unwrap(),
+            EmployeeCategory::new("PM", 150_000).unwrap(),
+        ];
+        let settings = Settings::default();
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&categories[0], 1);
+
+        let edit = |categories: &mut Vec<EmployeeCategory>, idx, input, meeting: &mut Meeting| {
+            apply_category_edit(categories, idx, input, &settings, meeting)
+        };
+        assert_eq!(
+            edit(&mut categories, 0, "Dev:240000", &mut meeting),
+            Ok("Updated category Dev".to_string())
+        );
+        assert_eq!(categories[0].salary(), 240_000);
+        assert_cost_eq(meeting.burn_rate_per_minute(), 2.0, 1e-9);
+
+        assert_eq!(
+            edit(&mut categories, 1, "Dev:1", &mut meeting),
+            Err("A category named Dev already exists".to_string())
+        );
+        assert!(edit(&mut categories, 1, "PM", &mut meeting).is_err());
+        assert!(edit(&mut categories, 1, "PM:lots", &mut meeting).is_err());
+        assert!(edit(&mut categories, 1, " :5", &mut meeting).is_err());
+        assert_eq!(categories[1], EmployeeCategory::new("PM", 150_000).unwrap());
+
+        assert!(edit(
+            &mut categories,
+            1,
+            " Product Manager : 160000 ",
+            &mut meeting
+        )
+        .is_ok());
+        assert_eq!(
+            categories[1],
+            EmployeeCategory::new("Product Manager", 160_000).unwrap()
+        );
+
+        assert!(edit(&mut categories, 1, "PM:160000: HR band v7 ", &mut meeting).is_ok());
+        assert_eq!(categories[1].source(), Some("HR band v7"));
+        assert!(edit(&mut categories, 1, "PM:160000", &mut meeting).is_ok());
+        assert_eq!(categories[1].source(), Some("HR band v7"));
+        assert!(edit(&mut categories, 1, "PM:160000: ", &mut meeting).is_ok());
+        assert_eq!(categories[1].source(), None);
+
+        let year = Duration::from_hours(365 * 24);
+        let aged = std::time::SystemTime::now().checked_sub(year * 2);
+        categories[1].set_updated_at(aged);
+        assert!(edit(&mut categories, 1, "Product:160000", &mut meeting).is_ok());
+        assert_eq!(categories[1].updated_at(), aged);
+        assert!(edit(&mut categories, 1, "Product:170000", &mut meeting).is_ok());
+        assert!(!categories[1].is_stale(year));
+    }
+
+    #[test]
+    fn edited_category_keeps_usage_and_estimate() {
+        let recently = std::time::SystemTime::now();
+        let mut dev = EmployeeCategory::new("Dev", 120_000)
+            .unwrap()
+            .with_salary_range(100_000, 140_000)
+            .unwrap();
+        dev.set_approximate(true);
+        dev.set_last_used(Some(recently));
+        let mut pm = EmployeeCategory::new("PM", 150_000).unwrap();
+        pm.set_last_used(recently.checked_sub(Duration::from_hours(1)));
+        let mut categories = vec![dev, pm, EmployeeCategory::new("QA", 90_000).unwrap()];
+        let mut meeting = Meeting::new();
+
+        let settings = Settings::default();
+        apply_category_edit(
+            &mut categories,
+            0,
+            "Developer:120000",
+            &settings,
+            &mut meeting,
+        )
+        .unwrap();
+        let mut usage = CategoryUsage::from_categories(&categories);
+        let evicted = usage.evict(&mut categories, 1, &meeting);
+        assert_eq!(
+            evicted
+                .iter()
+                .map(EmployeeCategory::title)
+                .collect::<Vec<_>>(),
+            ["QA", "PM"]
+        );
+        assert_eq!(categories[0].title(), "Developer");
+        assert_eq!(categories[0].last_used(), Some(recently));
+        assert!(categories[0].is_approximate());
+        assert_eq!(categories[0].salary_range(), Some((100_000, 140_000)));
+
+        // A new salary is exact, and the range stays only while it fits.
+        apply_category_edit(
+            &mut categories,
+            0,
+            "Developer:130000",
+            &settings,
+            &mut meeting,
+        )
+        .unwrap();
+        assert!(!categories[0].is_approximate());
+        assert_eq!(categories[0].salary_range(), Some((100_000, 140_000)));
+        apply_category_edit(
+            &mut categories,
+            0,
+            "Developer:150000",
+            &settings,
+            &mut meeting,
+        )
+        .unwrap();
+        assert_eq!(categories[0].salary_range(), None);
+        assert_eq!(categories[0].last_used(), Some(recently));
+    }
+
+    #[test]
+    fn edit_key_prefills_selected_category() {
+        let mut app = sample_app();
+        process_key(press(KeyCode::Char('x')), &mut app);
+        process_key(press(KeyCode::Enter), &mut app);
+        assert!(matches!(app.mode, Mode::EditCategory));
+        assert_eq!(app.input_text, "Dev:120000");
+        for c in ":HR".chars() {
+            process_key(press(KeyCode::Char(c)), &mut app);
+        }
+        process_key(press(KeyCode::Enter), &mut app);
+        assert_eq!(app.categories[0].source(), Some("HR"));
+        process_key(press(KeyCode::Char('x')), &mut app);
+        process_key(press(KeyCode::Enter), &mut app);
+        assert_eq!(app.input_text, "Dev:120000:HR");
+        for _ in 0..3 {
+            process_key(press(KeyCode::Backspace), &mut app);
+        }
+        for _ in 0..6 {
+            process_key(press(KeyCode::Backspace), &mut app);
+        }
+        for c in "99000".chars() {
+            process_key(press(KeyCode::Char(c)), &mut app);
+        }
+        process_key(press(KeyCode::Enter), &mut app);
+        assert_eq!(app.categories[0].salary(), 99_000);
+        assert_eq!(app.status.as_deref(), Some("Updated category Dev"));
+    }
+
+    #[test]
+    fn undo_key_reverts_removal() {
+        let mut app = sample_app();
+        let cat = app.categories[0].clone();
+        app.meeting.add_attendee(&cat, 3);
+        process_key(press(KeyCode::Char('r')), &mut app);
+        process_key(press(KeyCode::Enter), &mut app);
+        assert!(app.meeting.is_empty());
+        process_key(press(KeyCode::Char('u')), &mut app);
+        assert_eq!(app.meeting.attendee_count("Dev"), Some(3));
+        assert!(app.status.is_none());
+        process_key(press(KeyCode::Char('u')), &mut app);
+        process_key(press(KeyCode::Char('u')), &mut app);
+        assert!(app.meeting.is_empty());
+        assert_eq!(app.status.as_deref(), Some("Nothing to undo"));
+    }
+
+    #[test]
+    fn status_is_cleared_on_next_key() {
+        let mut app = sample_app();
+        app.status = Some("something happened".into());
+        process_key(press(KeyCode::Char('p')), &mut app);
+        assert!(app.status.is_none());
+    }
+
+    #[test]
+    fn export_key_writes_to_export_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut app = sample_app();
+        app.export_dir = dir.path().join("exports");
+        process_key(press(KeyCode::Char('o')), &mut app);
+        process_key(press(KeyCode::Char('o')), &mut app);
+        let expected = app.export_dir.join("meeting-1.tsv");
+        assert_eq!(
+            app.status,
+            Some(format!("Exported to {}", expected.display()))
+        );
+        assert!(app.export_dir.join("meeting.tsv").exists());
+    }
+
+    #[test]
+    fn cli_argument_forms() {
+        let args = |list: &[&str]| list.iter().map(ToString::to_string).collect::<Vec<_>>();
+        let export_dir = |list: &[&str]| parse_cli_args(args(list)).map(|o| o.export_dir);
+        assert_eq!(export_dir(&[]), Ok(None));
+        assert_eq!(
+            export_dir(&["--export-dir", "out"]),
+            Ok(Some(PathBuf::from("out")))
+        );
+        assert_eq!(
+            export_dir(&["--export-dir=out"]),
+            Ok(Some(PathBuf::from("out")))
+        );
+        assert_eq!(
+            parse_cli_args(args(&[
+                "--exit-summary-file=run.json",
+                "--export-dir",
+                "out"
+            ])),
+            Ok(CliOptions {
+                export_dir: Some(PathBuf::from("out")),
+                exit_summary_file: Some(PathBuf::from("run.json")),
+            })
+        );
+        assert!(parse_cli_args(args(&["--export-dir"])).is_err());
+        assert!(parse_cli_args(args(&["--exit-summary-file"])).is_err());
+        assert!(parse_cli_args(args(&["--bogus"])).is_err());
+    }
+
+    #[test]
+    fn scripted_run_records_events() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut app = sample_app();
+        app.export_dir = dir.path().to_path_buf();
+        let keys = [
+            KeyCode::Char('o'),
+            KeyCode::Char('b'),
+            KeyCode::Char('?'),
+            KeyCode::Enter,
+            KeyCode::Char('t'),
+            KeyCode::Char('y'),
+            KeyCode::Enter,
+            KeyCode::Char('u'),
+        ];
+        for code in keys {
+            process_key(press(code), &mut app);
+        }
+        assert_eq!(
+            app.events.files_written,
+            vec![dir.path().join("meeting.tsv")]
+        );
+        assert_eq!(app.events.errors.len(), 2);
+        assert!(app.events.errors[0].starts_with("Invalid budget"));
+        assert_eq!(app.events.errors[1], "Enter a whole number of seconds");
+        // Messages that are not errors are only shown.
+        assert_eq!(app.status.as_deref(), Some("Nothing to undo"));
+    }
+
+    #[test]
+    fn exit_summary_is_json() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("summary.json");
+        let mut app = sample_app();
+        let cat = app.categories[0].clone();
+        app.meeting.add_attendee(&cat, 3);
+        app.events.errors.push("Failed to export: disk full".into());
+        app.events.files_written.push(PathBuf::from("meeting.tsv"));
+        write_exit_summary(&path, &app.meeting, &app.events).unwrap();
+        let json: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(json["summary"]["headcount"], 3);
+        assert_eq!(json["files_written"][0], "meeting.tsv");
+        assert_eq!(json["errors"][0], "Failed to export: disk full");
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn daemon_arguments() {
+        let args = |list: &[&str]| list.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            parse_daemon_args(args(&["--load", "roster.toml", "--listen", "0.0.0.0:80"])),
+            Ok(DaemonOptions {
+                load: Some(PathBuf::from("roster.toml")),
+                listen: "0.0.0.0:80".to_string(),
+            })
+        );
+        assert_eq!(
+            parse_daemon_args(args(&[])).unwrap().listen,
+            "127.0.0.1:7878"
+        );
+        assert!(parse_daemon_args(args(&["--listen"])).is_err());
+        assert!(parse_daemon_args(args(&["--bogus"])).is_err());
+    }
+
+    #[test]
+    fn timed_start_key_arms_meeting() {
+        let mut app = sample_app();
+        process_key(press(KeyCode::Char('t')), &mut app);
+        for c in "90".chars() {
+            process_key(press(KeyCode::Char(c)), &mut app);
+        }
+        process_key(press(KeyCode::Enter), &mut app);
+        assert!(matches!(app.mode, Mode::View));
+        assert!(app.meeting.is_armed());
+        assert!(!app.meeting.is_running());
+    }
+
+    #[test]
+    fn settings_cap_meeting_duration() {
+        let settings = Settings {
+            max_meeting_minutes: Some(60),
+            ..Settings::default()
+        };
+        let app = App::new(MemoryStore::default(), settings, PathBuf::from(".")).unwrap();
+        assert_eq!(app.meeting.max_duration(), Some(Duration::from_hours(1)));
+    }
+
+    #[test]
+    fn adjust_time_key_corrects_clock() {
+        let mut app = sample_app();
+        for input in ["+10", "-3", "bogus"] {
+            process_key(press(KeyCode::Char('m')), &mut app);
+            for c in input.chars() {
+                process_key(press(KeyCode::Char(c)), &mut app);
+            }
+            process_key(press(KeyCode::Enter), &mut app);
+        }
+        assert_eq!(app.meeting.duration(), Duration::from_mins(7));
+        assert_eq!(app.status.as_deref(), Some("Enter minutes as +N, -N or N"));
+
+        assert!(apply_elapsed_adjustment(&mut app.meeting, " 2 "));
+        assert_eq!(app.meeting.duration(), Duration::from_mins(2));
+        assert!(!apply_elapsed_adjustment(&mut app.meeting, "+"));
+    }
+
+    #[test]
+    fn attendee_count_prompt_uses_shared_rules() {
+        let mut app = sample_app();
+        process_key(press(KeyCode::Char('e')), &mut app);
+        process_key(press(KeyCode::Enter), &mut app);
+        assert_eq!(
+            count_input_preview(&app.input_text, 1),
+            "Enter attendee count (Enter adds 1)"
+        );
+        process_key(press(KeyCode::Char('0')), &mut app);
+        process_key(press(KeyCode::Enter), &mut app);
+        assert!(matches!(app.mode, Mode::AddAttendeeCount));
+        assert_eq!(app.status.as_deref(), Some("Count must be at least 1"));
+
+        process_key(press(KeyCode::Backspace), &mut app);
+        for c in "1_2".chars() {
+            process_key(press(KeyCode::Char(c)), &mut app);
+        }
+        assert_eq!(
+            count_input_preview(&app.input_text, 1),
+            "Enter attendee count (Enter adds 12)"
+        );
+        process_key(press(KeyCode::Enter), &mut app);
+        assert_eq!(app.meeting.attendee_count("Dev"), Some(12));
+    }
+
+    #[test]
+    fn empty_count_uses_configured_defaults() {
+        let mut store = MemoryStore::default();
+        store
+            .save_categories(&[
+                EmployeeCategory::new("Dev", 120_000).unwrap(),
+                EmployeeCategory::new("QA", 90_000)
+                    .unwrap()
+                    .with_default_count(3),
+            ])
+            .unwrap();
+        let mut app = App::new(store, Settings::default(), PathBuf::from(".")).unwrap();
+        let add_empty = |app: &mut App<MemoryStore>, down: usize| {
+            process_key(press(KeyCode::Char('e')), app);
+            for _ in 0..down {
+                process_key(press(KeyCode::Down), app);
+            }
+            process_key(press(KeyCode::Enter), app);
+            process_key(press(KeyCode::Enter), app);
+        };
+        add_empty(&mut app, 0);
+        add_empty(&mut app, 1);
+        assert_eq!(app.meeting.attendee_count("Dev"), Some(1));
+        assert_eq!(app.meeting.attendee_count("QA"), Some(3));
+
+        app.settings.default_attendee_count = Some(2);
+        add_empty(&mut app, 0);
+        add_empty(&mut app, 1);
+        assert_eq!(app.meeting.attendee_count("Dev"), Some(3));
+        assert_eq!(app.meeting.attendee_count("QA"), Some(5));
+    }
+
+    #[test]
+    fn budget_key_sets_and_clears_budget() {
+        let mut app = sample_app();
+        process_key(press(KeyCode::Char('b')), &mut app);
+        for c in "250".chars() {
+            process_key(press(KeyCode::Char(c)), &mut app);
+        }
+        process_key(press(KeyCode::Enter), &mut app);
+        assert_eq!(app.meeting.budget(), Some(250.0));
+
+        process_key(press(KeyCode::Char('b')), &mut app);
+        process_key(press(KeyCode::Char('x')), &mut app);
+        process_key(press(KeyCode::Enter), &mut app);
+        assert_eq!(app.meeting.budget(), Some(250.0));
+        assert!(app.status.as_deref().unwrap().starts_with("Invalid budget"));
+
+        process_key(press(KeyCode::Char('b')), &mut app);
+        process_key(press(KeyCode::Enter), &mut app);
+        assert_eq!(app.meeting.budget(), None);
+    }
+
+    #[test]
+    fn adding_category_evicts_least_recently_used() {
+        let mut store = MemoryStore::default();
+        store
+            .save_categories(&[
+                EmployeeCategory::new("Dev", 120_000).unwrap(),
+                EmployeeCategory::new("QA", 90_000).unwrap(),
+            ])
+            .unwrap();
+        let settings = Settings {
+            max_categories: Some(2),
+            ..Settings::default()
+        };
+        let mut app = App::new(store, settings, PathBuf::from(".")).unwrap();
+        // Dev is in the meeting, so QA is the only candidate.
+        process_key(press(KeyCode::Char('e')), &mut app);
+        process_key(press(KeyCode::Enter), &mut app);
+        process_key(press(KeyCode::Enter), &mut app);
+        process_key(press(KeyCode::Char('a')), &mut app);
+        for c in "PM:150000".chars() {
+            process_key(press(KeyCode::Char(c)), &mut app);
+        }
+        process_key(press(KeyCode::Enter), &mut app);
+        let titles: Vec<&str> = app.categories.iter().map(EmployeeCategory::title).collect();
+        assert_eq!(titles, vec!["Dev", "PM"]);
+        assert_eq!(app.status.as_deref(), Some("Removed unused categories: QA"));
+    }
+
+    #[test]
+    fn added_salary_is_rounded_per_settings() {
+        let settings = Settings {
+            round_salary_to: Some(1_000),
+            ..Settings::default()
+        };
+        let mut app = App::new(MemoryStore::default(), settings, PathBuf::from(".")).unwrap();
+        process_key(press(KeyCode::Char('a')), &mut app);
+        for c in "QA:123456".chars() {
+            process_key(press(KeyCode::Char(c)), &mut app);
+        }
+        process_key(press(KeyCode::Enter), &mut app);
+        assert_eq!(app.categories[0].salary(), 123_000);
+    }
+
+    #[test]
+    fn re_adding_category_updates_salary_in_meeting() {
+        let mut app = sample_app();
+        let cat = app.categories[0].clone();
+        app.meeting.add_attendee(&cat, 1);
+        process_key(press(KeyCode::Char('a')), &mut app);
+        for c in "Dev:240000".chars() {
+            process_key(press(KeyCode::Char(c)), &mut app);
+        }
+        process_key(press(KeyCode::Enter), &mut app);
+        assert_eq!(app.categories.len(), 1);
+        assert_eq!(app.categories[0].salary(), 240_000);
+        assert_cost_eq(app.meeting.burn_rate_per_minute(), 2.0, 1e-9);
+        assert_eq!(app.status.as_deref(), Some("Updated salary for Dev"));
     }
 
     #[test]
@@ -1,19 +1,336 @@
-use std::collections::HashMap;
-use std::time::{Duration, Instant};
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
 
-use crate::model::EmployeeCategory;
+use serde::{Deserialize, Serialize};
+
+use crate::clock::{Clock, Monotonic};
+use crate::model::{
+    EmployeeCategory, WorkYear, DEFAULT_WORK_HOURS_PER_YEAR, MILLIS_PER_HOUR, MILLIS_PER_WORK_YEAR,
+};
+use crate::snapshot::{MeetingSnapshot, RosterChange};
+use crate::storage::AttendeeInfo;
+use thiserror::Error;
+
+/// Errors related to meeting configuration and attendee management.
+#[derive(Debug, Error, PartialEq)]
+pub enum MeetingError {
+    /// The number of work hours per year must be a positive, finite number.
+    #[error("Work hours per year must be greater than zero, got {0}")]
+    InvalidWorkHours(f64),
+
+    /// A fraction must lie within `0.0..=1.0`.
+    #[error("Fraction must be between 0 and 1, got {0}")]
+    InvalidFraction(f64),
+
+    /// Overhead must be a finite, non-negative fraction.
+    #[error("Overhead must be a non-negative fraction, got {0}")]
+    InvalidOverhead(f64),
+
+    /// A cost multiplier must be finite and at least `1.0`.
+    #[error("Cost multiplier must be at least 1, got {0}")]
+    InvalidMultiplier(f64),
+
+    /// A budget must be a finite, non-negative amount of dollars.
+    #[error("Budget must be a non-negative amount, got {0}")]
+    InvalidBudget(f64),
+
+    /// Attendee counts must be at least one.
+    #[error("Attendee count must be greater than zero")]
+    InvalidCount,
+
+    /// Adding the attendees would overflow the group's count.
+    #[error("Too many attendees titled '{0}'")]
+    TooManyAttendees(String),
+
+    /// No attendee group with the given title is part of the meeting.
+    #[error("No attendees titled '{0}' in the meeting")]
+    AttendeeNotFound(String),
+
+    /// Both meetings list the same title with different salaries.
+    #[error("Attendees titled '{0}' have different salaries")]
+    SalaryMismatch(String),
+
+    /// The operation requires stopped meetings.
+    #[error("Meetings must be stopped first")]
+    MeetingRunning,
+
+    /// A person with the given name is already part of the meeting.
+    #[error("'{0}' is already in the meeting")]
+    PersonExists(String),
+
+    /// No person with the given name is part of the meeting.
+    #[error("'{0}' is not in the meeting")]
+    PersonNotFound(String),
+
+    /// A saved attendee entry lacks a usable title or salary.
+    #[error("Attendee entry '{0}' needs a title and a positive salary")]
+    InvalidAttendee(String),
+
+    /// The operation requires a running meeting.
+    #[error("The meeting is not running")]
+    NotRunning,
+
+    /// A [`MeetingSet`](crate::MeetingSet) already has a meeting with the
+    /// given name.
+    #[error("A meeting named '{0}' already exists")]
+    MeetingExists(String),
+
+    /// A [`MeetingSet`](crate::MeetingSet) has no meeting with the given name.
+    #[error("No meeting named '{0}'")]
+    MeetingNotFound(String),
+
+    /// A start delay reaches past the latest instant the clock can represent.
+    #[error("Start delay is too far in the future")]
+    InvalidDelay,
+}
 
 /// Internal record of attendees sharing the same salary.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 struct Attendee {
     salary: u64,
     count: u32,
+    /// Share of the group's cost that is billed, in [`ATTENTION_SCALE`] parts.
+    attention: u32,
 }
 
 impl Attendee {
     fn new(salary: u64, count: u32) -> Self {
-        Self { salary, count }
+        Self {
+            salary,
+            count,
+            attention: ATTENTION_SCALE,
+        }
+    }
+}
+
+/// How attendee time is billed when the roster changes during a meeting.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CostModel {
+    /// Each attendee group is billed only for the time it was present.
+    ///
+    /// Someone added at minute 30 of a 60 minute meeting costs 30 minutes,
+    /// and a group that left keeps the cost it accrued while present.
+    #[default]
+    TimeWeighted,
+    /// The current roster is billed for the whole meeting duration, no matter
+    /// when attendees joined. Attendees that left cost nothing.
+    FlatFromStart,
+}
+
+/// Cost contributed by one attendee group, as returned by [`Meeting::breakdown`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CategoryCost {
+    /// Title of the attendee group.
+    pub title: String,
+    /// Annual salary of each attendee in the group.
+    pub salary: u64,
+    /// Number of attendees currently in the group.
+    pub count: u32,
+    /// Dollars accrued by the group so far; [`CategoryCost::cost_cents`]
+    /// as a float.
+    pub cost: f64,
+    /// Cents accrued by the group so far, computed exactly and rounded half-up.
+    pub cost_cents: u64,
+}
+
+/// Time and cost of one agenda segment, as returned by [`Meeting::segments`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SegmentCost {
+    /// Name given to [`Meeting::begin_segment`].
+    pub name: String,
+    /// Meeting time the segment lasted.
+    pub duration: Duration,
+    /// Dollars the attendees present during the segment cost.
+    pub cost: f64,
+}
+
+/// A named slice of meeting time recorded by [`Meeting::begin_segment`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Segment {
+    name: String,
+    start: Duration,
+    /// Meeting time the segment ended, or `None` while it is open.
+    end: Option<Duration>,
+}
+
+/// Snapshot of a meeting for reporting, as returned by [`Meeting::summary`].
+///
+/// The summary owns its data, so it outlives a later [`Meeting::reset`] and
+/// can be serialized straight to TOML or JSON.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MeetingSummary {
+    /// Name of the meeting, if one was set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Wall-clock time at which the meeting first started.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<SystemTime>,
+    /// Wall-clock time at which the meeting last stopped, or `None` while it
+    /// is running.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ended_at: Option<SystemTime>,
+    /// Time the meeting has run.
+    pub duration: Duration,
+    /// Dollars the meeting has cost.
+    pub total_cost: f64,
+    /// Number of attendees present at the time of the summary.
+    pub headcount: u32,
+    /// Attendee counts and costs per group, sorted by title.
+    pub categories: Vec<CategoryCost>,
+    /// Whether the costs use approximate salaries; see
+    /// [`Meeting::is_approximate`].
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub approximate: bool,
+    /// Agenda segments in the order they began; see [`Meeting::segments`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub segments: Vec<SegmentCost>,
+}
+
+/// Outcome of removing several attendee groups with [`Meeting::remove_groups`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemovalReport {
+    /// Removed groups with the number of attendees each had, in request order.
+    pub removed: Vec<(String, u32)>,
+    /// Requested titles that are not part of the meeting.
+    pub unknown: Vec<String>,
+    /// Total number of attendees removed.
+    pub headcount: u32,
+    /// Dollars per minute the burn rate dropped by.
+    pub burn_rate_reduction: f64,
+}
+
+/// Snapshot of a running meeting, sent to subscribers by [`Meeting::tick`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CostUpdate {
+    /// Meeting duration at the time of the tick.
+    pub duration: Duration,
+    /// Total cost in dollars at the time of the tick.
+    pub total_cost: f64,
+}
+
+/// Everything a frontend renders about a meeting's cost, returned by
+/// [`Meeting::snapshot_cost`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CostSnapshot {
+    /// Meeting duration when the snapshot was taken.
+    pub duration: Duration,
+    /// Total cost in dollars.
+    pub total: f64,
+    /// Cost in dollars of each attendee group, ordered by title.
+    pub per_category: Vec<(String, f64)>,
+}
+
+/// Direction of the burn rate's most recent change, as returned by
+/// [`Meeting::rate_trend`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    /// The last roster change made the meeting more expensive per minute.
+    Increasing,
+    /// The burn rate has not changed, or the last change left it as it was.
+    Steady,
+    /// The last roster change made the meeting cheaper per minute.
+    Decreasing,
+}
+
+/// Something that happened at a point in a meeting, as listed by
+/// [`Meeting::timeline`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum TimelineEvent {
+    /// An attendee group changed size, changing the meeting's burn rate.
+    RateChange {
+        /// Meeting time at which the change happened.
+        at: Duration,
+        /// Title of the group that changed.
+        title: String,
+        /// Size of the group after the change.
+        count: u32,
+        /// Burn rate of the whole meeting after the change, in dollars per minute.
+        burn_rate_per_minute: f64,
+    },
+}
+
+impl TimelineEvent {
+    /// Returns the meeting time at which the event happened.
+    #[must_use]
+    pub fn at(&self) -> Duration {
+        match self {
+            Self::RateChange { at, .. } => *at,
+        }
+    }
+}
+
+/// A change in the size of one attendee group at a point in the meeting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct RosterEvent {
+    /// Meeting time at which the change happened.
+    at: Duration,
+    title: String,
+    salary: u64,
+    /// Group size from `at` onward.
+    count: u32,
+    /// Attention of the group from `at` onward, in [`ATTENTION_SCALE`] parts.
+    attention: u32,
+}
+
+/// Maximum number of attendee changes kept for [`Meeting::undo_last_attendee_change`].
+const UNDO_LIMIT: usize = 32;
+
+/// Fixed-point scale used to turn the overhead fraction into an integer.
+const OVERHEAD_SCALE: u128 = 1_000_000;
+
+/// Fixed-point scale of a group's attention; this many parts is full attention.
+const ATTENTION_SCALE: u32 = 1_000_000;
+
+/// Returns `attention` parts of [`ATTENTION_SCALE`] as a fraction.
+fn attention_fraction(attention: u32) -> f64 {
+    f64::from(attention) / f64::from(ATTENTION_SCALE)
+}
+
+/// Converts an attention fraction into [`ATTENTION_SCALE`] parts.
+///
+/// Returns [`MeetingError::InvalidFraction`] unless `attention` lies within
+/// `(0.0, 1.0]`.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn attention_parts(attention: f64) -> Result<u32, MeetingError> {
+    if !(attention > 0.0 && attention <= 1.0) {
+        return Err(MeetingError::InvalidFraction(attention));
     }
+    Ok(((attention * f64::from(ATTENTION_SCALE)).round() as u32).max(1))
+}
+
+/// Converts `usage / denominator` into dollars, dividing the whole dollars
+/// exactly so that only the fraction is subject to float rounding.
+#[allow(clippy::cast_precision_loss)]
+fn dollars(usage: u128, denominator: u128) -> f64 {
+    (usage / denominator) as f64 + (usage % denominator) as f64 / denominator as f64
+}
+
+/// Converts `usage / denominator` dollars into cents, rounding half-up.
+fn cents_half_up(usage: u128, denominator: u128) -> u128 {
+    (usage * 200 + denominator) / (denominator * 2)
+}
+
+/// State of one attendee group before an add or remove, used for undo.
+#[derive(Debug, Clone)]
+struct AttendeeChange {
+    title: String,
+    previous: Option<Attendee>,
+    /// Named people in the group before the change.
+    people: Vec<String>,
+}
+
+/// Opaque copy of a meeting's attendee roster.
+///
+/// Obtained from [`Meeting::roster_snapshot`] and handed back to
+/// [`Meeting::restore_roster`] to undo roster changes such as loading a file.
+#[derive(Debug, Clone)]
+pub struct RosterSnapshot {
+    attendees: HashMap<String, Attendee>,
+    people: BTreeMap<String, String>,
 }
 
 #[derive(Debug)]
@@ -22,6 +339,43 @@ pub struct Meeting {
     start_time: Option<Instant>,
     elapsed: Duration,
     running: bool,
+    /// Instant at which an armed meeting starts by itself.
+    scheduled_start: Option<Instant>,
+    /// Duration after which the meeting stops by itself.
+    max_duration: Option<Duration>,
+    /// Dollars the meeting is allowed to cost.
+    budget: Option<f64>,
+    /// Name of the meeting used as a heading in summaries.
+    title: Option<String>,
+    /// Fraction added on top of salaries, e.g. `0.3` for 30% overhead.
+    overhead: f64,
+    work_hours_per_year: f64,
+    fired_thresholds: Vec<f64>,
+    undo: VecDeque<AttendeeChange>,
+    cost_model: CostModel,
+    roster_log: Vec<RosterEvent>,
+    /// Named attendees, mapping each name to its category title.
+    people: BTreeMap<String, String>,
+    /// Channels that receive a [`CostUpdate`] on every [`Meeting::tick`].
+    subscribers: Vec<Sender<CostUpdate>>,
+    /// Whether people named under several groups are billed only once.
+    strict_duplicates: bool,
+    /// Wall-clock time of the first start since the last reset.
+    started_at: Option<SystemTime>,
+    /// Wall-clock time of the last stop, cleared when the meeting restarts.
+    ended_at: Option<SystemTime>,
+    /// Whether snapshots of the running meeting record the wall-clock time,
+    /// so that restoring them counts the time in between.
+    wall_clock_resume: bool,
+    /// Titles of groups added from a category with an approximate salary.
+    approximate_groups: BTreeSet<String>,
+    /// Salary ranges of groups added from a category that has one.
+    salary_ranges: HashMap<String, (u64, u64)>,
+    /// Agenda segments in the order they began.
+    segments: Vec<Segment>,
+    /// Source of the current time; [`Monotonic`] unless set by
+    /// [`Meeting::with_clock`].
+    clock: Arc<dyn Clock>,
 }
 
 impl Meeting {
@@ -45,6 +399,7 @@ impl Meeting {
     /// # See Also
     /// * [`Meeting::start`]
     /// * [`Meeting::add_attendee`]
+    /// * [`Meeting::with_work_hours`]
     #[must_use]
     pub fn new() -> Self {
         Self {
@@ -52,437 +407,5901 @@ impl Meeting {
             start_time: None,
             elapsed: Duration::ZERO,
             running: false,
+            scheduled_start: None,
+            max_duration: None,
+            budget: None,
+            title: None,
+            overhead: 0.0,
+            work_hours_per_year: DEFAULT_WORK_HOURS_PER_YEAR,
+            fired_thresholds: Vec::new(),
+            undo: VecDeque::new(),
+            cost_model: CostModel::default(),
+            roster_log: Vec::new(),
+            people: BTreeMap::new(),
+            subscribers: Vec::new(),
+            strict_duplicates: false,
+            started_at: None,
+            ended_at: None,
+            wall_clock_resume: false,
+            approximate_groups: BTreeSet::new(),
+            salary_ranges: HashMap::new(),
+            segments: Vec::new(),
+            clock: Arc::new(Monotonic),
         }
     }
 
-    /// Adds `count` attendees of a given [`EmployeeCategory`] to the meeting.
+    /// Creates a new, empty [`Meeting`] that reads the time from `clock`.
+    ///
+    /// [`Meeting::new`] uses the system's [`Monotonic`] clock; pass a
+    /// `testing::ManualClock`, from the `test-util` feature, to control time
+    /// in tests.
     ///
     /// ## Example
     /// ```
-    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
-    /// let mut meeting = Meeting::new();
-    /// let cat = EmployeeCategory::new("Engineer", 100_000).unwrap();
-    /// meeting.add_attendee(&cat, 3);
+    /// use std::time::Duration;
+    /// use meeting_cost_tracker::testing::ManualClock;
+    /// use meeting_cost_tracker::Meeting;
+    ///
+    /// let clock = ManualClock::new();
+    /// let mut meeting = Meeting::with_clock(clock.clone());
+    /// meeting.start();
+    /// clock.advance(Duration::from_secs(90));
+    /// assert_eq!(meeting.duration(), Duration::from_secs(90));
     /// ```
     ///
     /// # Arguments
     ///
-    /// * `category` - The [`EmployeeCategory`] to add.
-    /// * `count` - Number of attendees to add.
+    /// * `clock` - The [`Clock`] used by `start`, `stop` and `duration`.
     ///
     /// # Returns
     ///
-    /// Nothing.
+    /// A new empty [`Meeting`].
     ///
     /// # See Also
-    /// * [`Meeting::remove_attendee`]
-    pub fn add_attendee(&mut self, category: &EmployeeCategory, count: u32) {
-        let entry = self
-            .attendees
-            .entry(category.title().to_string())
-            .or_insert_with(|| Attendee::new(category.salary(), 0));
-        entry.count += count;
+    /// * [`Meeting::new`]
+    #[must_use]
+    pub fn with_clock(clock: impl Clock + 'static) -> Self {
+        Self {
+            clock: Arc::new(clock),
+            ..Self::new()
+        }
     }
 
-    /// Removes up to `count` attendees of the given title from the meeting.
-    /// If the resulting count is zero, the attendee entry is removed entirely.
+    /// Returns the wall-clock time corresponding to a past `instant`.
+    fn wall_clock_of(&self, instant: Instant) -> Option<SystemTime> {
+        SystemTime::now().checked_sub(self.clock.now().saturating_duration_since(instant))
+    }
+
+    /// Creates a new, empty [`Meeting`] that spreads salaries across
+    /// `hours` working hours per year instead of the default 2,000.
     ///
     /// ## Example
     /// ```
-    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
-    /// let cat = EmployeeCategory::new("Dev", 90_000).unwrap();
-    /// let mut meeting = Meeting::new();
-    /// meeting.add_attendee(&cat, 2);
-    /// meeting.remove_attendee("Dev", 1);
+    /// use meeting_cost_tracker::Meeting;
+    /// let meeting = Meeting::with_work_hours(1760.0).unwrap();
+    /// assert_eq!(meeting.work_hours_per_year(), 1760.0);
     /// ```
     ///
     /// # Arguments
     ///
-    /// * `title` - Title of the attendees to remove.
-    /// * `count` - Number of attendees to remove.
+    /// * `hours` - Working hours in a year used to derive hourly rates.
     ///
     /// # Returns
     ///
-    /// Nothing.
+    /// A new empty [`Meeting`] on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeetingError::InvalidWorkHours`] if `hours` is not a positive,
+    /// finite number.
     ///
     /// # See Also
-    /// * [`Meeting::add_attendee`]
-    pub fn remove_attendee(&mut self, title: &str, count: u32) {
-        if let Some(entry) = self.attendees.get_mut(title) {
-            if entry.count <= count {
-                self.attendees.remove(title);
-            } else {
-                entry.count -= count;
-            }
-        }
+    /// * [`Meeting::set_work_hours`]
+    pub fn with_work_hours(hours: f64) -> Result<Self, MeetingError> {
+        let mut meeting = Self::new();
+        meeting.set_work_hours(hours)?;
+        Ok(meeting)
     }
 
-    /// Returns an iterator over the attendee list.
+    /// Changes the number of working hours per year used for cost computation.
     ///
-    /// The iterator yields a tuple of `(title, salary, count)` for each attendee group.
+    /// The new value applies to the whole meeting, including time that has
+    /// already elapsed.
     ///
     /// ## Example
     /// ```
-    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
-    /// let cat = EmployeeCategory::new("QA", 80_000).unwrap();
+    /// use meeting_cost_tracker::Meeting;
     /// let mut meeting = Meeting::new();
-    /// meeting.add_attendee(&cat, 1);
-    /// for (title, salary, count) in meeting.attendees() {
-    ///     println!("{} - {} x ${}", title, count, salary);
-    /// }
+    /// meeting.set_work_hours(2080.0).unwrap();
+    /// assert!(meeting.set_work_hours(0.0).is_err());
+    /// assert_eq!(meeting.work_hours_per_year(), 2080.0);
     /// ```
     ///
     /// # Arguments
     ///
-    /// * None
+    /// * `hours` - Working hours in a year used to derive hourly rates.
     ///
     /// # Returns
     ///
-    /// An iterator over `(title, salary, count)` entries.
+    /// Nothing on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeetingError::InvalidWorkHours`] if `hours` is not a positive,
+    /// finite number. The previous value is kept in that case.
     ///
     /// # See Also
-    /// * [`Meeting::add_attendee`]
-    /// * [`Meeting::remove_attendee`]
-    pub fn attendees(&self) -> impl Iterator<Item = (&str, u64, &u32)> {
-        self.attendees
-            .iter()
-            .map(|(title, attendee)| (title.as_str(), attendee.salary, &attendee.count))
+    /// * [`Meeting::work_hours_per_year`]
+    pub fn set_work_hours(&mut self, hours: f64) -> Result<(), MeetingError> {
+        if !hours.is_finite() || hours <= 0.0 {
+            return Err(MeetingError::InvalidWorkHours(hours));
+        }
+        self.work_hours_per_year = hours;
+        Ok(())
     }
 
-    /// Returns the attendee count for a given category title, if present.
+    /// Returns the number of working hours per year used for cost computation.
     #[must_use]
-    pub fn attendee_count(&self, title: &str) -> Option<u32> {
-        self.attendees.get(title).map(|a| a.count)
+    pub fn work_hours_per_year(&self) -> f64 {
+        self.work_hours_per_year
     }
 
-    /// Starts the meeting timer.
+    /// Sets the overhead added on top of salaries, as a fraction.
     ///
-    /// Calling this method while the meeting is already running has no effect.
+    /// Benefits, office space and similar costs make an employee more
+    /// expensive than their salary. An overhead of `0.3` bills every attendee
+    /// at 130% of their salary in [`Meeting::total_cost`],
+    /// [`Meeting::breakdown`] and [`Meeting::burn_rate_per_minute`].
     ///
     /// ## Example
     /// ```
-    /// use meeting_cost_tracker::Meeting;
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let cat = EmployeeCategory::new("Engineer", 120_000).unwrap();
     /// let mut meeting = Meeting::new();
-    /// meeting.start();
+    /// meeting.add_attendee(&cat, 1);
+    /// meeting.set_overhead(0.5).unwrap();
+    /// assert!((meeting.burn_rate_per_minute() - 1.5).abs() < 1e-9);
     /// ```
     ///
     /// # Arguments
     ///
-    /// * None
+    /// * `overhead` - Fraction of salary added as overhead.
     ///
     /// # Returns
     ///
-    /// Nothing.
+    /// `Ok(())` if the overhead was updated.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeetingError::InvalidOverhead`] if `overhead` is negative or
+    /// not finite. The previous value is kept in that case.
     ///
     /// # See Also
-    /// * [`Meeting::stop`]
-    pub fn start(&mut self) {
-        if !self.running {
-            self.start_time = Some(Instant::now());
-            self.running = true;
+    /// * [`Meeting::overhead`]
+    pub fn set_overhead(&mut self, overhead: f64) -> Result<(), MeetingError> {
+        if !overhead.is_finite() || overhead < 0.0 {
+            return Err(MeetingError::InvalidOverhead(overhead));
         }
+        self.overhead = overhead;
+        Ok(())
     }
 
-    /// Stops the meeting and accumulates elapsed time.
+    /// Returns the overhead set by [`Meeting::set_overhead`].
+    #[must_use]
+    pub fn overhead(&self) -> f64 {
+        self.overhead
+    }
+
+    /// Chooses whether people named under several groups are billed once.
     ///
-    /// This method is safe to call multiple times.
+    /// By default such duplicates are only reported by
+    /// [`Meeting::duplicate_names`]. In strict mode each duplicate is kept in
+    /// its highest-paid group and left out of the others' headcount, burn rate
+    /// and cost, in proportion to the group's size.
     ///
     /// ## Example
     /// ```
-    /// use meeting_cost_tracker::Meeting;
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let eng = EmployeeCategory::new("Engineer", 120_000).unwrap();
+    /// let lead = EmployeeCategory::new("Tech Lead", 180_000).unwrap();
     /// let mut meeting = Meeting::new();
-    /// meeting.start();
-    /// meeting.stop();
+    /// meeting.add_person("Alice", &eng).unwrap();
+    /// meeting.add_person("alice", &lead).unwrap();
+    /// assert_eq!(meeting.total_attendees(), 2);
+    /// meeting.set_strict_duplicate_names(true);
+    /// assert_eq!(meeting.total_attendees(), 1);
     /// ```
     ///
     /// # Arguments
     ///
-    /// * None
+    /// * `strict` - `true` to exclude duplicates, `false` to only warn.
     ///
     /// # Returns
     ///
     /// Nothing.
     ///
     /// # See Also
-    /// * [`Meeting::start`]
-    pub fn stop(&mut self) {
-        if self.running {
-            if let Some(start_time) = self.start_time.take() {
-                self.elapsed += start_time.elapsed();
-            }
-            self.running = false;
-        }
+    /// * [`Meeting::duplicate_names`]
+    pub fn set_strict_duplicate_names(&mut self, strict: bool) {
+        self.strict_duplicates = strict;
     }
 
-    /// Resets the meeting to its initial state.
+    /// Returns whether duplicates are excluded; see
+    /// [`Meeting::set_strict_duplicate_names`].
+    #[must_use]
+    pub fn strict_duplicate_names(&self) -> bool {
+        self.strict_duplicates
+    }
+
+    /// Multiplier applied to salaries to include overhead.
+    fn overhead_factor(&self) -> f64 {
+        1.0 + self.overhead
+    }
+
+    /// Returns `1 + overhead` as an integer fraction of [`OVERHEAD_SCALE`].
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    fn overhead_numerator(&self) -> u128 {
+        OVERHEAD_SCALE + (self.overhead * OVERHEAD_SCALE as f64).round() as u128
+    }
+
+    /// Returns the integer divisor turning `salary * millis * overhead_numerator`
+    /// into dollars.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::float_cmp
+    )]
+    fn cost_denominator(&self) -> u128 {
+        let millis_per_year = if self.work_hours_per_year == DEFAULT_WORK_HOURS_PER_YEAR {
+            MILLIS_PER_WORK_YEAR
+        } else {
+            (self.work_hours_per_year * MILLIS_PER_HOUR).round() as u128
+        };
+        millis_per_year * OVERHEAD_SCALE * u128::from(ATTENTION_SCALE)
+    }
+
+    /// Selects how attendees who join or leave mid-meeting are billed.
     ///
-    /// This clears all attendees and elapsed time.
+    /// Attendee changes are always recorded, so switching models re-prices
+    /// the whole meeting consistently.
     ///
     /// ## Example
     /// ```
-    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// use meeting_cost_tracker::{CostModel, Meeting};
     /// let mut meeting = Meeting::new();
-    /// let cat = EmployeeCategory::new("Engineer", 120_000).unwrap();
-    /// meeting.add_attendee(&cat, 2);
-    /// meeting.reset();
-    /// assert_eq!(meeting.total_cost(), 0.0);
+    /// assert_eq!(meeting.cost_model(), CostModel::TimeWeighted);
+    /// meeting.set_cost_model(CostModel::FlatFromStart);
+    /// assert_eq!(meeting.cost_model(), CostModel::FlatFromStart);
     /// ```
     ///
     /// # Arguments
     ///
-    /// * None
+    /// * `model` - The [`CostModel`] used by [`Meeting::total_cost`].
     ///
     /// # Returns
     ///
     /// Nothing.
     ///
     /// # See Also
-    /// * [`Meeting::start`]
-    /// * [`Meeting::stop`]
-    pub fn reset(&mut self) {
-        self.attendees.clear();
-        self.start_time = None;
-        self.elapsed = Duration::ZERO;
-        self.running = false;
+    /// * [`Meeting::cost_model`]
+    pub fn set_cost_model(&mut self, model: CostModel) {
+        self.cost_model = model;
     }
 
-    /// Removes all attendees without modifying timing information.
-    pub fn clear_attendees(&mut self) {
-        self.attendees.clear();
+    /// Returns the [`CostModel`] used by [`Meeting::total_cost`].
+    #[must_use]
+    pub fn cost_model(&self) -> CostModel {
+        self.cost_model
     }
 
-    /// Returns the total duration the meeting has been active.
+    /// Adds `count` attendees of a given [`EmployeeCategory`] to the meeting.
+    ///
+    /// A `count` of zero is ignored, and a group that would grow beyond
+    /// `u32::MAX` attendees stops at `u32::MAX`. Use
+    /// [`Meeting::try_add_attendee`] to detect either case.
     ///
     /// ## Example
     /// ```
-    /// use meeting_cost_tracker::Meeting;
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
     /// let mut meeting = Meeting::new();
-    /// meeting.start();
-    /// std::thread::sleep(std::time::Duration::from_millis(10));
-    /// meeting.stop();
-    /// let d = meeting.duration();
-    /// assert!(d.as_millis() > 0);
+    /// let cat = EmployeeCategory::new("Engineer", 100_000).unwrap();
+    /// meeting.add_attendee(&cat, 3);
     /// ```
     ///
     /// # Arguments
     ///
-    /// * None
+    /// * `category` - The [`EmployeeCategory`] to add.
+    /// * `count` - Number of attendees to add.
     ///
     /// # Returns
     ///
-    /// The total duration of the meeting.
+    /// Nothing.
     ///
     /// # See Also
-    /// * [`Meeting::start`]
-    /// * [`Meeting::stop`]
-    #[must_use]
-    pub fn duration(&self) -> Duration {
-        self.elapsed + self.current_duration()
+    /// * [`Meeting::remove_attendee`]
+    /// * [`Meeting::try_add_attendee`]
+    pub fn add_attendee(&mut self, category: &EmployeeCategory, count: u32) {
+        if count == 0 {
+            return;
+        }
+        let present = self.attendee_count(category.title()).unwrap_or(0);
+        self.grow_group(category, present.saturating_add(count), None);
     }
 
-    /// Returns the cost in dollars based on elapsed time and attendee salaries.
+    /// Adds `count` attendees of a given [`EmployeeCategory`] to the meeting,
+    /// rejecting counts that [`Meeting::add_attendee`] would ignore or clamp.
     ///
     /// ## Example
     /// ```
-    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting, MeetingError};
+    /// let cat = EmployeeCategory::new("Dev", 90_000).unwrap();
     /// let mut meeting = Meeting::new();
-    /// let cat = EmployeeCategory::new("Engineer", 100_000).unwrap();
-    /// meeting.add_attendee(&cat, 1);
-    /// meeting.start();
-    /// std::thread::sleep(std::time::Duration::from_millis(10));
-    /// meeting.stop();
-    /// let cost = meeting.total_cost();
-    /// assert!(cost > 0.0);
+    /// assert_eq!(meeting.try_add_attendee(&cat, 2), Ok(2));
+    /// assert_eq!(meeting.try_add_attendee(&cat, 0), Err(MeetingError::InvalidCount));
     /// ```
     ///
     /// # Arguments
     ///
-    /// * None
+    /// * `category` - The [`EmployeeCategory`] to add.
+    /// * `count` - Number of attendees to add.
     ///
     /// # Returns
     ///
-    /// The total cost in dollars.
+    /// The size of the group after adding.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeetingError::InvalidCount`] if `count` is zero and
+    /// [`MeetingError::TooManyAttendees`] if the group would exceed `u32::MAX`
+    /// attendees. The meeting is unchanged on error.
     ///
     /// # See Also
-    /// * [`Meeting::duration`]
-    /// * [`EmployeeCategory::cost_per_millisecond`]
-    #[must_use]
-    #[allow(clippy::cast_precision_loss)]
-    pub fn total_cost(&self) -> f64 {
-        let millis = self.duration().as_millis() as f64;
-        self.attendees
-            .values()
-            .map(|a| {
-                let cost_per_ms = a.salary as f64 / crate::model::MILLIS_PER_WORK_YEAR;
-                cost_per_ms * f64::from(a.count) * millis
-            })
-            .sum()
+    /// * [`Meeting::add_attendee`]
+    pub fn try_add_attendee(
+        &mut self,
+        category: &EmployeeCategory,
+        count: u32,
+    ) -> Result<u32, MeetingError> {
+        if count == 0 {
+            return Err(MeetingError::InvalidCount);
+        }
+        let present = self.attendee_count(category.title()).unwrap_or(0);
+        let total = present
+            .checked_add(count)
+            .ok_or_else(|| MeetingError::TooManyAttendees(category.title().to_string()))?;
+        self.grow_group(category, total, None);
+        Ok(total)
     }
 
-    /// Checks whether the meeting is currently running.
+    /// Adds `count` attendees of `category` who only pay part attention,
+    /// billing the group at `attention` of its salary cost.
+    ///
+    /// Attention is stored per group, so it applies to every attendee of
+    /// `category` from now on, including those already present. Use a
+    /// separate category to bill people of the same role differently.
     ///
     /// ## Example
     /// ```
-    /// use meeting_cost_tracker::Meeting;
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let cat = EmployeeCategory::new("Engineer", 120_000).unwrap();
     /// let mut meeting = Meeting::new();
-    /// meeting.start();
-    /// assert!(meeting.is_running());
-    /// meeting.stop();
-    /// assert!(!meeting.is_running());
+    /// meeting.add_attendee_attention(&cat, 2, 0.5).unwrap();
+    /// assert!((meeting.burn_rate_per_minute() - 1.0).abs() < 1e-9);
+    /// assert!(meeting.add_attendee_attention(&cat, 1, 1.5).is_err());
     /// ```
     ///
     /// # Arguments
     ///
-    /// * None
+    /// * `category` - The [`EmployeeCategory`] to add.
+    /// * `count` - Number of attendees to add.
+    /// * `attention` - Share of their time billed, within `(0.0, 1.0]`.
     ///
     /// # Returns
     ///
-    /// `true` if the meeting is running.
+    /// The size of the group after adding.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeetingError::InvalidFraction`] if `attention` is not within
+    /// `(0.0, 1.0]`, and the errors of [`Meeting::try_add_attendee`]. The
+    /// meeting is unchanged on error.
     ///
     /// # See Also
-    /// * [`Meeting::start`]
-    /// * [`Meeting::stop`]
+    /// * [`Meeting::attention`]
+    pub fn add_attendee_attention(
+        &mut self,
+        category: &EmployeeCategory,
+        count: u32,
+        attention: f64,
+    ) -> Result<u32, MeetingError> {
+        let parts = attention_parts(attention)?;
+        if count == 0 {
+            return Err(MeetingError::InvalidCount);
+        }
+        let present = self.attendee_count(category.title()).unwrap_or(0);
+        let total = present
+            .checked_add(count)
+            .ok_or_else(|| MeetingError::TooManyAttendees(category.title().to_string()))?;
+        self.grow_group(category, total, Some(parts));
+        Ok(total)
+    }
+
+    /// Returns the attention the group titled `title` is billed at, or `None`
+    /// if no such group is in the meeting.
     #[must_use]
-    pub fn is_running(&self) -> bool {
-        self.running
+    pub fn attention(&self, title: &str) -> Option<f64> {
+        self.attendees
+            .get(title)
+            .map(|a| attention_fraction(a.attention))
+    }
+
+    /// Sets the `category` group to `total` attendees, and to `attention`
+    /// parts if given, recording the change.
+    ///
+    /// A group already in the meeting keeps its salary, and with it its
+    /// estimate markers.
+    fn grow_group(&mut self, category: &EmployeeCategory, total: u32, attention: Option<u32>) {
+        self.record_change(category.title());
+        if !self.attendees.contains_key(category.title()) {
+            self.note_estimates(category);
+        }
+        let group = self
+            .attendees
+            .entry(category.title().to_string())
+            .or_insert_with(|| Attendee::new(category.salary(), 0));
+        group.count = total;
+        if let Some(attention) = attention {
+            group.attention = attention;
+        }
+        self.log_roster_change(category.title());
+    }
+
+    /// Records whether `category` has an approximate salary or a salary range.
+    fn note_estimates(&mut self, category: &EmployeeCategory) {
+        let title = category.title();
+        if category.is_approximate() {
+            self.approximate_groups.insert(title.to_string());
+        }
+        match category.salary_range() {
+            Some(range) => {
+                self.salary_ranges.insert(title.to_string(), range);
+            }
+            None => {
+                self.salary_ranges.remove(title);
+            }
+        }
+    }
+
+    /// Removes up to `count` attendees of the given title from the meeting.
+    /// If the resulting count is zero, the attendee entry is removed entirely.
+    ///
+    /// Unknown titles are ignored; use [`Meeting::try_remove_attendee`] to
+    /// detect them.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let cat = EmployeeCategory::new("Dev", 90_000).unwrap();
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&cat, 2);
+    /// meeting.remove_attendee("Dev", 1);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - Title of the attendees to remove.
+    /// * `count` - Number of attendees to remove.
+    ///
+    /// # Returns
+    ///
+    /// Nothing.
+    ///
+    /// # See Also
+    /// * [`Meeting::add_attendee`]
+    /// * [`Meeting::try_remove_attendee`]
+    pub fn remove_attendee(&mut self, title: &str, count: u32) {
+        let _ = self.try_remove_attendee(title, count);
+    }
+
+    /// Removes up to `count` attendees of the given title from the meeting,
+    /// reporting how many were actually removed.
+    ///
+    /// Removing more attendees than are present removes the whole group.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting, MeetingError};
+    /// let cat = EmployeeCategory::new("Dev", 90_000).unwrap();
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&cat, 2);
+    /// assert_eq!(meeting.try_remove_attendee("Dev", 5), Ok(2));
+    /// assert_eq!(
+    ///     meeting.try_remove_attendee("Dev", 1),
+    ///     Err(MeetingError::AttendeeNotFound("Dev".into()))
+    /// );
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - Title of the attendees to remove.
+    /// * `count` - Maximum number of attendees to remove.
+    ///
+    /// # Returns
+    ///
+    /// The number of attendees removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeetingError::AttendeeNotFound`] if no group with `title` is
+    /// part of the meeting.
+    ///
+    /// # See Also
+    /// * [`Meeting::remove_attendee`]
+    pub fn try_remove_attendee(&mut self, title: &str, count: u32) -> Result<u32, MeetingError> {
+        let present = self
+            .attendee_count(title)
+            .ok_or_else(|| MeetingError::AttendeeNotFound(title.to_string()))?;
+        self.record_change(title);
+        let removed = present.min(count);
+        self.shrink_group(title, removed);
+        self.prune_people(title);
+        self.log_roster_change(title);
+        Ok(removed)
+    }
+
+    /// Sets the number of attendees of `category` in one call, creating,
+    /// resizing or removing its group.
+    ///
+    /// A group already in the meeting keeps its stored salary; `category`'s
+    /// salary is only used for a new group. Named people beyond the new count
+    /// are dropped as with [`Meeting::try_remove_attendee`]. The change can be
+    /// reverted with [`Meeting::undo_last_attendee_change`].
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let cat = EmployeeCategory::new("Dev", 90_000).unwrap();
+    /// let mut meeting = Meeting::new();
+    /// assert_eq!(meeting.set_attendee_count(&cat, 5), 0);
+    /// assert_eq!(meeting.set_attendee_count(&cat, 2), 5);
+    /// assert_eq!(meeting.set_attendee_count(&cat, 0), 2);
+    /// assert_eq!(meeting.attendee_count("Dev"), None);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - The [`EmployeeCategory`] of the group.
+    /// * `count` - New number of attendees; `0` removes the group.
+    ///
+    /// # Returns
+    ///
+    /// The number of attendees before the change, `0` if the group was not
+    /// part of the meeting.
+    ///
+    /// # See Also
+    /// * [`Meeting::try_add_attendee`]
+    /// * [`Meeting::try_remove_attendee`]
+    pub fn set_attendee_count(&mut self, category: &EmployeeCategory, count: u32) -> u32 {
+        let title = category.title();
+        let previous = self.attendee_count(title).unwrap_or(0);
+        if count == previous {
+            return previous;
+        }
+        if count == 0 {
+            self.remove_attendee(title, previous);
+        } else {
+            self.grow_group(category, count, None);
+            self.prune_people(title);
+        }
+        previous
+    }
+
+    /// Describes what [`Meeting::remove_groups`] would do, without removing
+    /// anything.
+    ///
+    /// # Arguments
+    ///
+    /// * `titles` - Titles of the groups to remove.
+    ///
+    /// # Returns
+    ///
+    /// The [`RemovalReport`] that removing `titles` would produce.
+    #[must_use]
+    pub fn preview_remove_groups(&self, titles: &[String]) -> RemovalReport {
+        let mut report = RemovalReport::default();
+        for title in titles {
+            if report.removed.iter().any(|(t, _)| t == title) || report.unknown.contains(title) {
+                continue;
+            }
+            match self.attendees.get(title) {
+                Some(group) => {
+                    report.removed.push((title.clone(), group.count));
+                    report.headcount = report.headcount.saturating_add(group.count);
+                }
+                None => report.unknown.push(title.clone()),
+            }
+        }
+        // In strict duplicate mode, removing a group can bill a person listed
+        // under another group again, so compare the whole rate.
+        let mut remaining = Self {
+            attendees: self.attendees.clone(),
+            people: self.people.clone(),
+            strict_duplicates: self.strict_duplicates,
+            overhead: self.overhead,
+            work_hours_per_year: self.work_hours_per_year,
+            ..Self::new()
+        };
+        for (title, _) in &report.removed {
+            remaining.attendees.remove(title);
+            remaining.people.retain(|_, t| t != title);
+        }
+        report.burn_rate_reduction = self.burn_rate_per_minute() - remaining.burn_rate_per_minute();
+        report
+    }
+
+    /// Removes every attendee of each group in `titles`.
+    ///
+    /// Known groups are removed even if some titles are unknown; the report
+    /// lists both. Duplicate titles are only counted once. Each removed group
+    /// can be restored with [`Meeting::undo_last_attendee_change`].
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&EmployeeCategory::new("Dev", 120_000).unwrap(), 2);
+    /// let report = meeting.remove_groups(&["Dev".into(), "PM".into()]);
+    /// assert_eq!(report.removed, vec![("Dev".to_string(), 2)]);
+    /// assert_eq!(report.unknown, vec!["PM".to_string()]);
+    /// assert!(meeting.is_empty());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `titles` - Titles of the groups to remove.
+    ///
+    /// # Returns
+    ///
+    /// A [`RemovalReport`] of what was removed and which titles were unknown.
+    ///
+    /// # See Also
+    /// * [`Meeting::preview_remove_groups`]
+    /// * [`Meeting::try_remove_attendee`]
+    pub fn remove_groups(&mut self, titles: &[String]) -> RemovalReport {
+        let report = self.preview_remove_groups(titles);
+        for (title, count) in &report.removed {
+            let _ = self.try_remove_attendee(title, *count);
+        }
+        report
+    }
+
+    /// Changes the salary of the attendee group titled `title`.
+    ///
+    /// With [`CostModel::TimeWeighted`] the new salary applies from this point
+    /// of the meeting on; time already spent keeps the salary it was billed
+    /// at. With [`CostModel::FlatFromStart`] the whole meeting is re-priced at
+    /// the new salary, like every other roster change under that model. The
+    /// change can be reverted with [`Meeting::undo_last_attendee_change`].
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting, MeetingError};
+    /// let cat = EmployeeCategory::new("Dev", 90_000).unwrap();
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&cat, 2);
+    /// meeting.update_salary("Dev", 120_000).unwrap();
+    /// assert!((meeting.burn_rate_per_minute() - 2.0).abs() < 1e-9);
+    /// assert_eq!(
+    ///     meeting.update_salary("PM", 1),
+    ///     Err(MeetingError::AttendeeNotFound("PM".into()))
+    /// );
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - Title of the attendee group.
+    /// * `salary` - New annual salary in dollars.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` once the salary has been updated.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeetingError::AttendeeNotFound`] if no group with `title` is
+    /// part of the meeting.
+    ///
+    /// # See Also
+    /// * [`Meeting::add_attendee`]
+    pub fn update_salary(&mut self, title: &str, salary: u64) -> Result<(), MeetingError> {
+        if !self.attendees.contains_key(title) {
+            return Err(MeetingError::AttendeeNotFound(title.to_string()));
+        }
+        self.record_change(title);
+        if let Some(entry) = self.attendees.get_mut(title) {
+            entry.salary = salary;
+        }
+        self.log_roster_change(title);
+        Ok(())
+    }
+
+    /// Adds a named person to the meeting as one attendee of `category`.
+    ///
+    /// The person counts towards the category's attendee count just like an
+    /// anonymous attendee added with [`Meeting::add_attendee`].
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let staff = EmployeeCategory::new("Staff Engineer", 200_000).unwrap();
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&staff, 2);
+    /// meeting.add_person("Alice", &staff).unwrap();
+    /// assert_eq!(meeting.attendee_count("Staff Engineer"), Some(3));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the person, unique within the meeting.
+    /// * `category` - The [`EmployeeCategory`] the person is billed as.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` once the person has been added.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeetingError::PersonExists`] if `name` is already in the
+    /// meeting and [`MeetingError::TooManyAttendees`] if the category is full.
+    ///
+    /// # See Also
+    /// * [`Meeting::remove_person`]
+    /// * [`Meeting::people`]
+    pub fn add_person(
+        &mut self,
+        name: &str,
+        category: &EmployeeCategory,
+    ) -> Result<(), MeetingError> {
+        if self.people.contains_key(name) {
+            return Err(MeetingError::PersonExists(name.to_string()));
+        }
+        self.try_add_attendee(category, 1)?;
+        self.people
+            .insert(name.to_string(), category.title().to_string());
+        Ok(())
+    }
+
+    /// Removes a named person from the meeting.
+    ///
+    /// The person's category loses one attendee.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let staff = EmployeeCategory::new("Staff Engineer", 200_000).unwrap();
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_person("Alice", &staff).unwrap();
+    /// assert_eq!(meeting.remove_person("Alice").unwrap(), "Staff Engineer");
+    /// assert!(meeting.is_empty());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the person to remove.
+    ///
+    /// # Returns
+    ///
+    /// The category title the person was billed as.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeetingError::PersonNotFound`] if `name` is not in the meeting.
+    ///
+    /// # See Also
+    /// * [`Meeting::add_person`]
+    pub fn remove_person(&mut self, name: &str) -> Result<String, MeetingError> {
+        let title = self
+            .people
+            .get(name)
+            .cloned()
+            .ok_or_else(|| MeetingError::PersonNotFound(name.to_string()))?;
+        self.record_change(&title);
+        self.people.remove(name);
+        self.shrink_group(&title, 1);
+        self.log_roster_change(&title);
+        Ok(title)
+    }
+
+    /// Returns the named people in the meeting as `(name, category_title)`
+    /// pairs, sorted by name.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let staff = EmployeeCategory::new("Staff Engineer", 200_000).unwrap();
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_person("Bob", &staff).unwrap();
+    /// meeting.add_person("Alice", &staff).unwrap();
+    /// let people: Vec<_> = meeting.people().collect();
+    /// assert_eq!(people[0], ("Alice", "Staff Engineer"));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// An iterator over named attendees.
+    ///
+    /// # See Also
+    /// * [`Meeting::attendees`]
+    pub fn people(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.people
+            .iter()
+            .map(|(name, title)| (name.as_str(), title.as_str()))
+    }
+
+    /// Describes the roster as [`AttendeeInfo`] entries suitable for saving.
+    ///
+    /// Each group yields one entry with its anonymous attendees, if any, and
+    /// one entry with a count of 1 per named person. Entries are sorted by
+    /// title, with the anonymous entry before the named ones.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let dev = EmployeeCategory::new("Dev", 100_000).unwrap();
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&dev, 2);
+    /// meeting.add_person("Alice", &dev).unwrap();
+    /// let entries = meeting.roster_entries();
+    /// assert_eq!(entries[0].count, 2);
+    /// assert_eq!(entries[1].name.as_deref(), Some("Alice"));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// The roster with named people listed individually.
+    ///
+    /// # See Also
+    /// * [`Meeting::people`]
+    /// * [`save_attendees`](crate::save_attendees)
+    #[must_use]
+    pub fn roster_entries(&self) -> Vec<AttendeeInfo> {
+        let mut titles: Vec<&String> = self.attendees.keys().collect();
+        titles.sort();
+        let mut entries = Vec::new();
+        for title in titles {
+            let names: Vec<&String> = self
+                .people
+                .iter()
+                .filter(|(_, t)| *t == title)
+                .map(|(name, _)| name)
+                .collect();
+            let named_count = u32::try_from(names.len()).unwrap_or(u32::MAX);
+            let anonymous = self.attendees[title].count.saturating_sub(named_count);
+            let attention = attention_fraction(self.attendees[title].attention);
+            if anonymous > 0 {
+                entries.push(AttendeeInfo {
+                    title: title.clone(),
+                    count: anonymous,
+                    name: None,
+                    salary: None,
+                    attention,
+                });
+            }
+            entries.extend(names.into_iter().map(|name| AttendeeInfo {
+                title: title.clone(),
+                count: 1,
+                name: Some(name.clone()),
+                salary: None,
+                attention,
+            }));
+        }
+        entries
+    }
+
+    /// Adds one saved roster entry, such as those from
+    /// [`Meeting::roster_entries`], billed as `category`.
+    ///
+    /// Anonymous entries are added with [`Meeting::add_attendee_attention`]
+    /// and named ones with [`Meeting::add_person`]; either way the group is
+    /// billed at the entry's attention afterwards.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let dev = EmployeeCategory::new("Dev", 120_000).unwrap();
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee_attention(&dev, 2, 0.5).unwrap();
+    /// meeting.add_person("Alice", &dev).unwrap();
+    ///
+    /// let mut copy = Meeting::new();
+    /// for entry in meeting.roster_entries() {
+    ///     copy.add_roster_entry(&dev, &entry).unwrap();
+    /// }
+    /// assert_eq!(copy.attendee_count("Dev"), Some(3));
+    /// assert_eq!(copy.attention("Dev"), Some(0.5));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - Category the entry is billed as.
+    /// * `entry` - Saved entry with the count, name and attention to add.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` once the entry is added.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeetingError::InvalidFraction`] for an attention outside
+    /// `(0.0, 1.0]`, and the errors of [`Meeting::add_attendee_attention`] and
+    /// [`Meeting::add_person`]. The meeting is unchanged on error.
+    ///
+    /// # See Also
+    /// * [`Meeting::roster_entries`]
+    pub fn add_roster_entry(
+        &mut self,
+        category: &EmployeeCategory,
+        entry: &AttendeeInfo,
+    ) -> Result<(), MeetingError> {
+        match &entry.name {
+            None => {
+                self.add_attendee_attention(category, entry.count, entry.attention)?;
+            }
+            Some(name) => {
+                let parts = attention_parts(entry.attention)?;
+                self.add_person(name, category)?;
+                self.set_group_attention(category.title(), parts);
+            }
+        }
+        Ok(())
+    }
+
+    /// Bills the `title` group at `attention` parts, recording the change.
+    fn set_group_attention(&mut self, title: &str, attention: u32) {
+        if let Some(group) = self.attendees.get_mut(title) {
+            if group.attention != attention {
+                group.attention = attention;
+                self.log_roster_change(title);
+            }
+        }
+    }
+
+    /// Lowers the `title` group by `count`, dropping the group when it empties.
+    fn shrink_group(&mut self, title: &str, count: u32) {
+        if let Some(entry) = self.attendees.get_mut(title) {
+            entry.count = entry.count.saturating_sub(count);
+            if entry.count == 0 {
+                self.attendees.remove(title);
+            }
+        }
+    }
+
+    /// Drops named people from `title` until they fit within the group's count.
+    ///
+    /// Anonymous removals take named people last, starting from the end of
+    /// the alphabet.
+    fn prune_people(&mut self, title: &str) {
+        let count = self.attendee_count(title).unwrap_or(0) as usize;
+        let names: Vec<String> = self
+            .people
+            .iter()
+            .filter(|(_, t)| t.as_str() == title)
+            .map(|(name, _)| name.clone())
+            .collect();
+        for name in names.iter().skip(count) {
+            self.people.remove(name);
+        }
+    }
+
+    /// Reverts the most recent [`Meeting::add_attendee`],
+    /// [`Meeting::try_remove_attendee`], [`Meeting::set_attendee_count`],
+    /// [`Meeting::add_person`] or [`Meeting::remove_person`] call.
+    ///
+    /// The affected group is restored to its exact previous count, including
+    /// groups that were removed entirely. Up to 32 changes are remembered.
+    /// Bulk roster changes such as [`Meeting::clear_attendees`],
+    /// [`Meeting::swap_attendees`], [`Meeting::restore_roster`] and
+    /// [`Meeting::reset`] discard the undo history.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let cat = EmployeeCategory::new("Dev", 90_000).unwrap();
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&cat, 2);
+    /// meeting.remove_attendee("Dev", 2);
+    /// assert!(meeting.undo_last_attendee_change());
+    /// assert_eq!(meeting.attendee_count("Dev"), Some(2));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// `true` if a change was undone, `false` if there was nothing to undo.
+    ///
+    /// # See Also
+    /// * [`Meeting::add_attendee`]
+    /// * [`Meeting::try_remove_attendee`]
+    pub fn undo_last_attendee_change(&mut self) -> bool {
+        let Some(change) = self.undo.pop_back() else {
+            return false;
+        };
+        match change.previous {
+            Some(attendee) => {
+                self.attendees.insert(change.title.clone(), attendee);
+            }
+            None => {
+                self.attendees.remove(&change.title);
+            }
+        }
+        self.people.retain(|_, title| *title != change.title);
+        for name in change.people {
+            self.people.insert(name, change.title.clone());
+        }
+        self.log_roster_change(&change.title);
+        true
+    }
+
+    /// Remembers the current state of `title` so the next change can be undone.
+    fn record_change(&mut self, title: &str) {
+        if self.undo.len() == UNDO_LIMIT {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(AttendeeChange {
+            title: title.to_string(),
+            previous: self.attendees.get(title).cloned(),
+            people: self
+                .people
+                .iter()
+                .filter(|(_, t)| t.as_str() == title)
+                .map(|(name, _)| name.clone())
+                .collect(),
+        });
+    }
+
+    /// Records the current size of the `title` group in the roster log.
+    ///
+    /// Groups that left keep the salary they were last billed at.
+    fn log_roster_change(&mut self, title: &str) {
+        let (salary, count, attention) = if let Some(a) = self.attendees.get(title) {
+            (a.salary, a.count, a.attention)
+        } else {
+            let (salary, attention) = self
+                .roster_log
+                .iter()
+                .rev()
+                .find(|e| e.title == title)
+                .map_or((0, ATTENTION_SCALE), |e| (e.salary, e.attention));
+            (salary, 0, attention)
+        };
+        self.roster_log.push(RosterEvent {
+            at: self.duration(),
+            title: title.to_string(),
+            salary,
+            count,
+            attention,
+        });
+    }
+
+    /// Replaces the roster with `attendees`, logging every group that changed.
+    fn replace_roster(
+        &mut self,
+        attendees: HashMap<String, Attendee>,
+    ) -> HashMap<String, Attendee> {
+        let previous = std::mem::replace(&mut self.attendees, attendees);
+        let mut titles: Vec<String> = previous
+            .keys()
+            .chain(self.attendees.keys())
+            .cloned()
+            .collect();
+        titles.sort();
+        titles.dedup();
+        for title in titles {
+            self.prune_people(&title);
+            self.log_roster_change(&title);
+        }
+        self.undo.clear();
+        previous
+    }
+
+    /// Sums `salary * count * attention * milliseconds` per attendee group up
+    /// to `until`, with attention in [`ATTENTION_SCALE`] parts.
+    ///
+    /// Groups that left before `until` keep the usage they accrued while present.
+    /// Each entry maps a title to its last salary and its usage.
+    fn salary_millis_by_title(&self, until: Duration) -> HashMap<&str, (u64, u128)> {
+        let mut open: HashMap<&str, (Duration, u128)> = HashMap::new();
+        let mut totals: HashMap<&str, (u64, u128)> = HashMap::new();
+        for event in self.roster_log.iter().take_while(|e| e.at <= until) {
+            let title = event.title.as_str();
+            let total = totals.entry(title).or_default();
+            total.0 = event.salary;
+            let weight =
+                u128::from(event.salary) * u128::from(event.count) * u128::from(event.attention);
+            if let Some((since, weight)) = open.insert(title, (event.at, weight)) {
+                total.1 += weight * event.at.saturating_sub(since).as_millis();
+            }
+        }
+        for (title, (since, weight)) in open {
+            totals.entry(title).or_default().1 += weight * until.saturating_sub(since).as_millis();
+        }
+        totals
+    }
+
+    /// Reports named people that appear under more than one attendee group.
+    ///
+    /// Names are compared ignoring case and surrounding whitespace, so
+    /// `"Alice"` under one group and `" alice"` under another are reported as
+    /// the same person, e.g. after a reorg renamed her role.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let eng = EmployeeCategory::new("Engineer", 120_000).unwrap();
+    /// let lead = EmployeeCategory::new("Tech Lead", 180_000).unwrap();
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_person("Alice", &eng).unwrap();
+    /// meeting.add_person("alice ", &lead).unwrap();
+    /// assert_eq!(
+    ///     meeting.duplicate_names(),
+    ///     vec![("Alice".to_string(), vec!["Engineer".to_string(), "Tech Lead".to_string()])]
+    /// );
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// Each duplicated name, trimmed and spelled as its first spelling in sort
+    /// order, with the sorted titles of the groups it appears under.
+    ///
+    /// # See Also
+    /// * [`Meeting::set_strict_duplicate_names`]
+    /// * [`Meeting::people`]
+    #[must_use]
+    pub fn duplicate_names(&self) -> Vec<(String, Vec<String>)> {
+        self.people_by_normalized_name()
+            .into_values()
+            .filter_map(|(name, mut titles)| {
+                titles.sort_unstable();
+                titles.dedup();
+                (titles.len() > 1).then(|| {
+                    let titles = titles.into_iter().map(str::to_string).collect();
+                    (name.trim().to_string(), titles)
+                })
+            })
+            .collect()
+    }
+
+    /// Groups named people by trimmed, lowercased name, keeping the first
+    /// spelling in sort order and every group title the name appears under.
+    fn people_by_normalized_name(&self) -> BTreeMap<String, (&str, Vec<&str>)> {
+        let mut names: BTreeMap<String, (&str, Vec<&str>)> = BTreeMap::new();
+        for (name, title) in &self.people {
+            names
+                .entry(name.trim().to_lowercase())
+                .or_insert_with(|| (name.as_str(), Vec::new()))
+                .1
+                .push(title.as_str());
+        }
+        names
+    }
+
+    /// Counts attendees to leave out of each group in strict duplicate mode.
+    ///
+    /// Each duplicated person stays in the highest-paid group they appear
+    /// under and is excluded everywhere else.
+    fn duplicate_exclusions(&self) -> HashMap<&str, u32> {
+        let mut excluded: HashMap<&str, u32> = HashMap::new();
+        if !self.strict_duplicates {
+            return excluded;
+        }
+        for (_, mut titles) in self.people_by_normalized_name().into_values() {
+            let first = titles[0];
+            if titles.iter().all(|t| *t == first) {
+                continue;
+            }
+            titles.sort_by_key(|t| {
+                let salary = self.attendees.get(*t).map_or(0, |a| a.salary);
+                (std::cmp::Reverse(salary), *t)
+            });
+            for title in &titles[1..] {
+                *excluded.entry(title).or_default() += 1;
+            }
+        }
+        excluded
+    }
+
+    /// Returns the number of attendees billed in each group, after strict
+    /// duplicate exclusions.
+    fn billed_counts(&self) -> HashMap<&str, u32> {
+        let excluded = self.duplicate_exclusions();
+        self.attendees
+            .iter()
+            .map(|(title, a)| {
+                let skip = excluded.get(title.as_str()).copied().unwrap_or(0);
+                (title.as_str(), a.count.saturating_sub(skip))
+            })
+            .collect()
+    }
+
+    /// Returns an iterator over the attendee list.
+    ///
+    /// The iterator yields a tuple of `(title, salary, count)` for each attendee group.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let cat = EmployeeCategory::new("QA", 80_000).unwrap();
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&cat, 1);
+    /// for (title, salary, count) in meeting.attendees() {
+    ///     println!("{} - {} x ${}", title, count, salary);
+    /// }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// An iterator over `(title, salary, count)` entries.
+    ///
+    /// # See Also
+    /// * [`Meeting::add_attendee`]
+    /// * [`Meeting::remove_attendee`]
+    pub fn attendees(&self) -> impl Iterator<Item = (&str, u64, &u32)> {
+        self.attendees
+            .iter()
+            .map(|(title, attendee)| (title.as_str(), attendee.salary, &attendee.count))
+    }
+
+    /// Returns the attendee groups, most expensive first.
+    ///
+    /// Groups are ordered by `salary * count` in descending order, with ties
+    /// broken alphabetically by title so the order is deterministic. Each
+    /// group's share of the [`Meeting::burn_rate_per_minute`] takes attention
+    /// and duplicate people into account, like the burn rate itself.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&EmployeeCategory::new("Dev", 100_000).unwrap(), 3);
+    /// meeting.add_attendee(&EmployeeCategory::new("VP", 200_000).unwrap(), 1);
+    /// assert_eq!(meeting.attendees_by_cost()[0], ("Dev", 100_000, 3, 0.6));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// `(title, salary, count, cost_share)` entries sorted by cost
+    /// contribution, where `cost_share` is the group's fraction of the burn
+    /// rate, or `0.0` while nothing is being spent.
+    ///
+    /// # See Also
+    /// * [`Meeting::attendees`]
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn attendees_by_cost(&self) -> Vec<(&str, u64, u32, f64)> {
+        let counts = self.billed_counts();
+        let weight = |title: &str, a: &Attendee| {
+            let count = counts.get(title).copied().unwrap_or(a.count);
+            u128::from(a.salary) * u128::from(count) * u128::from(a.attention)
+        };
+        let total: u128 = self.attendees.iter().map(|(t, a)| weight(t, a)).sum();
+        let mut groups: Vec<(&str, u64, u32, f64)> = self
+            .attendees
+            .iter()
+            .map(|(title, a)| {
+                let share = if total == 0 {
+                    0.0
+                } else {
+                    weight(title, a) as f64 / total as f64
+                };
+                (title.as_str(), a.salary, a.count, share)
+            })
+            .collect();
+        groups.sort_by(|a, b| {
+            let cost = |g: &(&str, u64, u32, f64)| u128::from(g.1) * u128::from(g.2);
+            cost(b).cmp(&cost(a)).then_with(|| a.0.cmp(b.0))
+        });
+        groups
+    }
+
+    /// Picks the cheapest attendees that still make up a quorum.
+    ///
+    /// Attendees are chosen greedily, lowest salary first, until `quorum`
+    /// people are selected. Groups with equal salaries are taken in title
+    /// order. If the meeting has fewer than `quorum` attendees, all of them
+    /// are returned.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&EmployeeCategory::new("Director", 250_000).unwrap(), 1);
+    /// meeting.add_attendee(&EmployeeCategory::new("Dev", 120_000).unwrap(), 2);
+    /// assert_eq!(meeting.cheapest_quorum(3), vec![
+    ///     ("Dev".to_string(), 2),
+    ///     ("Director".to_string(), 1),
+    /// ]);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `quorum` - Number of attendees needed.
+    ///
+    /// # Returns
+    ///
+    /// The selected `(title, count)` pairs, cheapest group first.
+    ///
+    /// # See Also
+    /// * [`Meeting::attendees`]
+    #[must_use]
+    pub fn cheapest_quorum(&self, quorum: u32) -> Vec<(String, u32)> {
+        let mut groups: Vec<(&str, u64, u32)> = self
+            .attendees()
+            .map(|(title, salary, count)| (title, salary, *count))
+            .collect();
+        groups.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(b.0)));
+        let mut remaining = quorum;
+        let mut selected = Vec::new();
+        for (title, _, count) in groups {
+            if remaining == 0 {
+                break;
+            }
+            let take = count.min(remaining);
+            remaining -= take;
+            selected.push((title.to_string(), take));
+        }
+        selected
+    }
+
+    /// Registers a new receiver of cost updates.
+    ///
+    /// Every call returns an independent receiver, so several frontends can
+    /// listen at once. Updates are only produced by [`Meeting::tick`]; the
+    /// meeting never spawns threads of its own. Dropping a receiver simply
+    /// unsubscribes it.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::Meeting;
+    /// let mut meeting = Meeting::new();
+    /// let updates = meeting.subscribe();
+    /// meeting.start();
+    /// meeting.tick();
+    /// assert!(updates.try_recv().is_ok());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// A [`Receiver`] of [`CostUpdate`] snapshots.
+    ///
+    /// # See Also
+    /// * [`Meeting::tick`]
+    pub fn subscribe(&mut self) -> Receiver<CostUpdate> {
+        let (sender, receiver) = mpsc::channel();
+        self.subscribers.push(sender);
+        receiver
+    }
+
+    /// Sends a [`CostUpdate`] to every subscriber if the meeting is running.
+    ///
+    /// The owner of the meeting calls this periodically, e.g. from its UI
+    /// timer. Nothing is sent while the meeting is stopped or armed.
+    /// Subscribers whose receiver was dropped are removed; sending never
+    /// blocks.
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// Nothing.
+    ///
+    /// # See Also
+    /// * [`Meeting::subscribe`]
+    pub fn tick(&mut self) {
+        if self.subscribers.is_empty() || !self.is_running() {
+            return;
+        }
+        let update = CostUpdate {
+            duration: self.duration(),
+            total_cost: self.total_cost(),
+        };
+        self.subscribers.retain(|s| s.send(update).is_ok());
+    }
+
+    /// Returns the attendee count for a given category title, if present.
+    #[must_use]
+    pub fn attendee_count(&self, title: &str) -> Option<u32> {
+        self.attendees.get(title).map(|a| a.count)
+    }
+
+    /// Returns the categories in `all` that have no attendees in the meeting.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let dev = EmployeeCategory::new("Dev", 100_000).unwrap();
+    /// let pm = EmployeeCategory::new("PM", 110_000).unwrap();
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&dev, 2);
+    /// let all = [dev, pm];
+    /// assert_eq!(meeting.unused_categories(&all), vec![&all[1]]);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `all` - Known categories.
+    ///
+    /// # Returns
+    ///
+    /// The categories whose title is not an attendee group, in their order in
+    /// `all`.
+    ///
+    /// # See Also
+    /// * [`Meeting::attendee_count`]
+    #[must_use]
+    pub fn unused_categories<'a>(&self, all: &'a [EmployeeCategory]) -> Vec<&'a EmployeeCategory> {
+        all.iter()
+            .filter(|c| self.attendee_count(c.title()).is_none_or(|n| n == 0))
+            .collect()
+    }
+
+    /// Returns the number of paid attendees across all categories.
+    ///
+    /// Observers are left out; see [`Meeting::observer_count`]. The sum
+    /// saturates at [`u32::MAX`] instead of overflowing.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&EmployeeCategory::new("Dev", 100_000).unwrap(), 3);
+    /// meeting.add_attendee(&EmployeeCategory::new("PM", 110_000).unwrap(), 1);
+    /// assert_eq!(meeting.total_attendees(), 4);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// The total headcount, or zero for an empty meeting.
+    ///
+    /// # See Also
+    /// * [`Meeting::total_annual_salary`]
+    /// * [`Meeting::observer_count`]
+    #[must_use]
+    pub fn total_attendees(&self) -> u32 {
+        self.headcount_where(false)
+    }
+
+    /// Returns the number of attendees from observer categories, such as
+    /// customers, who are listed but add nothing to the cost.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&EmployeeCategory::new("Dev", 100_000).unwrap(), 8);
+    /// meeting.add_attendee(&EmployeeCategory::observer("Customer").unwrap(), 2);
+    /// assert_eq!(meeting.total_attendees(), 8);
+    /// assert_eq!(meeting.observer_count(), 2);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// The number of observers, or zero if there are none.
+    ///
+    /// # See Also
+    /// * [`EmployeeCategory::observer`]
+    /// * [`Meeting::total_attendees`]
+    #[must_use]
+    pub fn observer_count(&self) -> u32 {
+        self.headcount_where(true)
+    }
+
+    /// Returns the number of people in the meeting, observers included.
+    ///
+    /// The sum saturates at [`u32::MAX`] instead of overflowing.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&EmployeeCategory::new("Dev", 100_000).unwrap(), 8);
+    /// meeting.add_attendee(&EmployeeCategory::observer("Customer").unwrap(), 2);
+    /// assert_eq!(meeting.total_headcount(), 10);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// The number of paid attendees plus observers, or zero for an empty
+    /// meeting.
+    ///
+    /// # See Also
+    /// * [`Meeting::total_attendees`]
+    /// * [`Meeting::observer_count`]
+    #[must_use]
+    pub fn total_headcount(&self) -> u32 {
+        self.total_attendees().saturating_add(self.observer_count())
+    }
+
+    /// Sums the billed counts of observer groups, or of paid groups.
+    ///
+    /// Observers are the groups without a salary.
+    fn headcount_where(&self, observers: bool) -> u32 {
+        self.billed_counts()
+            .into_iter()
+            .filter(|(title, _)| {
+                self.attendees
+                    .get(*title)
+                    .is_some_and(|a| (a.salary == 0) == observers)
+            })
+            .fold(0, |sum, (_, count)| sum.saturating_add(count))
+    }
+
+    /// Returns `true` if the meeting has no attendees.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let mut meeting = Meeting::new();
+    /// assert!(meeting.is_empty());
+    /// meeting.add_attendee(&EmployeeCategory::new("Dev", 100_000).unwrap(), 1);
+    /// assert!(!meeting.is_empty());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// Whether the attendee list is empty.
+    ///
+    /// # See Also
+    /// * [`Meeting::total_attendees`]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.attendees.is_empty()
+    }
+
+    /// Returns the combined annual salary of everyone in the meeting.
+    ///
+    /// Each group contributes `salary * count`, where in strict duplicate
+    /// mode people listed under several groups are counted once, as they are
+    /// billed. The result saturates at [`u64::MAX`] instead of overflowing.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&EmployeeCategory::new("Dev", 100_000).unwrap(), 3);
+    /// assert_eq!(meeting.total_annual_salary(), 300_000);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// The summed annual salary in dollars, or zero for an empty meeting.
+    ///
+    /// # See Also
+    /// * [`Meeting::total_attendees`]
+    #[must_use]
+    pub fn total_annual_salary(&self) -> u64 {
+        let counts = self.billed_counts();
+        self.attendees.iter().fold(0, |sum, (title, a)| {
+            let count = counts.get(title.as_str()).copied().unwrap_or(a.count);
+            sum.saturating_add(a.salary.saturating_mul(u64::from(count)))
+        })
+    }
+
+    /// Captures the current attendee roster so it can be restored later.
+    ///
+    /// Timing information is not part of the snapshot.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let cat = EmployeeCategory::new("Dev", 90_000).unwrap();
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&cat, 1);
+    /// let snapshot = meeting.roster_snapshot();
+    /// meeting.add_attendee(&cat, 39);
+    /// meeting.restore_roster(snapshot);
+    /// assert_eq!(meeting.attendee_count("Dev"), Some(1));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// A [`RosterSnapshot`] of the current attendees.
+    ///
+    /// # See Also
+    /// * [`Meeting::restore_roster`]
+    #[must_use]
+    pub fn roster_snapshot(&self) -> RosterSnapshot {
+        RosterSnapshot {
+            attendees: self.attendees.clone(),
+            people: self.people.clone(),
+        }
+    }
+
+    /// Replaces the attendee roster with a previously captured snapshot.
+    ///
+    /// Elapsed time and the running state are left untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `snapshot` - Roster captured by [`Meeting::roster_snapshot`].
+    ///
+    /// # Returns
+    ///
+    /// Nothing.
+    ///
+    /// # See Also
+    /// * [`Meeting::roster_snapshot`]
+    pub fn restore_roster(&mut self, snapshot: RosterSnapshot) {
+        self.people = snapshot.people;
+        self.replace_roster(snapshot.attendees);
+    }
+
+    /// Starts the meeting timer.
+    ///
+    /// Calling this method while the meeting is already running has no effect.
+    /// Starting an armed meeting cancels the scheduled start.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::Meeting;
+    /// let mut meeting = Meeting::new();
+    /// meeting.start();
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// Nothing.
+    ///
+    /// # See Also
+    /// * [`Meeting::stop`]
+    pub fn start(&mut self) {
+        self.settle_schedule();
+        if !self.running {
+            self.scheduled_start = None;
+            self.start_time = Some(self.clock.now());
+            self.running = true;
+            self.started_at.get_or_insert_with(SystemTime::now);
+            self.ended_at = None;
+        }
+    }
+
+    /// Arms the meeting to start by itself once `delay` has passed.
+    ///
+    /// Until then the meeting is [armed](Meeting::is_armed) but not running and
+    /// accrues no time. When the delay elapses the meeting behaves exactly as
+    /// if [`Meeting::start`] had been called at that instant: the state is
+    /// derived on every query, so callers such as the TUI tick loop do not
+    /// need to poll anything for the switch to happen. Calling
+    /// [`Meeting::stop`] before then disarms the meeting.
+    ///
+    /// Arming a meeting that is already running has no effect.
+    ///
+    /// ## Example
+    /// ```
+    /// use std::time::Duration;
+    /// use meeting_cost_tracker::Meeting;
+    /// let mut meeting = Meeting::new();
+    /// meeting.arm_start_in(Duration::from_mins(1)).unwrap();
+    /// assert!(meeting.is_armed());
+    /// assert!(!meeting.is_running());
+    /// assert!(meeting.time_until_start().unwrap() > Duration::from_secs(59));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - Time from now until the meeting starts.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` once the meeting is armed, or was already running.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeetingError::InvalidDelay`] if `delay` reaches past the
+    /// latest instant the clock can represent; the meeting is then unchanged.
+    ///
+    /// # See Also
+    /// * [`Meeting::arm_start_at`]
+    /// * [`Meeting::time_until_start`]
+    pub fn arm_start_in(&mut self, delay: Duration) -> Result<(), MeetingError> {
+        let at = self
+            .clock
+            .now()
+            .checked_add(delay)
+            .ok_or(MeetingError::InvalidDelay)?;
+        self.arm_start_at(at);
+        Ok(())
+    }
+
+    /// Arms the meeting to start by itself at `at`.
+    ///
+    /// See [`Meeting::arm_start_in`] for details.
+    ///
+    /// # Arguments
+    ///
+    /// * `at` - Instant at which the meeting starts.
+    ///
+    /// # Returns
+    ///
+    /// Nothing.
+    ///
+    /// # See Also
+    /// * [`Meeting::arm_start_in`]
+    pub fn arm_start_at(&mut self, at: Instant) {
+        if !self.is_running() {
+            self.scheduled_start = Some(at);
+        }
+    }
+
+    /// Returns `true` if the meeting is waiting for a scheduled start.
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// `true` between [`Meeting::arm_start_in`] and the scheduled start.
+    ///
+    /// # See Also
+    /// * [`Meeting::time_until_start`]
+    #[must_use]
+    pub fn is_armed(&self) -> bool {
+        self.scheduled_start.is_some_and(|at| self.clock.now() < at)
+    }
+
+    /// Returns the time left until an armed meeting starts.
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// The remaining countdown, or `None` if the meeting is not armed.
+    ///
+    /// # See Also
+    /// * [`Meeting::is_armed`]
+    #[must_use]
+    pub fn time_until_start(&self) -> Option<Duration> {
+        self.scheduled_start
+            .and_then(|at| at.checked_duration_since(self.clock.now()))
+            .filter(|d| !d.is_zero())
+    }
+
+    /// Turns a scheduled start that has passed into a regular running state.
+    fn settle_schedule(&mut self) {
+        if let Some(at) = self.scheduled_start {
+            if self.clock.now() >= at {
+                self.scheduled_start = None;
+                self.start_time = Some(at);
+                self.running = true;
+                if self.started_at.is_none() {
+                    self.started_at = self.wall_clock_of(at);
+                }
+                self.ended_at = None;
+            }
+        }
+    }
+
+    /// Stops the meeting and accumulates elapsed time.
+    ///
+    /// This method is safe to call multiple times. Stopping an armed meeting
+    /// cancels its scheduled start.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::Meeting;
+    /// let mut meeting = Meeting::new();
+    /// meeting.start();
+    /// meeting.stop();
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// Nothing.
+    ///
+    /// # See Also
+    /// * [`Meeting::start`]
+    pub fn stop(&mut self) {
+        self.settle_schedule();
+        self.scheduled_start = None;
+        if self.running {
+            if let Some(start_time) = self.start_time.take() {
+                self.elapsed += self.clock.now().saturating_duration_since(start_time);
+            }
+            self.elapsed = self.duration();
+            self.running = false;
+            self.ended_at = Some(SystemTime::now());
+        }
+        self.end_segment();
+    }
+
+    /// Starts a named agenda segment at the current meeting time, ending the
+    /// open segment, if any.
+    ///
+    /// Segments record which part of the meeting each agenda item took, so
+    /// [`Meeting::segments`] can report what each one cost. Stopping the
+    /// meeting ends the open segment.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::Meeting;
+    /// let mut meeting = Meeting::new();
+    /// assert!(meeting.begin_segment("Intro").is_err());
+    /// meeting.start();
+    /// meeting.begin_segment("Intro").unwrap();
+    /// meeting.begin_segment("Retro").unwrap();
+    /// meeting.stop();
+    /// let names: Vec<String> = meeting.segments().into_iter().map(|s| s.name).collect();
+    /// assert_eq!(names, ["Intro", "Retro"]);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the agenda item.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` once the segment has begun.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeetingError::NotRunning`] unless the meeting is running.
+    ///
+    /// # See Also
+    /// * [`Meeting::end_segment`]
+    /// * [`Meeting::segments`]
+    pub fn begin_segment<T: Into<String>>(&mut self, name: T) -> Result<(), MeetingError> {
+        if !self.is_running() {
+            return Err(MeetingError::NotRunning);
+        }
+        self.end_segment();
+        self.segments.push(Segment {
+            name: name.into(),
+            start: self.duration(),
+            end: None,
+        });
+        Ok(())
+    }
+
+    /// Ends the open agenda segment at the current meeting time.
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// `true` if a segment was open.
+    ///
+    /// # See Also
+    /// * [`Meeting::begin_segment`]
+    pub fn end_segment(&mut self) -> bool {
+        let now = self.duration();
+        match self.segments.last_mut() {
+            Some(segment) if segment.end.is_none() => {
+                segment.end = Some(now);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the duration and cost of each agenda segment.
+    ///
+    /// A segment costs what the attendees present during it accrued, billed
+    /// as under [`CostModel::TimeWeighted`], so people who joined or left
+    /// part-way count only for their share. The open segment is measured up
+    /// to the current meeting time.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// use std::time::Duration;
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&EmployeeCategory::new("Engineer", 120_000).unwrap(), 2);
+    /// meeting.start();
+    /// meeting.begin_segment("Demo").unwrap();
+    /// meeting.stop();
+    /// let segment = &meeting.segments()[0];
+    /// assert_eq!(segment.name, "Demo");
+    /// assert!(segment.cost <= meeting.total_cost());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// One [`SegmentCost`] per segment, in the order they began.
+    ///
+    /// # See Also
+    /// * [`Meeting::begin_segment`]
+    /// * [`MeetingSummary::segments`]
+    #[must_use]
+    pub fn segments(&self) -> Vec<SegmentCost> {
+        let now = self.duration();
+        let usage = |at: Duration| -> u128 {
+            self.salary_millis_by_title(at)
+                .values()
+                .map(|&(_, used)| used)
+                .sum()
+        };
+        let overhead = self.overhead_numerator();
+        let denominator = self.cost_denominator();
+        self.segments
+            .iter()
+            .map(|segment| {
+                let end = segment.end.unwrap_or(now).min(now);
+                let start = segment.start.min(end);
+                let used = usage(end).saturating_sub(usage(start));
+                SegmentCost {
+                    name: segment.name.clone(),
+                    duration: end.saturating_sub(start),
+                    cost: dollars(used * overhead, denominator),
+                }
+            })
+            .collect()
+    }
+
+    /// Resets the meeting to its initial state.
+    ///
+    /// This clears all attendees, elapsed time, and the record of thresholds
+    /// reported by [`Meeting::check_thresholds`].
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let mut meeting = Meeting::new();
+    /// let cat = EmployeeCategory::new("Engineer", 120_000).unwrap();
+    /// meeting.add_attendee(&cat, 2);
+    /// meeting.reset();
+    /// assert_eq!(meeting.total_cost(), 0.0);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// Nothing.
+    ///
+    /// # See Also
+    /// * [`Meeting::start`]
+    /// * [`Meeting::stop`]
+    pub fn reset(&mut self) {
+        self.attendees.clear();
+        self.start_time = None;
+        self.elapsed = Duration::ZERO;
+        self.running = false;
+        self.scheduled_start = None;
+        self.fired_thresholds.clear();
+        self.undo.clear();
+        self.roster_log.clear();
+        self.people.clear();
+        self.started_at = None;
+        self.ended_at = None;
+        self.approximate_groups.clear();
+        self.salary_ranges.clear();
+        self.segments.clear();
+    }
+
+    /// Removes all attendees without modifying timing information.
+    ///
+    /// With [`CostModel::TimeWeighted`] the cost accrued so far is kept.
+    pub fn clear_attendees(&mut self) {
+        self.replace_roster(HashMap::new());
+    }
+
+    /// Replaces the whole attendee roster in one step without touching the timer.
+    ///
+    /// A running meeting keeps running, so time continues to accrue against the
+    /// new roster from this point on.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let dev = EmployeeCategory::new("Dev", 100_000).unwrap();
+    /// let pm = EmployeeCategory::new("PM", 110_000).unwrap();
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&dev, 4);
+    /// let previous = meeting.swap_attendees(&[(pm, 1)]);
+    /// assert_eq!(previous[0].title, "Dev");
+    /// assert_eq!(meeting.attendee_count("PM"), Some(1));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `new` - Categories and counts forming the new roster.
+    ///
+    /// # Returns
+    ///
+    /// The previous roster as [`AttendeeInfo`] entries sorted by title.
+    ///
+    /// # See Also
+    /// * [`Meeting::clear_attendees`]
+    /// * [`Meeting::add_attendee`]
+    pub fn swap_attendees(&mut self, new: &[(EmployeeCategory, u32)]) -> Vec<AttendeeInfo> {
+        let mut roster: HashMap<String, Attendee> = HashMap::new();
+        for (category, count) in new {
+            if !roster.contains_key(category.title()) {
+                self.note_estimates(category);
+            }
+            roster
+                .entry(category.title().to_string())
+                .or_insert_with(|| Attendee::new(category.salary(), 0))
+                .count += count;
+        }
+        let mut previous: Vec<AttendeeInfo> = self
+            .replace_roster(roster)
+            .into_iter()
+            .map(|(title, attendee)| AttendeeInfo {
+                title,
+                count: attendee.count,
+                name: None,
+                salary: None,
+                attention: attention_fraction(attendee.attention),
+            })
+            .collect();
+        previous.sort_by(|a, b| a.title.cmp(&b.title));
+        previous
+    }
+
+    /// Folds `other` into this meeting, e.g. to combine breakout rooms.
+    ///
+    /// Attendee counts are summed per title, named people are combined, and
+    /// the other meeting's duration is added to this one's. The merged
+    /// meeting treats the two as if they ran back to back: with
+    /// [`CostModel::TimeWeighted`] its [`Meeting::total_cost`] is exactly the
+    /// sum of both costs, and time spent after the merge is billed at the
+    /// combined roster. With [`CostModel::FlatFromStart`] the combined roster
+    /// is billed for the combined duration instead.
+    ///
+    /// Both meetings must be stopped; a running or armed meeting has no fixed
+    /// duration to add. The undo history of this meeting is discarded, while
+    /// its title, budget, cost model and work hours are kept.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let dev = EmployeeCategory::new("Dev", 120_000).unwrap();
+    /// let mut main_room = Meeting::new();
+    /// main_room.add_attendee(&dev, 3);
+    /// let mut breakout = Meeting::new();
+    /// breakout.add_attendee(&dev, 2);
+    /// main_room.merge(&breakout).unwrap();
+    /// assert_eq!(main_room.attendee_count("Dev"), Some(5));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Meeting to fold into this one. It is left unchanged.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` once the meetings have been merged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeetingError::MeetingRunning`] if either meeting is running
+    /// or armed, [`MeetingError::SalaryMismatch`] if a title has different
+    /// salaries in the two meetings, and [`MeetingError::PersonExists`] if a
+    /// named person is in both. Nothing is changed on error.
+    ///
+    /// # See Also
+    /// * [`Meeting::swap_attendees`]
+    pub fn merge(&mut self, other: &Meeting) -> Result<(), MeetingError> {
+        if [self, other].iter().any(|m| m.is_running() || m.is_armed()) {
+            return Err(MeetingError::MeetingRunning);
+        }
+        for (title, theirs) in &other.attendees {
+            if self
+                .attendees
+                .get(title)
+                .is_some_and(|ours| ours.salary != theirs.salary)
+            {
+                return Err(MeetingError::SalaryMismatch(title.clone()));
+            }
+        }
+        if let Some(name) = other.people.keys().find(|n| self.people.contains_key(*n)) {
+            return Err(MeetingError::PersonExists(name.clone()));
+        }
+
+        let offset = self.duration();
+        for (title, attendee) in &self.attendees {
+            self.roster_log.push(RosterEvent {
+                at: offset,
+                title: title.clone(),
+                salary: attendee.salary,
+                count: 0,
+                attention: attendee.attention,
+            });
+        }
+        self.roster_log
+            .extend(other.roster_log.iter().map(|event| RosterEvent {
+                at: offset + event.at,
+                ..event.clone()
+            }));
+        self.elapsed = offset + other.duration();
+        self.start_time = None;
+        self.scheduled_start = None;
+        for (title, theirs) in &other.attendees {
+            let ours = self
+                .attendees
+                .entry(title.clone())
+                .or_insert_with(|| Attendee::new(theirs.salary, 0));
+            ours.count = ours.count.saturating_add(theirs.count);
+        }
+        self.people
+            .extend(other.people.iter().map(|(n, t)| (n.clone(), t.clone())));
+        self.approximate_groups
+            .extend(other.approximate_groups.iter().cloned());
+        for (title, range) in &other.salary_ranges {
+            self.salary_ranges.entry(title.clone()).or_insert(*range);
+        }
+        self.segments
+            .extend(other.segments.iter().map(|segment| Segment {
+                name: segment.name.clone(),
+                start: offset + segment.start,
+                end: segment.end.map(|end| offset + end),
+            }));
+        let mut titles: Vec<String> = self.attendees.keys().cloned().collect();
+        titles.sort();
+        for title in titles {
+            self.log_roster_change(&title);
+        }
+        self.undo.clear();
+        Ok(())
+    }
+
+    /// Returns the total duration the meeting has been active.
+    ///
+    /// The result never exceeds the cap set by [`Meeting::set_max_duration`].
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::Meeting;
+    /// let mut meeting = Meeting::new();
+    /// meeting.start();
+    /// std::thread::sleep(std::time::Duration::from_millis(10));
+    /// meeting.stop();
+    /// let d = meeting.duration();
+    /// assert!(d.as_millis() > 0);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// The total duration of the meeting.
+    ///
+    /// # See Also
+    /// * [`Meeting::start`]
+    /// * [`Meeting::stop`]
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        let duration = self.elapsed + self.current_duration();
+        self.max_duration.map_or(duration, |max| duration.min(max))
+    }
+
+    /// Returns [`Meeting::duration`] in whole milliseconds, for external
+    /// logging or custom cost math on exact integers.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::Meeting;
+    /// use std::time::Duration;
+    /// let mut meeting = Meeting::new();
+    /// meeting.set_elapsed(Duration::from_micros(1_500_900));
+    /// assert_eq!(meeting.elapsed_millis(), 1_500);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// The duration in milliseconds, rounded down.
+    ///
+    /// # See Also
+    /// * [`Meeting::duration`]
+    #[must_use]
+    pub fn elapsed_millis(&self) -> u128 {
+        self.duration().as_millis()
+    }
+
+    /// Sets the meeting clock to `elapsed`, e.g. when the tracker was started
+    /// late.
+    ///
+    /// A running meeting keeps running: its current segment is folded into
+    /// the new value, so [`Meeting::duration`] continues from `elapsed`. An
+    /// armed meeting whose start time has passed is treated as running.
+    /// Attendee changes logged after `elapsed` are moved back to `elapsed`, so
+    /// the roster is the same as before the adjustment.
+    ///
+    /// ## Example
+    /// ```
+    /// use std::time::Duration;
+    /// use meeting_cost_tracker::Meeting;
+    /// let mut meeting = Meeting::new();
+    /// meeting.set_elapsed(Duration::from_secs(300));
+    /// assert_eq!(meeting.duration(), Duration::from_secs(300));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `elapsed` - New meeting duration.
+    ///
+    /// # Returns
+    ///
+    /// Nothing.
+    ///
+    /// # See Also
+    /// * [`Meeting::add_elapsed`]
+    /// * [`Meeting::subtract_elapsed`]
+    pub fn set_elapsed(&mut self, elapsed: Duration) {
+        self.settle_schedule();
+        if self.running {
+            self.start_time = Some(self.clock.now());
+        }
+        self.elapsed = elapsed;
+        for event in &mut self.roster_log {
+            event.at = event.at.min(elapsed);
+        }
+    }
+
+    /// Moves the meeting clock forward by `extra`.
+    ///
+    /// ## Example
+    /// ```
+    /// use std::time::Duration;
+    /// use meeting_cost_tracker::Meeting;
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_elapsed(Duration::from_secs(300));
+    /// meeting.add_elapsed(Duration::from_secs(60));
+    /// assert_eq!(meeting.duration(), Duration::from_secs(360));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `extra` - Time to add to [`Meeting::duration`].
+    ///
+    /// # Returns
+    ///
+    /// Nothing.
+    ///
+    /// # See Also
+    /// * [`Meeting::set_elapsed`]
+    pub fn add_elapsed(&mut self, extra: Duration) {
+        self.set_elapsed(self.duration().saturating_add(extra));
+    }
+
+    /// Moves the meeting clock back by `less`, stopping at zero.
+    ///
+    /// ## Example
+    /// ```
+    /// use std::time::Duration;
+    /// use meeting_cost_tracker::Meeting;
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_elapsed(Duration::from_secs(60));
+    /// meeting.subtract_elapsed(Duration::from_secs(300));
+    /// assert_eq!(meeting.duration(), Duration::ZERO);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `less` - Time to take off [`Meeting::duration`].
+    ///
+    /// # Returns
+    ///
+    /// Nothing.
+    ///
+    /// # See Also
+    /// * [`Meeting::set_elapsed`]
+    pub fn subtract_elapsed(&mut self, less: Duration) {
+        self.set_elapsed(self.duration().saturating_sub(less));
+    }
+
+    /// Caps the meeting at `max`, after which it stops by itself.
+    ///
+    /// Once the cap is reached [`Meeting::duration`] and
+    /// [`Meeting::total_cost`] stop growing and [`Meeting::is_running`]
+    /// returns `false`, even though [`Meeting::stop`] was never called.
+    /// Because the running time is measured live, the cap is applied
+    /// whenever these are queried rather than by a background timer.
+    /// Starting a capped meeting again has no effect until the cap is
+    /// raised or cleared.
+    ///
+    /// ## Example
+    /// ```
+    /// use std::time::Duration;
+    /// use meeting_cost_tracker::Meeting;
+    /// let mut meeting = Meeting::new();
+    /// meeting.set_max_duration(Duration::from_millis(5));
+    /// meeting.start();
+    /// std::thread::sleep(Duration::from_millis(20));
+    /// assert!(meeting.is_auto_stopped());
+    /// assert!(!meeting.is_running());
+    /// assert_eq!(meeting.duration(), Duration::from_millis(5));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - Longest the meeting may run.
+    ///
+    /// # Returns
+    ///
+    /// Nothing.
+    ///
+    /// # See Also
+    /// * [`Meeting::clear_max_duration`]
+    /// * [`Meeting::is_auto_stopped`]
+    pub fn set_max_duration(&mut self, max: Duration) {
+        self.max_duration = Some(max);
+    }
+
+    /// Removes the cap set by [`Meeting::set_max_duration`].
+    ///
+    /// A meeting that was auto-stopped while its timer was running resumes
+    /// from the time it was really running.
+    pub fn clear_max_duration(&mut self) {
+        self.max_duration = None;
+    }
+
+    /// Returns the cap set by [`Meeting::set_max_duration`], if any.
+    #[must_use]
+    pub fn max_duration(&self) -> Option<Duration> {
+        self.max_duration
+    }
+
+    /// Returns `true` if the meeting has reached its maximum duration.
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// `true` once [`Meeting::duration`] has reached the configured cap.
+    ///
+    /// # See Also
+    /// * [`Meeting::set_max_duration`]
+    #[must_use]
+    pub fn is_auto_stopped(&self) -> bool {
+        self.max_duration
+            .is_some_and(|max| self.elapsed + self.current_duration() >= max)
+    }
+
+    /// Returns the cost in dollars based on elapsed time and attendee salaries.
+    ///
+    /// Salaries are converted to a rate using [`Meeting::work_hours_per_year`].
+    /// Attendees who joined or left mid-meeting are billed according to the
+    /// [`Meeting::cost_model`]. The value is [`Meeting::total_cost_cents`] as
+    /// a float, so it never disagrees with the cents figure.
+    ///
+    /// ## Example
+    /// ```
+    /// use std::time::Duration;
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let mut meeting = Meeting::new();
+    /// let cat = EmployeeCategory::new("Engineer", 100_000).unwrap();
+    /// meeting.add_attendee(&cat, 1);
+    /// meeting.set_elapsed(Duration::from_mins(90));
+    /// assert_eq!(meeting.total_cost(), 75.0);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// The total cost in dollars, rounded half-up to whole cents.
+    ///
+    /// # See Also
+    /// * [`Meeting::duration`]
+    /// * [`Meeting::total_cost_cents`]
+    /// * [`EmployeeCategory::cost_per_millisecond_for`]
+    #[must_use]
+    pub fn total_cost(&self) -> f64 {
+        dollars(self.total_cost_cents(), 100)
+    }
+
+    /// Returns the cost in dollars with salaries spread over the working time
+    /// of `year` instead of [`Meeting::work_hours_per_year`].
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting, WorkYear};
+    /// use std::time::Duration;
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&EmployeeCategory::new("Engineer", 100_000).unwrap(), 1);
+    /// meeting.set_elapsed(Duration::from_hours(1));
+    /// // 2025 has 250 working days of 8 hours after 11 holidays.
+    /// let year = WorkYear::calendar(2025).with_holidays(11);
+    /// assert_eq!(meeting.total_cost_with(&year), 50.0);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - Working time the salaries are paid for.
+    ///
+    /// # Returns
+    ///
+    /// The total cost in dollars, or `0.0` for a year without working days.
+    ///
+    /// # See Also
+    /// * [`Meeting::total_cost`]
+    #[must_use]
+    pub fn total_cost_with(&self, year: &WorkYear) -> f64 {
+        let denominator = year.millis() * OVERHEAD_SCALE * u128::from(ATTENTION_SCALE);
+        if denominator == 0 {
+            return 0.0;
+        }
+        let usage: u128 = self.usage_by_group().iter().map(|g| g.3).sum();
+        dollars(usage, denominator)
+    }
+
+    /// Computes what the meeting would have cost so far without the named
+    /// attendee groups, e.g. without the directors in the room.
+    ///
+    /// The cost covers the same elapsed time as [`Meeting::total_cost`].
+    /// Titles that are not part of the meeting are ignored.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// use std::time::Duration;
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&EmployeeCategory::new("Engineer", 120_000).unwrap(), 2);
+    /// meeting.add_attendee(&EmployeeCategory::new("Director", 480_000).unwrap(), 3);
+    /// meeting.set_elapsed(Duration::from_secs(60));
+    /// assert!((meeting.cost_excluding(["Director", "Intern"]) - 2.0).abs() < 1e-9);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `titles` - Titles of the attendee groups to leave out.
+    ///
+    /// # Returns
+    ///
+    /// The cost in dollars of the remaining groups, `0.0` if none remain.
+    ///
+    /// # See Also
+    /// * [`Meeting::savings_if_removed`]
+    #[must_use]
+    pub fn cost_excluding<'a>(&self, titles: impl IntoIterator<Item = &'a str>) -> f64 {
+        let excluded: Vec<&str> = titles.into_iter().collect();
+        let usage: u128 = self
+            .usage_by_group()
+            .iter()
+            .filter(|g| !excluded.contains(&g.0.as_str()))
+            .map(|g| g.3)
+            .sum();
+        dollars(usage, self.cost_denominator())
+    }
+
+    /// Returns how much cheaper the meeting would have been so far without
+    /// the attendee group titled `title`.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// use std::time::Duration;
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&EmployeeCategory::new("Director", 480_000).unwrap(), 3);
+    /// meeting.set_elapsed(Duration::from_secs(60));
+    /// assert!((meeting.savings_if_removed("Director") - 12.0).abs() < 1e-9);
+    /// assert_eq!(meeting.savings_if_removed("Intern"), 0.0);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - Title of the attendee group.
+    ///
+    /// # Returns
+    ///
+    /// The group's cost in dollars, `0.0` if it is not part of the meeting.
+    ///
+    /// # See Also
+    /// * [`Meeting::cost_excluding`]
+    #[must_use]
+    pub fn savings_if_removed(&self, title: &str) -> f64 {
+        let usage: u128 = self
+            .usage_by_group()
+            .iter()
+            .filter(|g| g.0 == title)
+            .map(|g| g.3)
+            .sum();
+        dollars(usage, self.cost_denominator())
+    }
+
+    /// Previews what the meeting would have cost so far with `count` more
+    /// attendees of `category`, without changing it.
+    ///
+    /// The extra attendees are costed as if they had been present for the
+    /// whole elapsed time, with full attention, on top of
+    /// [`Meeting::total_cost`].
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// use std::time::Duration;
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&EmployeeCategory::new("Engineer", 120_000).unwrap(), 2);
+    /// meeting.set_elapsed(Duration::from_secs(60));
+    /// let manager = EmployeeCategory::new("Manager", 240_000).unwrap();
+    /// assert!((meeting.cost_if_added(&manager, 2) - 6.0).abs() < 1e-9);
+    /// assert_eq!(meeting.attendee_count("Manager"), None);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - Category of the hypothetical attendees.
+    /// * `count` - Number of hypothetical attendees.
+    ///
+    /// # Returns
+    ///
+    /// The total cost in dollars the meeting would have with them.
+    ///
+    /// # See Also
+    /// * [`Meeting::cost_excluding`]
+    #[must_use]
+    pub fn cost_if_added(&self, category: &EmployeeCategory, count: u32) -> f64 {
+        let usage: u128 = self.usage_by_group().iter().map(|g| g.3).sum();
+        let extra = u128::from(category.salary())
+            * u128::from(count)
+            * u128::from(ATTENTION_SCALE)
+            * self.elapsed_millis()
+            * self.overhead_numerator();
+        dollars(usage + extra, self.cost_denominator())
+    }
+
+    /// Returns the total cost of the meeting in cents.
+    ///
+    /// The cost is computed with integer arithmetic from each group's salary
+    /// and milliseconds present, and rounded half-up once at the end, so it
+    /// does not drift the way summing floats does. [`Meeting::total_cost`]
+    /// divides the same exact amount into dollars.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// use std::time::Duration;
+    /// let cat = EmployeeCategory::new("Dev", 120_000).unwrap();
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&cat, 1);
+    /// meeting.set_elapsed(Duration::from_secs(600));
+    /// assert_eq!(meeting.total_cost_cents(), 1_000);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// The total cost in whole cents.
+    ///
+    /// # See Also
+    /// * [`Meeting::total_cost`]
+    /// * [`CategoryCost::cost_cents`]
+    #[must_use]
+    pub fn total_cost_cents(&self) -> u128 {
+        let usage: u128 = self.usage_by_group().iter().map(|g| g.3).sum();
+        cents_half_up(usage, self.cost_denominator())
+    }
+
+    /// Returns [`Meeting::total_cost`] scaled by a single burden factor.
+    ///
+    /// This gives a quick fully-burdened figure, e.g. `1.3` for 30% on top of
+    /// salaries, without changing the meeting. Any overhead set with
+    /// [`Meeting::set_overhead`] is already part of the total cost, so the
+    /// multiplier applies on top of it.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::Meeting;
+    /// let meeting = Meeting::new();
+    /// assert_eq!(meeting.total_cost_with_overhead(1.3), Ok(0.0));
+    /// assert!(meeting.total_cost_with_overhead(0.5).is_err());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `multiplier` - Factor applied to the total cost, at least `1.0`.
+    ///
+    /// # Returns
+    ///
+    /// The scaled cost in dollars.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeetingError::InvalidMultiplier`] if `multiplier` is below
+    /// `1.0` or not finite.
+    ///
+    /// # See Also
+    /// * [`Meeting::set_overhead`]
+    pub fn total_cost_with_overhead(&self, multiplier: f64) -> Result<f64, MeetingError> {
+        if !multiplier.is_finite() || multiplier < 1.0 {
+            return Err(MeetingError::InvalidMultiplier(multiplier));
+        }
+        Ok(self.total_cost() * multiplier)
+    }
+
+    /// Returns the cost contributed by each attendee group, sorted by title.
+    ///
+    /// With [`CostModel::TimeWeighted`] the breakdown also lists groups that
+    /// have left the meeting, with a count of zero and the cost they accrued
+    /// while present. The costs always sum to [`Meeting::total_cost`].
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&EmployeeCategory::new("QA", 80_000).unwrap(), 2);
+    /// meeting.add_attendee(&EmployeeCategory::new("Dev", 100_000).unwrap(), 1);
+    /// let breakdown = meeting.breakdown();
+    /// assert_eq!(breakdown[0].title, "Dev");
+    /// assert_eq!(breakdown[1].count, 2);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// One [`CategoryCost`] per attendee group.
+    ///
+    /// # See Also
+    /// * [`Meeting::total_cost`]
+    #[must_use]
+    pub fn breakdown(&self) -> Vec<CategoryCost> {
+        let denominator = self.cost_denominator();
+        let mut rows: Vec<CategoryCost> = self
+            .usage_by_group()
+            .into_iter()
+            .map(|(title, salary, count, usage)| {
+                let cost_cents =
+                    u64::try_from(cents_half_up(usage, denominator)).unwrap_or(u64::MAX);
+                CategoryCost {
+                    title,
+                    salary,
+                    count,
+                    cost: dollars(u128::from(cost_cents), 100),
+                    cost_cents,
+                }
+            })
+            .collect();
+        rows.sort_by(|a, b| a.title.cmp(&b.title));
+        rows
+    }
+
+    /// Returns `(title, salary, count, usage)` per attendee group, where
+    /// `usage` divided by [`Meeting::cost_denominator`] is the group's cost in
+    /// dollars.
+    ///
+    /// Groups with strict duplicate exclusions report their billed count and
+    /// have their usage reduced in the same proportion.
+    fn usage_by_group(&self) -> Vec<(String, u64, u32, u128)> {
+        let overhead = self.overhead_numerator();
+        let billed = self.billed_counts();
+        let bill = |title: &str, salary: u64, usage: u128| {
+            let count = self.attendee_count(title).unwrap_or(0);
+            let billed = billed.get(title).copied().unwrap_or(count);
+            let usage = if billed < count {
+                usage * u128::from(billed) / u128::from(count)
+            } else {
+                usage
+            };
+            (title.to_string(), salary, billed, usage * overhead)
+        };
+        match self.cost_model {
+            CostModel::TimeWeighted => self
+                .salary_millis_by_title(self.duration())
+                .into_iter()
+                .map(|(title, (salary, usage))| bill(title, salary, usage))
+                .collect(),
+            CostModel::FlatFromStart => {
+                let millis = self.elapsed_millis();
+                self.attendees
+                    .iter()
+                    .map(|(title, a)| {
+                        let usage = u128::from(a.salary)
+                            * u128::from(a.count)
+                            * u128::from(a.attention)
+                            * millis;
+                        bill(title, a.salary, usage)
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// Names the meeting, e.g. for the heading of
+    /// [`Meeting::to_markdown_summary`].
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::Meeting;
+    /// let mut meeting = Meeting::new();
+    /// meeting.set_title("Weekly sync");
+    /// assert_eq!(meeting.title(), Some("Weekly sync"));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - Name of the meeting.
+    ///
+    /// # Returns
+    ///
+    /// Nothing.
+    ///
+    /// # See Also
+    /// * [`Meeting::title`]
+    pub fn set_title<T: Into<String>>(&mut self, title: T) {
+        self.title = Some(title.into());
+    }
+
+    /// Returns the name set by [`Meeting::set_title`], if any.
+    #[must_use]
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    /// Sets how many dollars the meeting is allowed to cost.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::Meeting;
+    /// let mut meeting = Meeting::new();
+    /// meeting.set_budget(500.0).unwrap();
+    /// assert_eq!(meeting.budget(), Some(500.0));
+    /// assert!(meeting.set_budget(-1.0).is_err());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `budget` - Budget in dollars.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the budget was set.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeetingError::InvalidBudget`] if `budget` is negative or not finite.
+    ///
+    /// # See Also
+    /// * [`Meeting::remaining_budget`]
+    /// * [`Meeting::time_until_budget_exhausted`]
+    pub fn set_budget(&mut self, budget: f64) -> Result<(), MeetingError> {
+        if !budget.is_finite() || budget < 0.0 {
+            return Err(MeetingError::InvalidBudget(budget));
+        }
+        self.budget = Some(budget);
+        Ok(())
+    }
+
+    /// Removes the budget set by [`Meeting::set_budget`].
+    pub fn clear_budget(&mut self) {
+        self.budget = None;
+    }
+
+    /// Returns the budget set by [`Meeting::set_budget`], if any.
+    #[must_use]
+    pub fn budget(&self) -> Option<f64> {
+        self.budget
+    }
+
+    /// Returns how many dollars of the budget are left.
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// The budget minus [`Meeting::total_cost`], negative once the meeting is
+    /// over budget, or `None` without a budget.
+    ///
+    /// # See Also
+    /// * [`Meeting::set_budget`]
+    #[must_use]
+    pub fn remaining_budget(&self) -> Option<f64> {
+        self.budget.map(|budget| budget - self.total_cost())
+    }
+
+    /// Checks whether the meeting has cost more than its budget.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// use std::time::Duration;
+    /// let cat = EmployeeCategory::new("Engineer", 120_000).unwrap();
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&cat, 1);
+    /// meeting.set_budget(5.0).unwrap();
+    /// assert!(!meeting.is_over_budget());
+    /// meeting.set_elapsed(Duration::from_secs(6 * 60));
+    /// assert!(meeting.is_over_budget());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// `true` once [`Meeting::total_cost`] exceeds the budget; always `false`
+    /// without a budget. A meeting that costs exactly its budget is not over.
+    ///
+    /// # See Also
+    /// * [`Meeting::remaining_budget`]
+    #[must_use]
+    pub fn is_over_budget(&self) -> bool {
+        self.remaining_budget().is_some_and(|left| left < 0.0)
+    }
+
+    /// Checks whether the meeting's costs are estimates because a group was
+    /// added from an [approximate](EmployeeCategory::is_approximate) category,
+    /// such as one loaded from a salary band file.
+    ///
+    /// The flag stays set after the group leaves, since the cost it accrued
+    /// remains part of the total, and is cleared by [`Meeting::reset`].
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let mut pm = EmployeeCategory::new("PM", 112_500).unwrap();
+    /// pm.set_approximate(true);
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&EmployeeCategory::new("Dev", 100_000).unwrap(), 2);
+    /// assert!(!meeting.is_approximate());
+    /// meeting.add_attendee(&pm, 1);
+    /// assert!(meeting.is_approximate());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// `true` if any cost was computed from an approximate salary.
+    ///
+    /// # See Also
+    /// * [`MeetingSummary::approximate`]
+    #[must_use]
+    pub fn is_approximate(&self) -> bool {
+        !self.approximate_groups.is_empty()
+    }
+
+    /// Returns how much longer the meeting can run before its budget is spent,
+    /// for a countdown display.
+    ///
+    /// The estimate assumes the current roster keeps burning at
+    /// [`Meeting::burn_rate_per_minute`], so it changes as soon as attendees
+    /// are added or removed.
+    ///
+    /// ## Example
+    /// ```
+    /// use std::time::Duration;
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// // $1 per minute.
+    /// let cat = EmployeeCategory::new("Engineer", 120_000).unwrap();
+    /// let mut meeting = Meeting::new();
+    /// assert_eq!(meeting.time_until_budget_exhausted(), None);
+    /// meeting.add_attendee(&cat, 1);
+    /// meeting.set_budget(10.0).unwrap();
+    /// meeting.set_elapsed(Duration::from_mins(4));
+    /// assert_eq!(meeting.time_until_budget_exhausted(), Some(Duration::from_mins(6)));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// `None` without a budget or paid attendees, `Some(Duration::ZERO)` once
+    /// the budget is used up, and otherwise the remaining budget divided by
+    /// the burn rate.
+    ///
+    /// # See Also
+    /// * [`Meeting::remaining_budget`]
+    /// * [`Meeting::burn_rate_per_minute`]
+    #[must_use]
+    pub fn time_until_budget_exhausted(&self) -> Option<Duration> {
+        let remaining = self.remaining_budget()?;
+        if self.total_attendees() == 0 {
+            return None;
+        }
+        if remaining <= 0.0 {
+            return Some(Duration::ZERO);
+        }
+        let rate_per_second = self.burn_rate_per_minute() / 60.0;
+        if rate_per_second <= 0.0 {
+            return None;
+        }
+        Duration::try_from_secs_f64(remaining / rate_per_second).ok()
+    }
+
+    /// Same as [`Meeting::time_until_budget_exhausted`], under its earlier
+    /// name.
+    #[must_use]
+    pub fn budget_exhaustion_eta(&self) -> Option<Duration> {
+        self.time_until_budget_exhausted()
+    }
+
+    /// Returns the average cost per attendee.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::Meeting;
+    /// let meeting = Meeting::new();
+    /// assert_eq!(meeting.cost_per_attendee(), 0.0);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// [`Meeting::total_cost`] divided by [`Meeting::total_attendees`], or `0.0`
+    /// for a meeting without attendees.
+    ///
+    /// # See Also
+    /// * [`Meeting::exceeds_per_head`]
+    #[must_use]
+    pub fn cost_per_attendee(&self) -> f64 {
+        match self.total_attendees() {
+            0 => 0.0,
+            count => self.total_cost() / f64::from(count),
+        }
+    }
+
+    /// Returns `true` if the average cost per attendee is above `cap` dollars.
+    ///
+    /// A meeting without attendees never exceeds the cap.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::Meeting;
+    /// let meeting = Meeting::new();
+    /// assert!(!meeting.exceeds_per_head(0.0));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `cap` - Maximum acceptable spend per attendee in dollars.
+    ///
+    /// # Returns
+    ///
+    /// Whether [`Meeting::cost_per_attendee`] is greater than `cap`.
+    ///
+    /// # See Also
+    /// * [`Meeting::cost_per_attendee`]
+    #[must_use]
+    pub fn exceeds_per_head(&self, cap: f64) -> bool {
+        !self.is_empty() && self.cost_per_attendee() > cap
+    }
+
+    /// Returns `true` once the total cost has reached `threshold` dollars.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::Meeting;
+    /// let meeting = Meeting::new();
+    /// assert!(meeting.cost_threshold_crossed(0.0));
+    /// assert!(!meeting.cost_threshold_crossed(1000.0));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - Cost in dollars to compare against.
+    ///
+    /// # Returns
+    ///
+    /// Whether [`Meeting::total_cost`] is at or above `threshold`.
+    ///
+    /// # See Also
+    /// * [`Meeting::check_thresholds`]
+    #[must_use]
+    pub fn cost_threshold_crossed(&self, threshold: f64) -> bool {
+        self.total_cost() >= threshold
+    }
+
+    /// Reports cost thresholds crossed since the previous call.
+    ///
+    /// Each threshold is reported at most once until [`Meeting::reset`] is
+    /// called, making this suitable for driving one-off alerts from a tick loop.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::Meeting;
+    /// let mut meeting = Meeting::new();
+    /// assert_eq!(meeting.check_thresholds(&[0.0, 1000.0]), vec![0.0]);
+    /// assert!(meeting.check_thresholds(&[0.0, 1000.0]).is_empty());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `thresholds` - Costs in dollars to watch for.
+    ///
+    /// # Returns
+    ///
+    /// The thresholds newly crossed, in the order they were given.
+    ///
+    /// # See Also
+    /// * [`Meeting::cost_threshold_crossed`]
+    pub fn check_thresholds(&mut self, thresholds: &[f64]) -> Vec<f64> {
+        let cost = self.total_cost();
+        let crossed: Vec<f64> = thresholds
+            .iter()
+            .copied()
+            .filter(|t| cost >= *t && !self.fired_thresholds.contains(t))
+            .collect();
+        self.fired_thresholds.extend(&crossed);
+        crossed
+    }
+
+    /// Splits the total cost into a shared-budget part and a direct part.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::Meeting;
+    /// let meeting = Meeting::new();
+    /// let (shared, direct) = meeting.attribute(0.25).unwrap();
+    /// assert_eq!(shared + direct, meeting.total_cost());
+    /// assert!(meeting.attribute(1.5).is_err());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `shared_fraction` - Portion of the cost charged to the shared pool, in `0.0..=1.0`.
+    ///
+    /// # Returns
+    ///
+    /// A `(shared, direct)` tuple of dollar amounts that sums to [`Meeting::total_cost`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeetingError::InvalidFraction`] if `shared_fraction` is outside
+    /// `0.0..=1.0` or not a number.
+    ///
+    /// # See Also
+    /// * [`Meeting::total_cost`]
+    pub fn attribute(&self, shared_fraction: f64) -> Result<(f64, f64), MeetingError> {
+        if !(0.0..=1.0).contains(&shared_fraction) {
+            return Err(MeetingError::InvalidFraction(shared_fraction));
+        }
+        let total = self.total_cost();
+        let shared = total * shared_fraction;
+        Ok((shared, total - shared))
+    }
+
+    /// Returns the range the meeting's cost lies in, given the salary ranges
+    /// of its categories.
+    ///
+    /// Groups added from a category with a
+    /// [salary range](EmployeeCategory::salary_range) are costed at the
+    /// lowest and highest salary of their range; other groups add the same
+    /// cost to both bounds. For now `confidence` only chooses between the
+    /// point estimate, for `0.0` or less, and the full min/max range for any
+    /// positive value.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// use std::time::Duration;
+    /// let cat = EmployeeCategory::new("Engineer", 120_000)
+    ///     .unwrap()
+    ///     .with_salary_range(90_000, 150_000)
+    ///     .unwrap();
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&cat, 1);
+    /// meeting.set_elapsed(Duration::from_mins(60));
+    /// assert_eq!(meeting.cost_interval(0.9), (45.0, 75.0));
+    /// assert_eq!(meeting.cost_interval(0.0), (60.0, 60.0));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `confidence` - How much of the salary ranges to cover.
+    ///
+    /// # Returns
+    ///
+    /// `(low, high)` dollar amounts around [`Meeting::total_cost`].
+    ///
+    /// # See Also
+    /// * [`Meeting::total_cost`]
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn cost_interval(&self, confidence: f64) -> (f64, f64) {
+        let denominator = self.cost_denominator();
+        let (mut low, mut high) = (0.0, 0.0);
+        for (title, salary, _, usage) in self.usage_by_group() {
+            let cost = dollars(usage, denominator);
+            match self.salary_ranges.get(&title) {
+                Some(&(min, max)) if confidence > 0.0 && salary > 0 => {
+                    low += cost * min as f64 / salary as f64;
+                    high += cost * max as f64 / salary as f64;
+                }
+                _ => {
+                    low += cost;
+                    high += cost;
+                }
+            }
+        }
+        (low, high)
+    }
+
+    /// Returns how many dollars the current roster costs per minute.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let cat = EmployeeCategory::new("Engineer", 120_000).unwrap();
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&cat, 2);
+    /// assert!((meeting.burn_rate_per_minute() - 2.0).abs() < 1e-9);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// The burn rate in dollars per minute, independent of elapsed time.
+    ///
+    /// # See Also
+    /// * [`Meeting::total_cost`]
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn burn_rate_per_minute(&self) -> f64 {
+        let minutes_per_year = self.work_hours_per_year * 60.0 / self.overhead_factor();
+        let counts = self.billed_counts();
+        self.attendees
+            .iter()
+            .map(|(title, a)| {
+                let count = counts.get(title.as_str()).copied().unwrap_or(a.count);
+                a.salary as f64 * f64::from(count) * attention_fraction(a.attention)
+                    / minutes_per_year
+            })
+            .sum()
+    }
+
+    /// Returns the events of the meeting in elapsed-time order.
+    ///
+    /// Every roster change appears as a [`TimelineEvent::RateChange`] carrying
+    /// the burn rate that applied from then on. Events recorded at the same
+    /// time keep the order in which they happened. The timeline is cleared by
+    /// [`Meeting::reset`].
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting, TimelineEvent};
+    /// let cat = EmployeeCategory::new("Engineer", 120_000).unwrap();
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&cat, 2);
+    /// let timeline = meeting.timeline();
+    /// assert!(matches!(
+    ///     &timeline[0],
+    ///     TimelineEvent::RateChange { count: 2, .. }
+    /// ));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// The meeting's events sorted by [`TimelineEvent::at`].
+    ///
+    /// # See Also
+    /// * [`Meeting::burn_rate_per_minute`]
+    /// * [`Meeting::breakdown`]
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn timeline(&self) -> Vec<TimelineEvent> {
+        let minutes_per_year = self.work_hours_per_year * 60.0 / self.overhead_factor();
+        let mut groups: HashMap<&str, f64> = HashMap::new();
+        let mut events: Vec<TimelineEvent> = self
+            .roster_log
+            .iter()
+            .map(|event| {
+                groups.insert(
+                    &event.title,
+                    event.salary as f64
+                        * f64::from(event.count)
+                        * attention_fraction(event.attention)
+                        / minutes_per_year,
+                );
+                TimelineEvent::RateChange {
+                    at: event.at,
+                    title: event.title.clone(),
+                    count: event.count,
+                    burn_rate_per_minute: groups.values().sum(),
+                }
+            })
+            .collect();
+        events.sort_by_key(TimelineEvent::at);
+        events
+    }
+
+    /// Reconstructs the meeting as it stood `offset` into its run, e.g. to
+    /// review a meeting after it ended.
+    ///
+    /// The roster is replayed from the recorded roster changes up to and
+    /// including `offset`, which is limited to [`Meeting::duration`]. The
+    /// snapshot is stopped, its elapsed time is `offset`, and its
+    /// [`total_cost`](MeetingSnapshot::total_cost) and
+    /// [`burn_rate_per_minute`](MeetingSnapshot::burn_rate_per_minute) are
+    /// the cost accrued by then and the rate in effect at that moment. Named
+    /// people are not tracked over time, so the roster lists groups only.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// use std::time::Duration;
+    /// let cat = EmployeeCategory::new("Engineer", 120_000).unwrap();
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&cat, 1);
+    /// meeting.set_elapsed(Duration::from_mins(10));
+    /// meeting.add_attendee(&cat, 2);
+    /// meeting.set_elapsed(Duration::from_mins(20));
+    ///
+    /// let past = meeting.state_at(Duration::from_mins(5));
+    /// assert_eq!(past.attendees[0].count, 1);
+    /// assert_eq!(past.total_cost, 5.0);
+    /// assert_eq!(past.burn_rate_per_minute, 1.0);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - Meeting time to reconstruct.
+    ///
+    /// # Returns
+    ///
+    /// A [`MeetingSnapshot`] of the meeting at `offset`.
+    ///
+    /// # See Also
+    /// * [`Meeting::timeline`]
+    /// * [`Meeting::rate_steps`]
+    #[must_use]
+    pub fn state_at(&self, offset: Duration) -> MeetingSnapshot {
+        let offset = offset.min(self.duration());
+        let roster_log: Vec<RosterEvent> = self
+            .roster_log
+            .iter()
+            .take_while(|e| e.at <= offset)
+            .cloned()
+            .collect();
+        let mut attendees = HashMap::new();
+        for event in &roster_log {
+            if event.count == 0 {
+                attendees.remove(&event.title);
+            } else {
+                attendees.insert(
+                    event.title.clone(),
+                    Attendee {
+                        salary: event.salary,
+                        count: event.count,
+                        attention: event.attention,
+                    },
+                );
+            }
+        }
+        let past = Self {
+            attendees,
+            elapsed: offset,
+            title: self.title.clone(),
+            overhead: self.overhead,
+            work_hours_per_year: self.work_hours_per_year,
+            cost_model: self.cost_model,
+            roster_log,
+            started_at: self.started_at,
+            approximate_groups: self.approximate_groups.clone(),
+            ..Self::new()
+        };
+        MeetingSnapshot::from(&past)
+    }
+
+    /// Captures the duration, total cost and per-group costs in one value.
+    ///
+    /// Useful for asserting on everything a frontend shows with a single
+    /// comparison, e.g. with a `testing::ManualClock`.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{CostSnapshot, EmployeeCategory, Meeting};
+    /// use std::time::Duration;
+    /// let cat = EmployeeCategory::new("Engineer", 120_000).unwrap();
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&cat, 2);
+    /// meeting.set_elapsed(Duration::from_secs(60));
+    /// assert_eq!(
+    ///     meeting.snapshot_cost(),
+    ///     CostSnapshot {
+    ///         duration: Duration::from_secs(60),
+    ///         total: 2.0,
+    ///         per_category: vec![("Engineer".to_string(), 2.0)],
+    ///     }
+    /// );
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// A [`CostSnapshot`] of the meeting's current cost.
+    ///
+    /// # See Also
+    /// * [`Meeting::summary`]
+    /// * [`Meeting::breakdown`]
+    #[must_use]
+    pub fn snapshot_cost(&self) -> CostSnapshot {
+        CostSnapshot {
+            duration: self.duration(),
+            total: self.total_cost(),
+            per_category: self
+                .breakdown()
+                .into_iter()
+                .map(|row| (row.title, row.cost))
+                .collect(),
+        }
+    }
+
+    /// Captures what happened in the meeting so far as a [`MeetingSummary`].
+    ///
+    /// The summary is a copy, so it can be kept after [`Meeting::reset`] and
+    /// serialized with any serde format.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// use std::time::Duration;
+    /// let cat = EmployeeCategory::new("Engineer", 120_000).unwrap();
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&cat, 2);
+    /// meeting.start();
+    /// meeting.stop();
+    /// let summary = meeting.summary();
+    /// meeting.reset();
+    /// assert_eq!(summary.headcount, 2);
+    /// assert_eq!(summary.categories[0].title, "Engineer");
+    /// assert!(summary.started_at.is_some() && summary.ended_at.is_some());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// A [`MeetingSummary`] of the meeting's current state.
+    ///
+    /// # See Also
+    /// * [`Meeting::breakdown`]
+    /// * [`Meeting::describe`]
+    #[must_use]
+    pub fn summary(&self) -> MeetingSummary {
+        let categories = self.breakdown();
+        MeetingSummary {
+            title: self.title.clone(),
+            started_at: self.started_at(),
+            ended_at: self.ended_at(),
+            duration: self.duration(),
+            total_cost: categories.iter().map(|c| c.cost).sum(),
+            headcount: self.total_attendees(),
+            categories,
+            approximate: self.is_approximate(),
+            segments: self.segments(),
+        }
+    }
+
+    /// Returns the burn rate as a step function over the meeting.
+    ///
+    /// Each entry is the meeting time of a roster change and the burn rate of
+    /// the whole meeting from then on. Changes at the same time are merged
+    /// into one step carrying the rate after the last of them.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// use std::time::Duration;
+    /// let cat = EmployeeCategory::new("Engineer", 120_000).unwrap();
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&cat, 2);
+    /// meeting.set_elapsed(Duration::from_secs(60));
+    /// meeting.remove_attendee("Engineer", 1);
+    /// let steps = meeting.rate_steps();
+    /// assert_eq!(steps.len(), 2);
+    /// assert_eq!(steps[1].0, Duration::from_secs(60));
+    /// assert!((steps[1].1 - 1.0).abs() < 1e-9);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// `(offset, burn rate per minute)` pairs in time order.
+    ///
+    /// # See Also
+    /// * [`Meeting::timeline`]
+    /// * [`Meeting::burn_rate_per_minute`]
+    #[must_use]
+    pub fn rate_steps(&self) -> Vec<(Duration, f64)> {
+        let mut steps: Vec<(Duration, f64)> = Vec::new();
+        for event in self.timeline() {
+            let TimelineEvent::RateChange {
+                at,
+                burn_rate_per_minute,
+                ..
+            } = event;
+            match steps.last_mut() {
+                Some(last) if last.0 == at => last.1 = burn_rate_per_minute,
+                _ => steps.push((at, burn_rate_per_minute)),
+            }
+        }
+        steps
+    }
+
+    /// Samples how the cost of the most expensive attendee groups built up.
+    ///
+    /// The series are derived from the recorded roster changes rather than
+    /// collected while the meeting runs, so they cost no memory between calls.
+    /// Only the first `top` groups of [`Meeting::attendees_by_cost`] are
+    /// sampled. Each series holds the group's accumulated cost in dollars at
+    /// `samples` evenly spaced points, ending at the current duration, billed
+    /// as under [`CostModel::TimeWeighted`]: a group that joined late stays
+    /// at zero until it joined.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// use std::time::Duration;
+    /// let cat = EmployeeCategory::new("Engineer", 120_000).unwrap();
+    /// let mut meeting = Meeting::new();
+    /// meeting.set_elapsed(Duration::from_secs(120));
+    /// meeting.add_attendee(&cat, 1);
+    /// meeting.set_elapsed(Duration::from_secs(240));
+    /// let series = meeting.category_cost_series(4, 5);
+    /// assert_eq!(series[0].0, "Engineer");
+    /// assert_eq!(series[0].1, vec![0.0, 0.0, 1.0, 2.0]);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `samples` - Number of points per series.
+    /// * `top` - Largest number of groups to sample.
+    ///
+    /// # Returns
+    ///
+    /// `(title, accumulated costs)` pairs, most expensive group first.
+    ///
+    /// # See Also
+    /// * [`mini_sparkline`](crate::mini_sparkline)
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn category_cost_series(&self, samples: usize, top: usize) -> Vec<(String, Vec<f64>)> {
+        let duration = self.duration();
+        let overhead = self.overhead_numerator();
+        let denominator = self.cost_denominator();
+        let mut series: Vec<(String, Vec<f64>)> = self
+            .attendees_by_cost()
+            .into_iter()
+            .take(top)
+            .map(|(title, ..)| (title.to_string(), Vec::with_capacity(samples)))
+            .collect();
+        if series.is_empty() {
+            return series;
+        }
+        for sample in 1..=samples {
+            let until = duration.mul_f64(sample as f64 / samples as f64);
+            let usage = self.salary_millis_by_title(until);
+            for (title, values) in &mut series {
+                let used = usage.get(title.as_str()).map_or(0, |g| g.1);
+                values.push(dollars(used * overhead, denominator));
+            }
+        }
+        series
+    }
+
+    /// Reports whether the meeting is getting more or less expensive.
+    ///
+    /// The current burn rate is compared with the rate in effect before the
+    /// last roster change, so the trend holds until attendees join or leave
+    /// again.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting, Trend};
+    /// let cat = EmployeeCategory::new("Engineer", 120_000).unwrap();
+    /// let mut meeting = Meeting::new();
+    /// assert_eq!(meeting.rate_trend(), Trend::Steady);
+    /// meeting.add_attendee(&cat, 2);
+    /// assert_eq!(meeting.rate_trend(), Trend::Increasing);
+    /// meeting.remove_attendee("Engineer", 1);
+    /// assert_eq!(meeting.rate_trend(), Trend::Decreasing);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// The [`Trend`] of the burn rate after its last change.
+    ///
+    /// # See Also
+    /// * [`Meeting::burn_rate_per_minute`]
+    /// * [`Meeting::timeline`]
+    #[must_use]
+    pub fn rate_trend(&self) -> Trend {
+        let rates: Vec<f64> = self
+            .timeline()
+            .into_iter()
+            .map(|event| match event {
+                TimelineEvent::RateChange {
+                    burn_rate_per_minute,
+                    ..
+                } => burn_rate_per_minute,
+            })
+            .collect();
+        let Some((&current, earlier)) = rates.split_last() else {
+            return Trend::Steady;
+        };
+        let previous = earlier.last().copied().unwrap_or(0.0);
+        if (current - previous).abs() < 1e-9 {
+            Trend::Steady
+        } else if current > previous {
+            Trend::Increasing
+        } else {
+            Trend::Decreasing
+        }
+    }
+
+    /// Chooses whether a running meeting carries on by wall-clock time after
+    /// a restart.
+    ///
+    /// When enabled, a [`MeetingSnapshot`] of the running meeting records the
+    /// wall-clock time it was taken, and [`Meeting::from_state`] adds the time
+    /// that passed until the snapshot is restored, e.g. after a crash or a
+    /// reboot. Off by default, so a restored meeting carries on from the
+    /// elapsed time it was saved with.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{Meeting, MeetingSnapshot};
+    /// let mut meeting = Meeting::new();
+    /// meeting.start();
+    /// assert_eq!(MeetingSnapshot::from(&meeting).saved_at, None);
+    /// meeting.set_wall_clock_resume(true);
+    /// assert!(MeetingSnapshot::from(&meeting).saved_at.is_some());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - `true` to count the time between saving and restoring.
+    ///
+    /// # Returns
+    ///
+    /// Nothing.
+    ///
+    /// # See Also
+    /// * [`Meeting::from_state_at`]
+    pub fn set_wall_clock_resume(&mut self, enabled: bool) {
+        self.wall_clock_resume = enabled;
+    }
+
+    /// Returns whether the meeting resumes by wall-clock time; see
+    /// [`Meeting::set_wall_clock_resume`].
+    #[must_use]
+    pub fn wall_clock_resume(&self) -> bool {
+        self.wall_clock_resume
+    }
+
+    /// Returns the roster history as [`RosterChange`] entries for a snapshot.
+    pub(crate) fn roster_changes(&self) -> Vec<RosterChange> {
+        self.roster_log
+            .iter()
+            .map(|event| RosterChange {
+                at_millis: u64::try_from(event.at.as_millis()).unwrap_or(u64::MAX),
+                title: event.title.clone(),
+                salary: event.salary,
+                count: event.count,
+                attention: attention_fraction(event.attention),
+            })
+            .collect()
+    }
+
+    /// Replaces the roster history with `changes`, e.g. from a snapshot.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeetingError::InvalidFraction`] for an attention outside
+    /// `(0, 1]`; the history is then unchanged.
+    pub(crate) fn restore_roster_log(
+        &mut self,
+        changes: &[RosterChange],
+    ) -> Result<(), MeetingError> {
+        let mut log = Vec::with_capacity(changes.len());
+        for change in changes {
+            log.push(RosterEvent {
+                at: Duration::from_millis(change.at_millis),
+                title: change.title.clone(),
+                salary: change.salary,
+                count: change.count,
+                attention: attention_parts(change.attention)?,
+            });
+        }
+        self.roster_log = log;
+        Ok(())
+    }
+
+    /// Returns the groups with an approximate salary and the salary ranges
+    /// by group, for a snapshot.
+    pub(crate) fn estimate_markers(&self) -> (Vec<String>, BTreeMap<String, (u64, u64)>) {
+        let approximate = self.approximate_groups.iter().cloned().collect();
+        let ranges = self
+            .salary_ranges
+            .iter()
+            .map(|(title, range)| (title.clone(), *range))
+            .collect();
+        (approximate, ranges)
+    }
+
+    /// Replaces the estimate markers with those saved by
+    /// [`Meeting::estimate_markers`].
+    pub(crate) fn restore_estimate_markers(
+        &mut self,
+        approximate: Vec<String>,
+        ranges: BTreeMap<String, (u64, u64)>,
+    ) {
+        self.approximate_groups = approximate.into_iter().collect();
+        self.salary_ranges = ranges.into_iter().collect();
+    }
+
+    /// Restores the wall-clock start and end times, e.g. from a snapshot.
+    pub(crate) fn restore_wall_clock(
+        &mut self,
+        started_at: Option<SystemTime>,
+        ended_at: Option<SystemTime>,
+    ) {
+        self.started_at = started_at;
+        self.ended_at = ended_at;
+    }
+
+    /// Returns the wall-clock time at which the meeting first started.
+    ///
+    /// Later start/stop cycles keep the first start, and [`Meeting::reset`]
+    /// clears it. A meeting armed with [`Meeting::arm_start_in`] reports the
+    /// time its countdown ran out.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::Meeting;
+    /// let mut meeting = Meeting::new();
+    /// assert_eq!(meeting.started_at(), None);
+    /// meeting.start();
+    /// let first = meeting.started_at().unwrap();
+    /// meeting.stop();
+    /// meeting.start();
+    /// assert_eq!(meeting.started_at(), Some(first));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// The time of the first start, or `None` if the meeting never started.
+    ///
+    /// # See Also
+    /// * [`Meeting::ended_at`]
+    #[must_use]
+    pub fn started_at(&self) -> Option<SystemTime> {
+        self.started_at.or_else(|| {
+            self.scheduled_start
+                .filter(|at| self.clock.now() >= *at)
+                .and_then(|at| self.wall_clock_of(at))
+        })
+    }
+
+    /// Returns the wall-clock time at which the meeting last stopped.
+    ///
+    /// The time is cleared while the meeting runs again, so it always marks
+    /// the end of the most recent stop, and [`Meeting::reset`] clears it.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::Meeting;
+    /// let mut meeting = Meeting::new();
+    /// meeting.start();
+    /// assert_eq!(meeting.ended_at(), None);
+    /// meeting.stop();
+    /// assert!(meeting.ended_at() >= meeting.started_at());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// The time of the last stop, or `None` if the meeting is running or
+    /// never stopped.
+    ///
+    /// # See Also
+    /// * [`Meeting::started_at`]
+    #[must_use]
+    pub fn ended_at(&self) -> Option<SystemTime> {
+        if self.is_running() {
+            None
+        } else {
+            self.ended_at
+        }
+    }
+
+    /// Checks whether the meeting is currently running.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::Meeting;
+    /// let mut meeting = Meeting::new();
+    /// meeting.start();
+    /// assert!(meeting.is_running());
+    /// meeting.stop();
+    /// assert!(!meeting.is_running());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// `true` if the meeting is running.
+    ///
+    /// # See Also
+    /// * [`Meeting::start`]
+    /// * [`Meeting::stop`]
+    #[must_use]
+    pub fn is_running(&self) -> bool {
+        (self.running
+            || self
+                .scheduled_start
+                .is_some_and(|at| self.clock.now() >= at))
+            && !self.is_auto_stopped()
+    }
+
+    /// Computes the duration since the meeting was started if it is running.
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// The current running duration.
+    ///
+    /// # See Also
+    /// * [`Meeting::duration`]
+    fn current_duration(&self) -> Duration {
+        if self.running {
+            self.start_time.map_or(Duration::ZERO, |t| {
+                self.clock.now().saturating_duration_since(t)
+            })
+        } else {
+            self.scheduled_start.map_or(Duration::ZERO, |at| {
+                self.clock.now().saturating_duration_since(at)
+            })
+        }
+    }
+}
+
+impl Default for Meeting {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Copies the meeting, including its timer.
+///
+/// A clone of a running meeting keeps running from the same start instant on
+/// the same clock, so both report the same duration until one of them is
+/// stopped or edited. Subscribers are not copied; the clone starts with none.
+///
+/// ## Example
+/// ```
+/// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+/// let mut meeting = Meeting::new();
+/// meeting.add_attendee(&EmployeeCategory::new("Dev", 120_000).unwrap(), 2);
+/// let copy = meeting.clone();
+/// assert_eq!(copy, meeting);
+/// ```
+impl Clone for Meeting {
+    fn clone(&self) -> Self {
+        Self {
+            attendees: self.attendees.clone(),
+            start_time: self.start_time,
+            elapsed: self.elapsed,
+            running: self.running,
+            scheduled_start: self.scheduled_start,
+            max_duration: self.max_duration,
+            budget: self.budget,
+            title: self.title.clone(),
+            overhead: self.overhead,
+            work_hours_per_year: self.work_hours_per_year,
+            fired_thresholds: self.fired_thresholds.clone(),
+            undo: self.undo.clone(),
+            cost_model: self.cost_model,
+            roster_log: self.roster_log.clone(),
+            people: self.people.clone(),
+            subscribers: Vec::new(),
+            strict_duplicates: self.strict_duplicates,
+            started_at: self.started_at,
+            ended_at: self.ended_at,
+            wall_clock_resume: self.wall_clock_resume,
+            approximate_groups: self.approximate_groups.clone(),
+            salary_ranges: self.salary_ranges.clone(),
+            segments: self.segments.clone(),
+            clock: Arc::clone(&self.clock),
+        }
+    }
+}
+
+/// Compares what the meeting bills for rather than its live timer.
+///
+/// Two meetings are equal when they have the same attendees, named people,
+/// roster history, approximate groups, salary ranges, agenda segments, cost
+/// settings, limits and title, the same time accumulated by earlier runs, and
+/// are both running or both stopped. The instant at which a running meeting
+/// was last started is ignored, so two running meetings that are equal now
+/// can report different durations. Undo history, fired thresholds,
+/// wall-clock timestamps and the [wall-clock resume](Meeting::set_wall_clock_resume)
+/// setting, subscribers, a pending scheduled start and the clock are also
+/// ignored.
+///
+/// ## Example
+/// ```
+/// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+/// let dev = EmployeeCategory::new("Dev", 120_000).unwrap();
+/// let mut a = Meeting::new();
+/// let mut b = Meeting::new();
+/// a.add_attendee(&dev, 1);
+/// b.add_attendee(&dev, 1);
+/// assert_eq!(a, b);
+/// b.add_attendee(&dev, 1);
+/// assert_ne!(a, b);
+/// ```
+impl PartialEq for Meeting {
+    fn eq(&self, other: &Self) -> bool {
+        self.attendees == other.attendees
+            && self.people == other.people
+            && self.roster_log == other.roster_log
+            && self.elapsed == other.elapsed
+            && self.running == other.running
+            && self.cost_model == other.cost_model
+            && self.overhead == other.overhead
+            && self.work_hours_per_year == other.work_hours_per_year
+            && self.budget == other.budget
+            && self.max_duration == other.max_duration
+            && self.title == other.title
+            && self.strict_duplicates == other.strict_duplicates
+            && self.approximate_groups == other.approximate_groups
+            && self.salary_ranges == other.salary_ranges
+            && self.segments == other.segments
+    }
+}
+
+/// Projects the yearly cost of a meeting that recurs with the same roster.
+///
+/// ## Example
+/// ```
+/// use meeting_cost_tracker::annualized_recurring_cost;
+/// assert_eq!(annualized_recurring_cost(150.0, 52), 7_800.0);
+/// ```
+///
+/// # Arguments
+///
+/// * `single_meeting_cost` - Cost of one occurrence, e.g. from
+///   [`Meeting::total_cost`].
+/// * `occurrences_per_year` - How often the meeting is held in a year, e.g.
+///   `52` for weekly or `26` for every other week.
+///
+/// # Returns
+///
+/// The cost of all occurrences in a year.
+#[must_use]
+pub fn annualized_recurring_cost(single_meeting_cost: f64, occurrences_per_year: u32) -> f64 {
+    single_meeting_cost * f64::from(occurrences_per_year)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::EmployeeCategory;
+    use crate::testing::{
+        assert_cost_eq, director, engineer, guest, manager, ManualClock, MeetingFixture, CENT,
+    };
+
+    fn sample_category() -> EmployeeCategory {
+        EmployeeCategory::new("dev", 120_000).unwrap()
+    }
+
+    #[test]
+    fn new_starts_empty() {
+        let meeting = Meeting::new();
+        assert!(!meeting.is_running());
+        assert_eq!(meeting.attendees().count(), 0);
+        assert_eq!(meeting.duration(), Duration::ZERO);
+    }
+
+    #[test]
+    fn default_equivalent_to_new() {
+        let a = Meeting::new();
+        let b = Meeting::default();
+        assert_eq!(a.is_running(), b.is_running());
+        assert_eq!(a.attendees().count(), b.attendees().count());
+    }
+
+    #[test]
+    fn add_and_remove_attendees_respects_counts() {
+        let cat = sample_category();
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&cat, 2);
+        meeting.add_attendee(&cat, 1);
+        assert_eq!(meeting.attendees().next().unwrap().2, &3);
+        meeting.remove_attendee(cat.title(), 1);
+        assert_eq!(meeting.attendees().next().unwrap().2, &2);
+        meeting.remove_attendee(cat.title(), 5);
+        assert!(meeting.attendees().next().is_none());
+    }
+
+    #[test]
+    fn start_stop_and_duration() {
+        let clock = ManualClock::new();
+        let mut meeting = Meeting::with_clock(clock.clone());
+        meeting.start();
+        clock.advance(Duration::from_millis(10));
+        meeting.start(); // should have no effect
+        clock.advance(Duration::from_millis(10));
+        meeting.stop();
+        let first = meeting.duration();
+        clock.advance(Duration::from_millis(10));
+        meeting.stop();
+        assert_eq!(meeting.duration(), first);
+        assert_eq!(first, Duration::from_millis(20));
+    }
+
+    #[test]
+    fn manual_clock_gives_exact_costs() {
+        let clock = ManualClock::new();
+        let mut meeting = Meeting::with_clock(clock.clone());
+        meeting.add_attendee(&sample_category(), 2);
+        meeting.start();
+        clock.advance(Duration::from_mins(15));
+        assert_eq!(meeting.total_cost_cents(), 3_000);
+        meeting.stop();
+        clock.advance(Duration::from_mins(5));
+        assert_eq!(meeting.duration(), Duration::from_mins(15));
+        meeting.start();
+        clock.advance(Duration::from_mins(5));
+        assert_cost_eq(meeting.total_cost(), 40.0, 1e-9);
+    }
+
+    #[test]
+    fn total_cost_accumulates() {
+        let (meeting, clock) = MeetingFixture::new()
+            .attendees(&sample_category(), 1)
+            .run_for(Duration::from_millis(10))
+            .running()
+            .build();
+        assert_eq!(meeting.total_cost_cents(), 0);
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(meeting.total_cost_cents(), 2);
+        assert_cost_eq(meeting.total_cost(), 0.02, 1e-12);
+    }
+
+    #[test]
+    fn total_cost_rounds_half_cents_like_cents() {
+        // Seven and a half seconds at $1 a minute is exactly half a cent over
+        // $0.12, which a float would print as 0.12.
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&sample_category(), 1);
+        meeting.set_elapsed(Duration::from_millis(7_500));
+        assert_eq!(meeting.total_cost_cents(), 13);
+        assert_eq!(format!("{:.2}", meeting.total_cost()), "0.13");
+        let row = &meeting.breakdown()[0];
+        assert_eq!(row.cost_cents, 13);
+        assert_eq!(format!("{:.2}", row.cost), "0.13");
+    }
+
+    #[test]
+    fn reset_clears_state() {
+        let (mut meeting, _clock) = MeetingFixture::new()
+            .attendees(&sample_category(), 1)
+            .run_for(Duration::from_millis(5))
+            .build();
+        meeting.reset();
+        assert_eq!(meeting.attendees().count(), 0);
+        assert!(!meeting.is_running());
+        assert_cost_eq(meeting.total_cost(), 0.0, 0.0);
+    }
+
+    #[test]
+    fn armed_meeting_starts_after_delay() {
+        let clock = ManualClock::new();
+        let mut meeting = Meeting::with_clock(clock.clone());
+        meeting.arm_start_in(Duration::from_millis(50)).unwrap();
+        assert!(meeting.is_armed());
+        assert!(!meeting.is_running());
+        assert_eq!(meeting.duration(), Duration::ZERO);
+        assert_eq!(meeting.time_until_start(), Some(Duration::from_millis(50)));
+
+        clock.advance(Duration::from_millis(80));
+        assert!(!meeting.is_armed());
+        assert!(meeting.is_running());
+        assert_eq!(meeting.time_until_start(), None);
+        assert_eq!(meeting.duration(), Duration::from_millis(30));
+
+        meeting.stop();
+        assert!(!meeting.is_running());
+        assert_eq!(meeting.duration(), Duration::from_millis(30));
+        clock.advance(Duration::from_millis(20));
+        assert_eq!(meeting.duration(), Duration::from_millis(30));
+    }
+
+    #[test]
+    fn stop_disarms_and_start_overrides_schedule() {
+        let clock = ManualClock::new();
+        let mut meeting = Meeting::with_clock(clock.clone());
+        meeting.arm_start_in(Duration::from_millis(30)).unwrap();
+        meeting.stop();
+        assert!(!meeting.is_armed());
+        clock.advance(Duration::from_millis(50));
+        assert!(!meeting.is_running());
+        assert_eq!(meeting.duration(), Duration::ZERO);
+
+        meeting.arm_start_in(Duration::from_mins(1)).unwrap();
+        meeting.start();
+        assert!(meeting.is_running());
+        assert!(!meeting.is_armed());
+
+        meeting.reset();
+        meeting.arm_start_in(Duration::from_mins(1)).unwrap();
+        meeting.reset();
+        assert!(!meeting.is_armed());
+    }
+
+    #[test]
+    fn arming_past_the_clock_range_fails() {
+        let mut meeting = Meeting::with_clock(ManualClock::new());
+        assert_eq!(
+            meeting.arm_start_in(Duration::MAX),
+            Err(MeetingError::InvalidDelay)
+        );
+        assert!(!meeting.is_armed());
+    }
+
+    #[test]
+    fn max_duration_auto_stops() {
+        let cat = sample_category();
+        let max = Duration::from_millis(20);
+        let clock = ManualClock::new();
+        let mut meeting = Meeting::with_clock(clock.clone());
+        meeting.add_attendee(&cat, 1);
+        meeting.set_max_duration(max);
+        meeting.start();
+        assert!(meeting.is_running());
+        clock.advance(Duration::from_millis(19));
+        assert!(!meeting.is_auto_stopped());
+        clock.advance(Duration::from_millis(31));
+        assert!(meeting.is_auto_stopped());
+        assert!(!meeting.is_running());
+        assert_eq!(meeting.duration(), max);
+        let cost = meeting.total_cost();
+        clock.advance(Duration::from_millis(10));
+        assert_cost_eq(meeting.total_cost(), cost, 0.0);
+
+        meeting.stop();
+        assert_eq!(meeting.duration(), max);
+        meeting.clear_max_duration();
+        assert_eq!(meeting.duration(), max);
+        assert!(!meeting.is_auto_stopped());
+    }
+
+    #[test]
+    fn budget_eta_follows_burn_rate() {
+        let cat = sample_category();
+        let mut meeting = Meeting::new();
+        assert_eq!(meeting.time_until_budget_exhausted(), None);
+        meeting.set_budget(60.0).unwrap();
+        // No attendees means nothing is spent.
+        assert_eq!(meeting.time_until_budget_exhausted(), None);
+
+        meeting.add_attendee(&cat, 2);
+        meeting.elapsed = Duration::from_mins(10);
+        assert_eq!(meeting.remaining_budget(), Some(40.0));
+        assert_eq!(
+            meeting.time_until_budget_exhausted(),
+            Some(Duration::from_mins(20))
+        );
+        meeting.add_attendee(&cat, 2);
+        assert_eq!(
+            meeting.time_until_budget_exhausted(),
+            Some(Duration::from_mins(10))
+        );
+
+        meeting.elapsed = Duration::from_mins(30);
+        assert_eq!(meeting.time_until_budget_exhausted(), Some(Duration::ZERO));
+
+        assert_eq!(
+            meeting.set_budget(f64::NAN).unwrap_err().to_string(),
+            "Budget must be a non-negative amount, got NaN"
+        );
+        meeting.clear_budget();
+        assert_eq!(meeting.time_until_budget_exhausted(), None);
+    }
+
+    #[test]
+    fn unused_categories_skip_attendee_groups() {
+        let all: Vec<EmployeeCategory> = ["Dev", "PM", "QA", "VP"]
+            .iter()
+            .map(|t| EmployeeCategory::new(*t, 100_000).unwrap())
+            .collect();
+        let mut meeting = Meeting::new();
+        assert_eq!(meeting.unused_categories(&all).len(), 4);
+        meeting.add_attendee(&all[0], 2);
+        meeting.add_attendee(&all[2], 1);
+        let titles: Vec<&str> = meeting
+            .unused_categories(&all)
+            .into_iter()
+            .map(EmployeeCategory::title)
+            .collect();
+        assert_eq!(titles, ["PM", "VP"]);
+        meeting.remove_attendee("QA", 1);
+        assert_eq!(meeting.unused_categories(&all).len(), 3);
+    }
+
+    #[test]
+    fn budget_eta_at_exact_budget() {
+        let cat = sample_category();
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&cat, 1);
+        meeting.set_budget(15.0).unwrap();
+        meeting.elapsed = Duration::from_mins(14);
+        assert_eq!(
+            meeting.time_until_budget_exhausted(),
+            Some(Duration::from_mins(1))
+        );
+        assert_eq!(
+            meeting.budget_exhaustion_eta(),
+            meeting.time_until_budget_exhausted()
+        );
+        // Spending exactly the budget leaves no time but is not yet over it.
+        meeting.elapsed = Duration::from_mins(15);
+        assert_eq!(meeting.remaining_budget(), Some(0.0));
+        assert!(!meeting.is_over_budget());
+        assert_eq!(meeting.time_until_budget_exhausted(), Some(Duration::ZERO));
+        meeting.elapsed += Duration::from_secs(1);
+        assert!(meeting.is_over_budget());
+        assert_eq!(meeting.time_until_budget_exhausted(), Some(Duration::ZERO));
+
+        meeting.clear_budget();
+        assert_eq!(meeting.time_until_budget_exhausted(), None);
+        let mut empty = Meeting::new();
+        empty.set_budget(0.0).unwrap();
+        assert_eq!(empty.time_until_budget_exhausted(), None);
+
+        // Over budget with everyone gone, nothing more is being spent.
+        meeting.set_budget(15.0).unwrap();
+        meeting.remove_attendee("dev", 1);
+        assert!(meeting.is_over_budget());
+        assert_eq!(meeting.time_until_budget_exhausted(), None);
+    }
+
+    #[test]
+    fn zero_counts_are_ignored_or_rejected() {
+        let cat = sample_category();
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&cat, 0);
+        assert!(meeting.is_empty());
+        assert_eq!(meeting.attendee_count("dev"), None);
+        assert_eq!(
+            meeting.try_add_attendee(&cat, 0),
+            Err(MeetingError::InvalidCount)
+        );
+        assert!(!meeting.undo_last_attendee_change());
+    }
+
+    #[test]
+    fn adding_max_twice_never_wraps() {
+        let cat = sample_category();
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&cat, u32::MAX);
+        meeting.add_attendee(&cat, u32::MAX);
+        assert_eq!(meeting.attendee_count("dev"), Some(u32::MAX));
+
+        let mut meeting = Meeting::new();
+        assert_eq!(meeting.try_add_attendee(&cat, u32::MAX), Ok(u32::MAX));
+        assert_eq!(
+            meeting.try_add_attendee(&cat, u32::MAX),
+            Err(MeetingError::TooManyAttendees("dev".into()))
+        );
+        assert_eq!(
+            meeting.add_person("Alice", &cat),
+            Err(MeetingError::TooManyAttendees("dev".into()))
+        );
+        assert_eq!(meeting.attendee_count("dev"), Some(u32::MAX));
+        assert_eq!(meeting.people().count(), 0);
+    }
+
+    #[test]
+    fn current_duration_only_when_running() {
+        let (mut meeting, clock) = MeetingFixture::new().build();
+        assert_eq!(meeting.current_duration(), Duration::ZERO);
+        meeting.start();
+        clock.advance(Duration::from_millis(5));
+        assert_eq!(meeting.current_duration(), Duration::from_millis(5));
+    }
+
+    #[test]
+    fn attendee_count_works() {
+        let cat = sample_category();
+        let mut meeting = Meeting::new();
+        assert_eq!(meeting.attendee_count(cat.title()), None);
+        meeting.add_attendee(&cat, 2);
+        assert_eq!(meeting.attendee_count(cat.title()), Some(2));
+    }
+
+    #[test]
+    fn clear_attendees_leaves_timing() {
+        let (mut meeting, _clock) = MeetingFixture::new()
+            .attendees(&sample_category(), 1)
+            .run_for(Duration::from_millis(5))
+            .build();
+        meeting.clear_attendees();
+        assert_eq!(meeting.attendees().count(), 0);
+        assert_eq!(meeting.duration(), Duration::from_millis(5));
+    }
+
+    #[test]
+    fn work_hours_default_and_validation() {
+        let mut meeting = Meeting::new();
+        assert!((meeting.work_hours_per_year() - DEFAULT_WORK_HOURS_PER_YEAR).abs() < f64::EPSILON);
+        assert_eq!(
+            meeting.set_work_hours(-1.0),
+            Err(MeetingError::InvalidWorkHours(-1.0))
+        );
+        assert!(meeting.set_work_hours(f64::NAN).is_err());
+        assert!(Meeting::with_work_hours(0.0).is_err());
+        assert!((meeting.work_hours_per_year() - DEFAULT_WORK_HOURS_PER_YEAR).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn total_cost_respects_work_hours() {
+        let cat = EmployeeCategory::new("dev", 176_000).unwrap();
+        let mut meeting = Meeting::with_work_hours(1760.0).unwrap();
+        meeting.add_attendee(&cat, 1);
+        meeting.elapsed = Duration::from_hours(1);
+        assert_cost_eq(meeting.total_cost(), 100.0, 1e-9);
+
+        let cat = EmployeeCategory::new("dev", 208_000).unwrap();
+        let mut meeting = Meeting::with_work_hours(2080.0).unwrap();
+        meeting.add_attendee(&cat, 2);
+        meeting.elapsed = Duration::from_mins(30);
+        assert_cost_eq(meeting.total_cost(), 100.0, 1e-9);
+
+        meeting.set_work_hours(1040.0).unwrap();
+        assert_cost_eq(meeting.total_cost(), 200.0, 1e-9);
+    }
+
+    #[test]
+    fn restore_roster_keeps_timing() {
+        let cat = sample_category();
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&cat, 1);
+        let snapshot = meeting.roster_snapshot();
+        meeting.start();
+        meeting.clear_attendees();
+        meeting.add_attendee(&EmployeeCategory::new("exec", 500_000).unwrap(), 40);
+        meeting.restore_roster(snapshot);
+        assert!(meeting.is_running());
+        assert_eq!(meeting.attendees().count(), 1);
+        assert_eq!(meeting.attendee_count(cat.title()), Some(1));
+    }
+
+    #[test]
+    fn swap_attendees_while_running() {
+        let dev = sample_category();
+        let qa = EmployeeCategory::new("qa", 80_000).unwrap();
+        let pm = EmployeeCategory::new("pm", 110_000).unwrap();
+        let (mut meeting, _clock) = MeetingFixture::new()
+            .attendees(&dev, 2)
+            .attendees(&qa, 1)
+            .run_for(Duration::from_millis(5))
+            .running()
+            .build();
+        let previous = meeting.swap_attendees(&[(pm.clone(), 3)]);
+        assert!(meeting.is_running());
+        assert_eq!(meeting.duration(), Duration::from_millis(5));
+        assert_eq!(
+            previous,
+            vec![
+                AttendeeInfo {
+                    title: "dev".into(),
+                    count: 2,
+                    name: None,
+                    salary: None,
+                    attention: 1.0,
+                },
+                AttendeeInfo {
+                    title: "qa".into(),
+                    count: 1,
+                    name: None,
+                    salary: None,
+                    attention: 1.0,
+                },
+            ]
+        );
+        assert_eq!(meeting.attendees().count(), 1);
+        assert_eq!(meeting.attendee_count(pm.title()), Some(3));
+    }
+
+    #[test]
+    fn headcount_and_salary_totals() {
+        let mut meeting = Meeting::new();
+        assert_eq!(meeting.total_attendees(), 0);
+        assert_eq!(meeting.total_annual_salary(), 0);
+        meeting.add_attendee(&sample_category(), 2);
+        meeting.add_attendee(&EmployeeCategory::new("qa", 80_000).unwrap(), 3);
+        assert_eq!(meeting.total_attendees(), 5);
+        assert_eq!(meeting.total_annual_salary(), 480_000);
+    }
+
+    #[test]
+    fn is_empty_tracks_attendees() {
+        let cat = sample_category();
+        let mut meeting = Meeting::new();
+        assert!(meeting.is_empty());
+        meeting.add_attendee(&cat, 2);
+        assert!(!meeting.is_empty());
+        meeting.remove_attendee(cat.title(), 2);
+        assert!(meeting.is_empty());
+    }
+
+    #[test]
+    fn headcount_and_salary_totals_saturate() {
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&EmployeeCategory::new("a", u64::MAX).unwrap(), u32::MAX);
+        meeting.add_attendee(&EmployeeCategory::new("b", 2).unwrap(), 1);
+        assert_eq!(meeting.total_attendees(), u32::MAX);
+        assert_eq!(meeting.total_annual_salary(), u64::MAX);
+    }
+
+    #[test]
+    fn try_remove_attendee_reports_removed_count() {
+        let cat = sample_category();
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&cat, 3);
+        assert_eq!(meeting.try_remove_attendee(cat.title(), 1), Ok(1));
+        assert_eq!(meeting.attendee_count(cat.title()), Some(2));
+        assert_eq!(meeting.try_remove_attendee(cat.title(), 10), Ok(2));
+        assert_eq!(meeting.attendee_count(cat.title()), None);
+        assert_eq!(
+            meeting.try_remove_attendee("typo", 3),
+            Err(MeetingError::AttendeeNotFound("typo".into()))
+        );
+    }
+
+    #[test]
+    fn attribute_splits_total_cost() {
+        // $1 per minute for 100 minutes.
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&EmployeeCategory::new("dev", 120_000).unwrap(), 1);
+        meeting.elapsed = Duration::from_mins(100);
+        let (shared, direct) = meeting.attribute(0.0).unwrap();
+        assert_cost_eq(shared, 0.0, 1e-9);
+        assert_cost_eq(direct, 100.0, 1e-9);
+        let (shared, direct) = meeting.attribute(0.5).unwrap();
+        assert_cost_eq(shared, 50.0, 1e-9);
+        assert_cost_eq(direct, 50.0, 1e-9);
+        let (shared, direct) = meeting.attribute(1.0).unwrap();
+        assert_cost_eq(shared, 100.0, 1e-9);
+        assert_cost_eq(direct, 0.0, 1e-9);
+    }
+
+    #[test]
+    fn attribute_rejects_invalid_fractions() {
+        let meeting = Meeting::new();
+        assert_eq!(
+            meeting.attribute(-0.1),
+            Err(MeetingError::InvalidFraction(-0.1))
+        );
+        assert!(meeting.attribute(1.01).is_err());
+        assert!(meeting.attribute(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn half_attention_group_costs_half() {
+        let mut full = Meeting::new();
+        full.add_attendee(&sample_category(), 2);
+        full.elapsed = Duration::from_mins(10);
+        let mut half = Meeting::new();
+        assert_eq!(
+            half.add_attendee_attention(&sample_category(), 2, 0.5),
+            Ok(2)
+        );
+        half.elapsed = Duration::from_mins(10);
+        assert_eq!(full.total_cost_cents(), 2_000);
+        assert_eq!(half.total_cost_cents(), 1_000);
+        assert_cost_eq(half.total_cost(), 10.0, 1e-9);
+        assert_cost_eq(half.burn_rate_per_minute(), 1.0, 1e-9);
+        assert_eq!(half.breakdown()[0].cost_cents, 1_000);
+        assert_eq!(half.attention("dev"), Some(0.5));
+    }
+
+    #[test]
+    fn attention_only_scales_its_own_group() {
+        let observer = EmployeeCategory::new("observer", 120_000).unwrap();
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&sample_category(), 1);
+        meeting.add_attendee_attention(&observer, 1, 0.25).unwrap();
+        meeting.elapsed = Duration::from_mins(20);
+        assert_eq!(meeting.total_cost_cents(), 2_500);
+        assert_eq!(meeting.attention("dev"), Some(1.0));
+        assert_eq!(meeting.attention("missing"), None);
+    }
+
+    #[test]
+    fn add_attendee_attention_rejects_invalid_input() {
+        let mut meeting = Meeting::new();
+        let dev = sample_category();
+        assert_eq!(
+            meeting.add_attendee_attention(&dev, 1, 0.0),
+            Err(MeetingError::InvalidFraction(0.0))
+        );
+        assert!(meeting.add_attendee_attention(&dev, 1, 1.5).is_err());
+        assert!(meeting.add_attendee_attention(&dev, 1, f64::NAN).is_err());
+        assert_eq!(
+            meeting.add_attendee_attention(&dev, 0, 0.5),
+            Err(MeetingError::InvalidCount)
+        );
+        assert_eq!(meeting.total_attendees(), 0);
+    }
+
+    #[test]
+    fn cost_series_shows_late_joiners() {
+        let lead = EmployeeCategory::new("lead", 240_000).unwrap();
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&sample_category(), 1);
+        meeting.elapsed = Duration::from_mins(2);
+        meeting.add_attendee(&lead, 1);
+        meeting.elapsed = Duration::from_mins(4);
+        let series = meeting.category_cost_series(4, 5);
+        assert_eq!(series.len(), 2);
+        assert_eq!(series[0], ("lead".to_string(), vec![0.0, 0.0, 2.0, 4.0]));
+        assert_eq!(series[1], ("dev".to_string(), vec![1.0, 2.0, 3.0, 4.0]));
+    }
+
+    #[test]
+    fn cost_series_is_limited_to_top_groups() {
+        let lead = EmployeeCategory::new("lead", 240_000).unwrap();
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&sample_category(), 1);
+        meeting.add_attendee(&lead, 1);
+        meeting.elapsed = Duration::from_mins(1);
+        let series = meeting.category_cost_series(3, 1);
+        assert_eq!(series.len(), 1);
+        assert_eq!(series[0].0, "lead");
+        assert_eq!(series[0].1.len(), 3);
+        assert!(meeting.category_cost_series(3, 0).is_empty());
+        assert!(Meeting::new().category_cost_series(3, 5).is_empty());
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn annualized_cost_for_weekly_and_biweekly() {
+        assert_eq!(annualized_recurring_cost(250.0, 52), 13_000.0);
+        assert_eq!(annualized_recurring_cost(250.0, 26), 6_500.0);
+        assert_eq!(annualized_recurring_cost(250.0, 0), 0.0);
+
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&sample_category(), 3);
+        meeting.elapsed = Duration::from_mins(30);
+        assert_cost_eq(
+            annualized_recurring_cost(meeting.total_cost(), 52),
+            4_680.0,
+            1e-9,
+        );
+    }
+
+    #[test]
+    fn observers_cost_nothing_and_count_apart() {
+        let guest = EmployeeCategory::observer("customer").unwrap();
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&sample_category(), 8);
+        meeting.add_attendee(&guest, 2);
+        meeting.elapsed = Duration::from_mins(10);
+        assert_eq!(meeting.total_attendees(), 8);
+        assert_eq!(meeting.observer_count(), 2);
+        assert_eq!(meeting.total_headcount(), 10);
+        assert_eq!(meeting.total_cost_cents(), 8_000);
+        assert_cost_eq(meeting.burn_rate_per_minute(), 8.0, 1e-9);
+        assert_cost_eq(meeting.cost_per_attendee(), 10.0, 1e-9);
+        let customer = meeting
+            .breakdown()
+            .into_iter()
+            .find(|row| row.title == "customer")
+            .unwrap();
+        assert_eq!((customer.count, customer.cost_cents), (2, 0));
+    }
+
+    #[test]
+    fn total_headcount_saturates_with_observers() {
+        let guest = EmployeeCategory::observer("customer").unwrap();
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&sample_category(), u32::MAX);
+        meeting.add_attendee(&guest, 3);
+        assert_eq!(meeting.total_attendees(), u32::MAX);
+        assert_eq!(meeting.observer_count(), 3);
+        assert_eq!(meeting.total_headcount(), u32::MAX);
+    }
+
+    #[test]
+    fn set_attendee_count_creates_updates_and_removes() {
+        let mut meeting = Meeting::new();
+        let dev = sample_category();
+        assert_eq!(meeting.set_attendee_count(&dev, 3), 0);
+        assert_eq!(meeting.attendee_count("dev"), Some(3));
+        assert_eq!(meeting.set_attendee_count(&dev, 7), 3);
+        assert_eq!(meeting.set_attendee_count(&dev, 7), 7);
+        assert_eq!(meeting.set_attendee_count(&dev, 0), 7);
+        assert_eq!(meeting.attendee_count("dev"), None);
+        assert_eq!(meeting.set_attendee_count(&dev, 0), 0);
+        assert!(meeting.undo_last_attendee_change());
+        assert_eq!(meeting.attendee_count("dev"), Some(7));
+    }
+
+    #[test]
+    fn set_attendee_count_keeps_stored_salary_and_trims_people() {
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&sample_category(), 1);
+        meeting.add_person("Zed", &sample_category()).unwrap();
+        meeting.add_person("Amy", &sample_category()).unwrap();
+        let repriced = EmployeeCategory::new("dev", 240_000).unwrap();
+        assert_eq!(meeting.set_attendee_count(&repriced, 1), 3);
+        assert_eq!(meeting.attendees().next().unwrap().1, 120_000);
+        assert_eq!(meeting.people().count(), 1);
+        meeting.elapsed = Duration::from_mins(1);
+        assert_eq!(meeting.total_cost_cents(), 100);
+    }
+
+    #[test]
+    fn cost_snapshot_matches_expected_struct() {
+        let clock = ManualClock::new();
+        let lead = EmployeeCategory::new("lead", 240_000).unwrap();
+        let mut meeting = Meeting::with_clock(clock.clone());
+        meeting.add_attendee(&sample_category(), 3);
+        meeting.add_attendee(&lead, 1);
+        meeting.start();
+        clock.advance(Duration::from_mins(5));
+        assert_eq!(
+            meeting.snapshot_cost(),
+            CostSnapshot {
+                duration: Duration::from_mins(5),
+                total: 25.0,
+                per_category: vec![("dev".to_string(), 15.0), ("lead".to_string(), 10.0)],
+            }
+        );
+    }
+
+    #[test]
+    fn elapsed_millis_matches_duration() {
+        let clock = ManualClock::new();
+        let mut meeting = Meeting::with_clock(clock.clone());
+        assert_eq!(meeting.elapsed_millis(), 0);
+        meeting.start();
+        clock.advance(Duration::from_micros(2_345_678));
+        assert_eq!(meeting.elapsed_millis(), meeting.duration().as_millis());
+        assert_eq!(meeting.elapsed_millis(), 2_345);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn cost_excluding_skips_named_groups() {
+        let director = EmployeeCategory::new("director", 480_000).unwrap();
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&sample_category(), 2);
+        meeting.add_attendee(&director, 3);
+        meeting.elapsed = Duration::from_mins(10);
+        assert_eq!(meeting.total_cost(), 140.0);
+        assert_eq!(meeting.cost_excluding(["director"]), 20.0);
+        assert_eq!(meeting.cost_excluding(["director", "unknown"]), 20.0);
+        assert_eq!(meeting.cost_excluding([]), 140.0);
+        assert_eq!(meeting.cost_excluding(["dev", "director"]), 0.0);
+        assert_eq!(meeting.savings_if_removed("director"), 120.0);
+        assert_eq!(meeting.savings_if_removed("unknown"), 0.0);
+    }
+
+    #[test]
+    fn per_head_cap_below_and_above() {
+        // Two attendees at $1 per minute each for 30 minutes: $30 per head.
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&EmployeeCategory::new("dev", 120_000).unwrap(), 2);
+        meeting.elapsed = Duration::from_mins(30);
+        assert_cost_eq(meeting.cost_per_attendee(), 30.0, 1e-9);
+        assert!(meeting.exceeds_per_head(25.0));
+        assert!(!meeting.exceeds_per_head(30.0));
+        assert!(!meeting.exceeds_per_head(50.0));
+    }
+
+    #[test]
+    fn per_head_cap_ignores_empty_meeting() {
+        let mut meeting = Meeting::new();
+        meeting.elapsed = Duration::from_mins(30);
+        assert_cost_eq(meeting.cost_per_attendee(), 0.0, f64::EPSILON);
+        assert!(!meeting.exceeds_per_head(-1.0));
+    }
+
+    #[test]
+    fn thresholds_fire_exactly_once() {
+        // $1 per minute.
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&EmployeeCategory::new("dev", 120_000).unwrap(), 1);
+        let thresholds = [10.0, 50.0, 100.0];
+        assert!(meeting.check_thresholds(&thresholds).is_empty());
+
+        meeting.elapsed = Duration::from_mins(10);
+        assert!(meeting.cost_threshold_crossed(10.0));
+        assert_eq!(meeting.check_thresholds(&thresholds), vec![10.0]);
+        assert!(meeting.check_thresholds(&thresholds).is_empty());
+
+        meeting.elapsed = Duration::from_hours(2);
+        assert_eq!(meeting.check_thresholds(&thresholds), vec![50.0, 100.0]);
+        assert!(meeting.check_thresholds(&thresholds).is_empty());
+        assert!(meeting.cost_threshold_crossed(100.0));
+        assert!(!meeting.cost_threshold_crossed(1000.0));
+    }
+
+    #[test]
+    fn reset_rearms_thresholds() {
+        let mut meeting = Meeting::new();
+        assert_eq!(meeting.check_thresholds(&[0.0]), vec![0.0]);
+        meeting.reset();
+        assert_eq!(meeting.check_thresholds(&[0.0]), vec![0.0]);
+    }
+
+    #[test]
+    fn undo_add_restores_previous_count() {
+        let cat = sample_category();
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&cat, 2);
+        meeting.add_attendee(&cat, 3);
+        assert!(meeting.undo_last_attendee_change());
+        assert_eq!(meeting.attendee_count(cat.title()), Some(2));
+        assert!(meeting.undo_last_attendee_change());
+        assert_eq!(meeting.attendee_count(cat.title()), None);
+    }
+
+    #[test]
+    fn undo_remove_restores_deleted_group() {
+        let cat = sample_category();
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&cat, 4);
+        meeting.remove_attendee(cat.title(), 10);
+        assert!(meeting.is_empty());
+        assert!(meeting.undo_last_attendee_change());
+        assert_eq!(meeting.attendee_count(cat.title()), Some(4));
+    }
+
+    #[test]
+    fn undo_past_empty_stack_does_nothing() {
+        let cat = sample_category();
+        let mut meeting = Meeting::new();
+        assert!(!meeting.undo_last_attendee_change());
+        meeting.add_attendee(&cat, 1);
+        meeting.remove_attendee("unknown", 1);
+        assert!(meeting.undo_last_attendee_change());
+        assert!(!meeting.undo_last_attendee_change());
+        assert!(meeting.is_empty());
+    }
+
+    #[test]
+    fn undo_history_is_bounded() {
+        let cat = sample_category();
+        let mut meeting = Meeting::new();
+        for _ in 0..UNDO_LIMIT + 5 {
+            meeting.add_attendee(&cat, 1);
+        }
+        let mut undone = 0;
+        while meeting.undo_last_attendee_change() {
+            undone += 1;
+        }
+        assert_eq!(undone, UNDO_LIMIT);
+        assert_eq!(meeting.attendee_count(cat.title()), Some(5));
+    }
+
+    #[test]
+    fn time_weighted_bills_late_joiners_from_join_time() {
+        // $1 per minute per attendee.
+        let dev = EmployeeCategory::new("dev", 120_000).unwrap();
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&dev, 1);
+        meeting.elapsed = Duration::from_mins(30);
+        meeting.add_attendee(&dev, 1);
+        meeting.elapsed = Duration::from_hours(1);
+        assert_cost_eq(meeting.total_cost(), 90.0, 1e-9);
+
+        meeting.set_cost_model(CostModel::FlatFromStart);
+        assert_cost_eq(meeting.total_cost(), 120.0, 1e-9);
+    }
+
+    #[test]
+    fn time_weighted_keeps_cost_of_departed_groups() {
+        let dev = EmployeeCategory::new("dev", 120_000).unwrap();
+        let pm = EmployeeCategory::new("pm", 240_000).unwrap();
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&dev, 2);
+        meeting.add_attendee(&pm, 1);
+        meeting.elapsed = Duration::from_mins(10);
+        meeting.remove_attendee(pm.title(), 1);
+        meeting.elapsed = Duration::from_mins(20);
+        meeting.clear_attendees();
+        meeting.elapsed = Duration::from_mins(45);
+        // pm: 2 * 10, dev: 2 * 20.
+        assert_cost_eq(meeting.total_cost(), 60.0, 1e-9);
+
+        meeting.set_cost_model(CostModel::FlatFromStart);
+        assert_cost_eq(meeting.total_cost(), 0.0, f64::EPSILON);
+    }
+
+    #[test]
+    fn time_weighted_across_stop_start_cycles() {
+        let dev = sample_category();
+        let clock = ManualClock::new();
+        let mut meeting = Meeting::with_clock(clock.clone());
+        meeting.add_attendee(&dev, 1);
+        meeting.start();
+        clock.advance(Duration::from_mins(20));
+        meeting.stop();
+        clock.advance(Duration::from_mins(5));
+        meeting.add_attendee(&dev, 1);
+        meeting.start();
+        clock.advance(Duration::from_mins(20));
+        meeting.stop();
+        assert_eq!(meeting.duration(), Duration::from_mins(40));
+        assert_cost_eq(meeting.total_cost(), 60.0, 1e-9);
+    }
+
+    #[test]
+    fn undo_and_restore_are_billed_from_change_time() {
+        let dev = EmployeeCategory::new("dev", 120_000).unwrap();
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&dev, 1);
+        let snapshot = meeting.roster_snapshot();
+        meeting.add_attendee(&dev, 9);
+        meeting.elapsed = Duration::from_mins(1);
+        assert!(meeting.undo_last_attendee_change());
+        meeting.elapsed = Duration::from_mins(2);
+        assert_cost_eq(meeting.total_cost(), 11.0, 1e-9);
+
+        meeting.swap_attendees(&[(dev.clone(), 5)]);
+        meeting.elapsed = Duration::from_mins(3);
+        meeting.restore_roster(snapshot);
+        meeting.elapsed = Duration::from_mins(4);
+        assert_cost_eq(meeting.total_cost(), 17.0, 1e-9);
+    }
+
+    #[test]
+    fn people_share_counts_with_anonymous_attendees() {
+        let cat = sample_category();
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&cat, 2);
+        meeting.add_person("Alice", &cat).unwrap();
+        meeting.add_person("Bob", &cat).unwrap();
+        assert_eq!(meeting.attendee_count("dev"), Some(4));
+        assert_eq!(
+            meeting.add_person("Alice", &cat),
+            Err(MeetingError::PersonExists("Alice".into()))
+        );
+        meeting.elapsed = Duration::from_mins(10);
+        assert_cost_eq(meeting.total_cost(), 40.0, 1e-9);
+
+        assert_eq!(meeting.remove_person("Alice").unwrap(), "dev");
+        assert_eq!(meeting.attendee_count("dev"), Some(3));
+        assert_eq!(
+            meeting.remove_person("Alice"),
+            Err(MeetingError::PersonNotFound("Alice".into()))
+        );
+
+        // Anonymous removals only drop named people once no anonymous remain.
+        meeting.remove_attendee("dev", 2);
+        assert_eq!(meeting.people().collect::<Vec<_>>(), vec![("Bob", "dev")]);
+        meeting.remove_attendee("dev", 1);
+        assert_eq!(meeting.people().count(), 0);
+    }
+
+    #[test]
+    fn roster_entries_mix_named_and_counted() {
+        let dev = sample_category();
+        let qa = EmployeeCategory::new("qa", 60_000).unwrap();
+        let mut meeting = Meeting::new();
+        meeting.add_person("Bob", &dev).unwrap();
+        meeting.add_attendee(&dev, 2);
+        meeting.add_person("Alice", &dev).unwrap();
+        meeting.add_person("Carol", &qa).unwrap();
+        let entries = meeting.roster_entries();
+        let entries: Vec<(&str, u32, Option<&str>)> = entries
+            .iter()
+            .map(|e| (e.title.as_str(), e.count, e.name.as_deref()))
+            .collect();
+        assert_eq!(
+            entries,
+            vec![
+                ("dev", 2, None),
+                ("dev", 1, Some("Alice")),
+                ("dev", 1, Some("Bob")),
+                ("qa", 1, Some("Carol")),
+            ]
+        );
+        meeting.elapsed = Duration::from_mins(1);
+        // Four devs at $1/min and one qa at $0.50/min.
+        assert_cost_eq(meeting.total_cost(), 4.5, 1e-9);
+    }
+
+    #[test]
+    fn undo_restores_people() {
+        let cat = sample_category();
+        let mut meeting = Meeting::new();
+        meeting.add_person("Alice", &cat).unwrap();
+        meeting.remove_person("Alice").unwrap();
+        assert!(meeting.undo_last_attendee_change());
+        assert_eq!(meeting.people().collect::<Vec<_>>(), vec![("Alice", "dev")]);
+        assert!(meeting.undo_last_attendee_change());
+        assert_eq!(meeting.people().count(), 0);
+        assert!(meeting.is_empty());
+
+        meeting.add_person("Bob", &cat).unwrap();
+        let snapshot = meeting.roster_snapshot();
+        meeting.clear_attendees();
+        assert_eq!(meeting.people().count(), 0);
+        meeting.restore_roster(snapshot);
+        assert_eq!(meeting.people().collect::<Vec<_>>(), vec![("Bob", "dev")]);
+    }
+
+    #[test]
+    fn timeline_is_in_elapsed_order() {
+        let dev = sample_category();
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&dev, 2);
+        meeting.elapsed = Duration::from_mins(5);
+        meeting.add_person("Alice", &dev).unwrap();
+        meeting.elapsed = Duration::from_mins(12);
+        meeting.remove_attendee("dev", 3);
+
+        let timeline = meeting.timeline();
+        let times: Vec<Duration> = timeline.iter().map(TimelineEvent::at).collect();
+        assert_eq!(
+            times,
+            vec![
+                Duration::ZERO,
+                Duration::from_mins(5),
+                Duration::from_mins(12)
+            ]
+        );
+        let rates: Vec<f64> = timeline
+            .iter()
+            .map(|e| match e {
+                TimelineEvent::RateChange {
+                    burn_rate_per_minute,
+                    ..
+                } => *burn_rate_per_minute,
+            })
+            .collect();
+        assert_eq!(rates, vec![2.0, 3.0, 0.0]);
+
+        meeting.reset();
+        assert!(meeting.timeline().is_empty());
+    }
+
+    #[test]
+    fn salary_update_applies_to_subsequent_time() {
+        let cat = sample_category();
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&cat, 1);
+        meeting.elapsed = Duration::from_mins(10);
+        meeting.update_salary("dev", 240_000).unwrap();
+        meeting.elapsed = Duration::from_mins(20);
+        // 10 minutes at $1/min, then 10 minutes at $2/min.
+        assert_cost_eq(meeting.total_cost(), 30.0, 1e-9);
+        assert_eq!(meeting.breakdown()[0].salary, 240_000);
+
+        meeting.set_cost_model(CostModel::FlatFromStart);
+        assert_cost_eq(meeting.total_cost(), 40.0, 1e-9);
+
+        meeting.set_cost_model(CostModel::TimeWeighted);
+        assert!(meeting.undo_last_attendee_change());
+        assert_cost_eq(meeting.burn_rate_per_minute(), 1.0, 1e-9);
+        assert_eq!(
+            meeting.update_salary("pm", 1),
+            Err(MeetingError::AttendeeNotFound("pm".into()))
+        );
+    }
+
+    #[test]
+    fn markdown_summary_is_exact() {
+        let mut meeting = Meeting::new();
+        meeting.set_title("Weekly sync");
+        meeting.add_attendee(&EmployeeCategory::new("qa", 60_000).unwrap(), 1);
+        meeting.add_attendee(&EmployeeCategory::new("dev|ops", 120_000).unwrap(), 2);
+        meeting.elapsed = Duration::from_secs(10 * 60 + 5);
+        assert_eq!(
+            meeting.to_markdown_summary(),
+            "## Weekly sync\n\
+             \n\
+             | Category | Count | Cost |\n\
+             | --- | ---: | ---: |\n\
+             | dev\\|ops | 2 | $20.17 |\n\
+             | qa | 1 | $5.04 |\n\
+             \n\
+             **Duration:** 00:10:05  \n\
+             **Total cost:** $25.21\n"
+        );
+    }
+
+    #[test]
+    fn merge_sums_costs_and_counts() {
+        let dev = sample_category();
+        let qa = EmployeeCategory::new("qa", 60_000).unwrap();
+        let mut a = Meeting::new();
+        a.add_attendee(&dev, 2);
+        a.elapsed = Duration::from_mins(10);
+        let mut b = Meeting::new();
+        b.add_attendee(&dev, 2);
+        b.add_person("Quinn", &qa).unwrap();
+        b.elapsed = Duration::from_mins(10);
+        let expected = a.total_cost() + b.total_cost();
+
+        a.merge(&b).unwrap();
+        assert_eq!(a.duration(), Duration::from_mins(20));
+        assert_cost_eq(a.total_cost(), expected, 1e-9);
+        assert_eq!(a.attendee_count("dev"), Some(4));
+        assert_eq!(a.people().collect::<Vec<_>>(), vec![("Quinn", "qa")]);
+        // Time after the merge is billed at the combined rate.
+        assert_cost_eq(a.burn_rate_per_minute(), 4.5, 1e-9);
+        a.elapsed = Duration::from_mins(21);
+        assert_cost_eq(a.total_cost(), expected + 4.5, 1e-9);
     }
 
-    /// Computes the duration since the meeting was started if it is running.
-    ///
-    /// # Arguments
-    ///
-    /// * None
-    ///
-    /// # Returns
-    ///
-    /// The current running duration.
-    ///
-    /// # See Also
-    /// * [`Meeting::duration`]
-    fn current_duration(&self) -> Duration {
-        if self.running {
-            self.start_time.map_or(Duration::ZERO, |t| t.elapsed())
-        } else {
-            Duration::ZERO
+    #[test]
+    fn merge_rejects_conflicts_without_changes() {
+        let dev = sample_category();
+        let mut a = Meeting::new();
+        a.add_attendee(&dev, 1);
+        let mut b = Meeting::new();
+        b.add_attendee(&EmployeeCategory::new("dev", 1).unwrap(), 1);
+        assert_eq!(a.merge(&b), Err(MeetingError::SalaryMismatch("dev".into())));
+        assert_eq!(a.attendee_count("dev"), Some(1));
+
+        let mut c = Meeting::new();
+        c.start();
+        assert_eq!(a.merge(&c), Err(MeetingError::MeetingRunning));
+        assert_eq!(c.merge(&a), Err(MeetingError::MeetingRunning));
+        assert_eq!(a.timeline().len(), 1);
+    }
+
+    #[test]
+    fn adjusting_elapsed_keeps_running_segment() {
+        let mut meeting = Meeting::new();
+        meeting.elapsed = Duration::from_mins(2);
+        meeting.start();
+        meeting.add_elapsed(Duration::from_mins(5));
+        assert!(meeting.is_running());
+        let duration = meeting.duration();
+        assert!(duration >= Duration::from_mins(7));
+        assert!(duration < Duration::from_mins(7) + Duration::from_secs(1));
+
+        meeting.subtract_elapsed(Duration::from_mins(3));
+        let duration = meeting.duration();
+        assert!(duration >= Duration::from_mins(4));
+        assert!(duration < Duration::from_mins(4) + Duration::from_secs(1));
+
+        meeting.subtract_elapsed(Duration::from_hours(1));
+        assert!(meeting.duration() < Duration::from_secs(1));
+        assert!(meeting.is_running());
+    }
+
+    #[test]
+    fn adjusting_elapsed_reprices_roster() {
+        let mut meeting = Meeting::new();
+        let cat = sample_category();
+        meeting.add_attendee(&cat, 1);
+        meeting.set_elapsed(Duration::from_mins(10));
+        assert_cost_eq(meeting.total_cost(), 10.0, 1e-9);
+
+        meeting.add_attendee(&cat, 1);
+        meeting.set_elapsed(Duration::from_mins(20));
+        assert_cost_eq(meeting.total_cost(), 30.0, 1e-9);
+
+        // Pulling the clock back before the second join moves the join too.
+        meeting.set_elapsed(Duration::from_mins(5));
+        assert_cost_eq(meeting.total_cost(), 5.0, 1e-9);
+        meeting.set_elapsed(Duration::from_mins(6));
+        assert_cost_eq(meeting.total_cost(), 7.0, 1e-9);
+    }
+
+    #[test]
+    fn attendees_by_cost_orders_descending_with_ties_by_title() {
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&EmployeeCategory::new("QA", 80_000).unwrap(), 1);
+        meeting.add_attendee(&EmployeeCategory::new("PM", 100_000).unwrap(), 2);
+        meeting.add_attendee(&EmployeeCategory::new("Dev", 50_000).unwrap(), 4);
+        let order: Vec<(&str, u64, u32)> = meeting
+            .attendees_by_cost()
+            .into_iter()
+            .map(|(title, salary, count, _)| (title, salary, count))
+            .collect();
+        assert_eq!(
+            order,
+            vec![("Dev", 50_000, 4), ("PM", 100_000, 2), ("QA", 80_000, 1)]
+        );
+    }
+
+    #[test]
+    fn attendees_by_cost_shares_the_burn_rate() {
+        let mut meeting = Meeting::new();
+        assert!(meeting.attendees_by_cost().is_empty());
+        meeting.add_attendee(&EmployeeCategory::new("Dev", 100_000).unwrap(), 3);
+        meeting.add_attendee(&EmployeeCategory::new("VP", 100_000).unwrap(), 1);
+        meeting.add_attendee(&EmployeeCategory::observer("Guest").unwrap(), 2);
+        let shares: Vec<(&str, f64)> = meeting
+            .attendees_by_cost()
+            .into_iter()
+            .map(|(title, _, _, share)| (title, share))
+            .collect();
+        assert_eq!(shares, vec![("Dev", 0.75), ("VP", 0.25), ("Guest", 0.0)]);
+
+        let mut guests = Meeting::new();
+        guests.add_attendee(&EmployeeCategory::observer("Guest").unwrap(), 2);
+        assert_eq!(guests.attendees_by_cost(), vec![("Guest", 0, 2, 0.0)]);
+    }
+
+    #[test]
+    fn cheapest_quorum_prefers_cheaper_roles() {
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&EmployeeCategory::new("VP", 300_000).unwrap(), 1);
+        meeting.add_attendee(&EmployeeCategory::new("Dev", 120_000).unwrap(), 3);
+        meeting.add_attendee(&EmployeeCategory::new("QA", 90_000).unwrap(), 2);
+        meeting.add_attendee(&EmployeeCategory::new("Ops", 120_000).unwrap(), 1);
+
+        assert_eq!(
+            meeting.cheapest_quorum(4),
+            vec![("QA".to_string(), 2), ("Dev".to_string(), 2)]
+        );
+        assert_eq!(
+            meeting.cheapest_quorum(6).last(),
+            Some(&("Ops".to_string(), 1))
+        );
+        assert_eq!(meeting.cheapest_quorum(20).len(), 4);
+        assert!(meeting.cheapest_quorum(0).is_empty());
+    }
+
+    #[test]
+    fn tick_notifies_subscribers_while_running() {
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&sample_category(), 1);
+        let first = meeting.subscribe();
+        let second = meeting.subscribe();
+        let dropped = meeting.subscribe();
+        drop(dropped);
+
+        meeting.tick();
+        assert!(first.try_recv().is_err());
+
+        meeting.elapsed = Duration::from_mins(10);
+        meeting.start();
+        meeting.tick();
+        assert_eq!(meeting.subscribers.len(), 2);
+        for receiver in [&first, &second] {
+            let update = receiver.try_recv().unwrap();
+            assert!(update.duration >= Duration::from_mins(10));
+            assert!(update.total_cost >= 10.0);
         }
+
+        drop(first);
+        meeting.tick();
+        assert_eq!(meeting.subscribers.len(), 1);
+        assert!(second.try_recv().is_ok());
     }
-}
 
-impl Default for Meeting {
-    fn default() -> Self {
-        Self::new()
+    #[test]
+    fn total_cost_with_overhead_scales_total() {
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&sample_category(), 3);
+        meeting.elapsed = Duration::from_mins(10);
+        let total = meeting.total_cost();
+        for multiplier in [1.0, 1.25, 2.0] {
+            let burdened = meeting.total_cost_with_overhead(multiplier).unwrap();
+            assert_cost_eq(burdened, total * multiplier, 1e-9);
+        }
+        assert_eq!(
+            meeting.total_cost_with_overhead(0.99),
+            Err(MeetingError::InvalidMultiplier(0.99))
+        );
+        assert!(meeting.total_cost_with_overhead(f64::NAN).is_err());
+        assert!(meeting.total_cost_with_overhead(f64::INFINITY).is_err());
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::model::EmployeeCategory;
-    use std::thread::sleep;
+    #[test]
+    fn remove_groups_reports_removed_and_unknown() {
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&sample_category(), 3);
+        meeting.add_attendee(&EmployeeCategory::new("pm", 240_000).unwrap(), 1);
+        meeting.add_attendee(&EmployeeCategory::new("qa", 60_000).unwrap(), 2);
 
-    fn sample_category() -> EmployeeCategory {
-        EmployeeCategory::new("dev", 120_000).unwrap()
+        let titles: Vec<String> = ["pm", "ghost", "dev", "pm"]
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        let preview = meeting.preview_remove_groups(&titles);
+        assert_eq!(meeting.total_attendees(), 6);
+
+        let report = meeting.remove_groups(&titles);
+        assert_eq!(report, preview);
+        assert_eq!(
+            report.removed,
+            vec![("pm".to_string(), 1), ("dev".to_string(), 3)]
+        );
+        assert_eq!(report.unknown, vec!["ghost".to_string()]);
+        assert_eq!(report.headcount, 4);
+        assert_cost_eq(report.burn_rate_reduction, 5.0, 1e-9);
+        assert_eq!(meeting.total_attendees(), 2);
+        assert_cost_eq(meeting.burn_rate_per_minute(), 1.0, 1e-9);
+
+        assert!(meeting.undo_last_attendee_change());
+        assert_eq!(meeting.attendee_count("dev"), Some(3));
     }
 
     #[test]
-    fn new_starts_empty() {
-        let meeting = Meeting::new();
-        assert!(!meeting.is_running());
-        assert_eq!(meeting.attendees().count(), 0);
-        assert_eq!(meeting.duration(), Duration::ZERO);
+    fn removal_preview_follows_strict_duplicates() {
+        let lead = EmployeeCategory::new("lead", 240_000).unwrap();
+        let mut meeting = Meeting::new();
+        meeting.set_strict_duplicate_names(true);
+        meeting.add_attendee(&sample_category(), 1);
+        meeting.add_person("Alice", &sample_category()).unwrap();
+        meeting.add_person("alice", &lead).unwrap();
+        assert_cost_eq(meeting.burn_rate_per_minute(), 3.0, 1e-9);
+
+        // Alice is billed under lead only, so dev costs just $1/min.
+        let preview = meeting.preview_remove_groups(&["dev".to_string()]);
+        assert_eq!(preview.headcount, 2);
+        assert_cost_eq(preview.burn_rate_reduction, 1.0, 1e-9);
+        // Without lead, Alice is billed under dev again.
+        let preview = meeting.preview_remove_groups(&["lead".to_string()]);
+        assert_cost_eq(preview.burn_rate_reduction, 1.0, 1e-9);
+
+        let before = meeting.burn_rate_per_minute();
+        let report = meeting.remove_groups(&["lead".to_string()]);
+        assert_eq!(report, preview);
+        assert_cost_eq(before - meeting.burn_rate_per_minute(), 1.0, 1e-9);
     }
 
     #[test]
-    fn default_equivalent_to_new() {
-        let a = Meeting::new();
-        let b = Meeting::default();
-        assert_eq!(a.is_running(), b.is_running());
-        assert_eq!(a.attendees().count(), b.attendees().count());
+    fn overhead_scales_all_costs() {
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&sample_category(), 2);
+        meeting.elapsed = Duration::from_mins(10);
+        meeting.set_overhead(0.25).unwrap();
+        assert_cost_eq(meeting.total_cost(), 25.0, 1e-9);
+        assert_cost_eq(meeting.breakdown()[0].cost, 25.0, 1e-9);
+        assert_cost_eq(meeting.burn_rate_per_minute(), 2.5, 1e-9);
+        assert_eq!(
+            meeting.set_overhead(-0.1),
+            Err(MeetingError::InvalidOverhead(-0.1))
+        );
+        assert!((meeting.overhead() - 0.25).abs() < f64::EPSILON);
     }
 
     #[test]
-    fn add_and_remove_attendees_respects_counts() {
-        let cat = sample_category();
+    fn breakdown_lists_departed_groups() {
+        let dev = EmployeeCategory::new("dev", 120_000).unwrap();
+        let pm = EmployeeCategory::new("pm", 240_000).unwrap();
         let mut meeting = Meeting::new();
-        meeting.add_attendee(&cat, 2);
-        meeting.add_attendee(&cat, 1);
-        assert_eq!(meeting.attendees().next().unwrap().2, &3);
-        meeting.remove_attendee(cat.title(), 1);
-        assert_eq!(meeting.attendees().next().unwrap().2, &2);
-        meeting.remove_attendee(cat.title(), 5);
-        assert!(meeting.attendees().next().is_none());
+        meeting.add_attendee(&pm, 1);
+        meeting.add_attendee(&dev, 3);
+        meeting.elapsed = Duration::from_mins(10);
+        meeting.remove_attendee(pm.title(), 1);
+        meeting.elapsed = Duration::from_mins(20);
+        let breakdown = meeting.breakdown();
+        assert_eq!(breakdown.len(), 2);
+        assert_eq!(breakdown[0].title, "dev");
+        assert_eq!(breakdown[0].count, 3);
+        assert_cost_eq(breakdown[0].cost, 60.0, 1e-9);
+        assert_eq!(breakdown[1].title, "pm");
+        assert_eq!(breakdown[1].count, 0);
+        assert_eq!(breakdown[1].salary, 240_000);
+        assert_cost_eq(breakdown[1].cost, 20.0, 1e-9);
+
+        meeting.set_cost_model(CostModel::FlatFromStart);
+        let breakdown = meeting.breakdown();
+        assert_eq!(breakdown.len(), 1);
+        assert_cost_eq(breakdown[0].cost, 60.0, 1e-9);
     }
 
     #[test]
-    fn start_stop_and_duration() {
+    fn burn_rate_sums_groups() {
+        let mut meeting = Meeting::new();
+        assert_cost_eq(meeting.burn_rate_per_minute(), 0.0, f64::EPSILON);
+        meeting.add_attendee(&EmployeeCategory::new("a", 120_000).unwrap(), 1);
+        meeting.add_attendee(&EmployeeCategory::new("b", 240_000).unwrap(), 2);
+        assert_cost_eq(meeting.burn_rate_per_minute(), 5.0, 1e-9);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn total_cost_with_uses_calendar_year() {
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&EmployeeCategory::new("dev", 104_800).unwrap(), 1);
+        meeting.elapsed = Duration::from_hours(1);
+        // 2024 has 262 weekdays, or 2096 hours.
+        assert_eq!(meeting.total_cost_with(&WorkYear::calendar(2024)), 50.0);
+        assert_eq!(
+            meeting.total_cost_with(&WorkYear::calendar(2024).with_holidays(12)),
+            52.4
+        );
+        assert_eq!(
+            meeting.total_cost_with(&WorkYear::calendar(2024).with_holidays(300)),
+            0.0
+        );
+    }
+
+    #[test]
+    fn total_cost_cents_is_exact() {
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&EmployeeCategory::new("analyst", 102_345).unwrap(), 2);
+        meeting.elapsed = Duration::from_hours(10);
+        assert_eq!(meeting.total_cost_cents(), 102_345);
+
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&EmployeeCategory::new("lead", 122_814).unwrap(), 1);
+        meeting.elapsed = Duration::from_hours(1);
+        // $61.407 rounds to $61.41.
+        assert_eq!(meeting.total_cost_cents(), 6_141);
+    }
+
+    #[test]
+    fn total_cost_cents_rounds_half_up() {
+        // $36,000 a year is half a cent per second.
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&EmployeeCategory::new("intern", 36_000).unwrap(), 1);
+        meeting.elapsed = Duration::from_millis(999);
+        assert_eq!(meeting.total_cost_cents(), 0);
+        meeting.elapsed = Duration::from_secs(1);
+        assert_eq!(meeting.total_cost_cents(), 1);
+        meeting.elapsed = Duration::from_secs(3);
+        assert_eq!(meeting.total_cost_cents(), 2);
+    }
+
+    #[test]
+    fn breakdown_reports_cents_per_group() {
+        let mut meeting = Meeting::new();
+        let pm = EmployeeCategory::new("pm", 240_000).unwrap();
+        meeting.add_attendee(&pm, 1);
+        meeting.add_attendee(&sample_category(), 3);
+        meeting.elapsed = Duration::from_mins(10);
+        meeting.remove_attendee(pm.title(), 1);
+        meeting.elapsed = Duration::from_mins(20);
+        let cents: Vec<u64> = meeting.breakdown().iter().map(|c| c.cost_cents).collect();
+        assert_eq!(cents, vec![6_000, 2_000]);
+        assert_eq!(meeting.total_cost_cents(), 8_000);
+
+        meeting.set_overhead(0.3).unwrap();
+        let cents: Vec<u64> = meeting.breakdown().iter().map(|c| c.cost_cents).collect();
+        assert_eq!(cents, vec![7_800, 2_600]);
+        assert_eq!(meeting.total_cost_cents(), 10_400);
+        assert_cost_eq(meeting.total_cost(), 104.0, 1e-9);
+    }
+
+    #[test]
+    fn total_cost_cents_honors_work_hours() {
+        let mut meeting = Meeting::with_work_hours(1_000.0).unwrap();
+        meeting.add_attendee(&sample_category(), 1);
+        meeting.elapsed = Duration::from_mins(10);
+        assert_eq!(meeting.total_cost_cents(), 2_000);
+    }
+
+    #[test]
+    fn rate_trend_follows_last_change() {
         let mut meeting = Meeting::new();
+        assert_eq!(meeting.rate_trend(), Trend::Steady);
+        meeting.add_attendee(&sample_category(), 2);
+        assert_eq!(meeting.rate_trend(), Trend::Increasing);
+        meeting.elapsed = Duration::from_mins(5);
+        assert_eq!(meeting.rate_trend(), Trend::Increasing);
+        meeting.remove_attendee("dev", 1);
+        assert_eq!(meeting.rate_trend(), Trend::Decreasing);
+        meeting.add_attendee(&EmployeeCategory::new("pm", 240_000).unwrap(), 1);
+        assert_eq!(meeting.rate_trend(), Trend::Increasing);
+        meeting.remove_attendee("pm", 1);
+        assert_eq!(meeting.rate_trend(), Trend::Decreasing);
+    }
+
+    #[test]
+    fn rate_trend_is_steady_after_unchanged_rate() {
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&sample_category(), 1);
+        meeting.update_salary("dev", 120_000).unwrap();
+        assert_eq!(meeting.rate_trend(), Trend::Steady);
+        meeting.reset();
+        assert_eq!(meeting.rate_trend(), Trend::Steady);
+    }
+
+    #[test]
+    fn rate_steps_follow_scripted_roster() {
+        let mut meeting = Meeting::new();
+        assert!(meeting.rate_steps().is_empty());
+        let pm = EmployeeCategory::new("pm", 240_000).unwrap();
+        meeting.add_attendee(&sample_category(), 2);
+        meeting.add_attendee(&pm, 1);
+        meeting.set_elapsed(Duration::from_mins(5));
+        meeting.remove_attendee("dev", 1);
+        meeting.set_elapsed(Duration::from_mins(12));
+        meeting.remove_attendee("pm", 1);
+        meeting.add_attendee(&sample_category(), 3);
+        let steps = meeting.rate_steps();
+        let offsets: Vec<Duration> = steps.iter().map(|s| s.0).collect();
+        assert_eq!(
+            offsets,
+            vec![
+                Duration::ZERO,
+                Duration::from_mins(5),
+                Duration::from_mins(12)
+            ]
+        );
+        let rates: Vec<f64> = steps.iter().map(|s| s.1).collect();
+        for (rate, expected) in rates.iter().zip([4.0, 3.0, 4.0]) {
+            assert_cost_eq(*rate, expected, 1e-9);
+        }
+    }
+
+    #[test]
+    fn summary_outlives_reset_and_round_trips() {
+        let mut meeting = Meeting::new();
+        meeting.set_title("Planning");
+        meeting.add_attendee(&sample_category(), 3);
+        assert_eq!(meeting.summary().started_at, None);
         meeting.start();
-        sleep(Duration::from_millis(10));
-        meeting.start(); // should have no effect
-        sleep(Duration::from_millis(10));
-        meeting.stop();
-        let first = meeting.duration();
-        sleep(Duration::from_millis(10));
         meeting.stop();
-        assert_eq!(meeting.duration(), first);
-        assert!(first >= Duration::from_millis(20));
+        meeting.set_elapsed(Duration::from_mins(10));
+
+        let summary = meeting.summary();
+        meeting.reset();
+        assert_eq!(summary.title.as_deref(), Some("Planning"));
+        assert_eq!(summary.duration, Duration::from_mins(10));
+        assert_cost_eq(summary.total_cost, 30.0, 1e-9);
+        assert_eq!(summary.headcount, 3);
+        assert_eq!(summary.categories.len(), 1);
+        assert_eq!(summary.categories[0].cost_cents, 3_000);
+        let started = summary.started_at.unwrap();
+        assert!(summary.ended_at.unwrap() >= started);
+
+        let toml = toml::to_string(&summary).unwrap();
+        assert_eq!(toml::from_str::<MeetingSummary>(&toml).unwrap(), summary);
     }
 
     #[test]
-    fn total_cost_accumulates() {
-        let cat = sample_category();
+    fn summary_has_no_end_while_running() {
         let mut meeting = Meeting::new();
-        meeting.add_attendee(&cat, 1);
         meeting.start();
-        sleep(Duration::from_millis(10));
         meeting.stop();
-        assert!(meeting.total_cost() > 0.0);
+        meeting.start();
+        let summary = meeting.summary();
+        assert!(summary.started_at.is_some());
+        assert_eq!(summary.ended_at, None);
     }
 
     #[test]
-    #[allow(clippy::float_cmp)]
-    fn reset_clears_state() {
-        let cat = sample_category();
+    fn duplicate_names_span_groups() {
+        let eng = EmployeeCategory::new("eng", 120_000).unwrap();
+        let lead = EmployeeCategory::new("lead", 180_000).unwrap();
+        let pm = EmployeeCategory::new("pm", 150_000).unwrap();
         let mut meeting = Meeting::new();
-        meeting.add_attendee(&cat, 1);
+        meeting.add_person("Alice", &eng).unwrap();
+        meeting.add_person(" alice", &lead).unwrap();
+        meeting.add_person("ALICE ", &pm).unwrap();
+        meeting.add_person("Bob", &eng).unwrap();
+        meeting.add_person("bob", &eng).unwrap();
+        meeting.add_person("Carol", &pm).unwrap();
+        assert_eq!(
+            meeting.duplicate_names(),
+            vec![(
+                "alice".to_string(),
+                vec!["eng".to_string(), "lead".to_string(), "pm".to_string()]
+            )]
+        );
+        assert_eq!(meeting.total_attendees(), 6);
+    }
+
+    #[test]
+    fn strict_duplicates_are_billed_once() {
+        let lead = EmployeeCategory::new("lead", 240_000).unwrap();
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&sample_category(), 1);
+        meeting.add_person("Alice", &sample_category()).unwrap();
+        meeting.add_person("alice", &lead).unwrap();
+        meeting.elapsed = Duration::from_mins(10);
+        assert_eq!(meeting.total_cost_cents(), 4_000);
+        assert_cost_eq(meeting.burn_rate_per_minute(), 4.0, 1e-9);
+
+        meeting.set_strict_duplicate_names(true);
+        // Alice stays in the better-paid lead group only.
+        assert_eq!(meeting.total_attendees(), 2);
+        assert_eq!(meeting.total_cost_cents(), 3_000);
+        assert_cost_eq(meeting.burn_rate_per_minute(), 3.0, 1e-9);
+        let counts: Vec<u32> = meeting.breakdown().iter().map(|c| c.count).collect();
+        assert_eq!(counts, vec![1, 1]);
+        assert_eq!(meeting.attendee_count("dev"), Some(2));
+    }
+
+    #[test]
+    fn strict_duplicates_count_salary_once() {
+        let lead = EmployeeCategory::new("lead", 240_000).unwrap();
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&sample_category(), 1);
+        meeting.add_person("Alice", &sample_category()).unwrap();
+        meeting.add_person("alice", &lead).unwrap();
+        assert_eq!(meeting.total_annual_salary(), 480_000);
+
+        meeting.set_strict_duplicate_names(true);
+        // One dev plus Alice as a lead, matching the $3/min burn rate.
+        assert_eq!(meeting.total_annual_salary(), 360_000);
+        assert_cost_eq(meeting.burn_rate_per_minute(), 3.0, 1e-9);
+    }
+
+    #[test]
+    fn wall_clock_keeps_first_start_and_last_stop() {
+        let mut meeting = Meeting::new();
+        meeting.start();
+        let first = meeting.started_at().unwrap();
+        meeting.stop();
+        let first_stop = meeting.ended_at().unwrap();
+        // Wall-clock times come from the system clock, so real time must pass.
+        std::thread::sleep(Duration::from_millis(5));
         meeting.start();
-        sleep(Duration::from_millis(5));
+        assert_eq!(meeting.ended_at(), None);
         meeting.stop();
+        assert_eq!(meeting.started_at(), Some(first));
+        assert!(meeting.ended_at().unwrap() > first_stop);
+
         meeting.reset();
-        assert_eq!(meeting.attendees().count(), 0);
-        assert!(!meeting.is_running());
-        assert_eq!(meeting.total_cost(), 0.0);
+        assert_eq!(meeting.started_at(), None);
+        assert_eq!(meeting.ended_at(), None);
     }
 
     #[test]
-    fn current_duration_only_when_running() {
+    fn armed_start_reports_wall_clock_start() {
         let mut meeting = Meeting::new();
-        assert_eq!(meeting.current_duration(), Duration::ZERO);
-        meeting.start();
-        sleep(Duration::from_millis(5));
-        assert!(meeting.current_duration() > Duration::ZERO);
+        meeting.arm_start_at(Instant::now());
+        assert!(meeting.started_at().is_some());
+        meeting.stop();
+        assert!(meeting.started_at().is_some());
+        assert!(meeting.ended_at().is_some());
     }
 
     #[test]
-    fn attendee_count_works() {
-        let cat = sample_category();
+    fn over_budget_only_above_budget() {
         let mut meeting = Meeting::new();
-        assert_eq!(meeting.attendee_count(cat.title()), None);
-        meeting.add_attendee(&cat, 2);
-        assert_eq!(meeting.attendee_count(cat.title()), Some(2));
+        meeting.add_attendee(&sample_category(), 1);
+        meeting.elapsed = Duration::from_mins(10);
+        assert!(!meeting.is_over_budget());
+
+        meeting.set_budget(10.01).unwrap();
+        assert!(!meeting.is_over_budget());
+        meeting.set_budget(10.0).unwrap();
+        assert!(!meeting.is_over_budget());
+        meeting.set_budget(9.99).unwrap();
+        assert!(meeting.is_over_budget());
+        meeting.clear_budget();
+        assert!(!meeting.is_over_budget());
     }
 
     #[test]
-    fn clear_attendees_leaves_timing() {
-        let cat = sample_category();
+    fn cloned_stopped_meeting_equals_until_edited() {
+        let (meeting, _clock) = MeetingFixture::new()
+            .attendees(&sample_category(), 2)
+            .run_for(Duration::from_mins(5))
+            .build();
+        let mut copy = meeting.clone();
+        assert_eq!(copy, meeting);
+        assert_eq!(copy.total_cost_cents(), meeting.total_cost_cents());
+
+        copy.add_attendee(&sample_category(), 1);
+        assert_ne!(copy, meeting);
+        copy.remove_attendee("dev", 1);
+        assert_ne!(copy, meeting, "roster history differs");
+
+        let mut titled = meeting.clone();
+        titled.set_title("Standup");
+        assert_ne!(titled, meeting);
+    }
+
+    #[test]
+    fn cloned_running_meeting_keeps_running() {
+        let (meeting, clock) = MeetingFixture::new()
+            .attendees(&sample_category(), 1)
+            .run_for(Duration::from_mins(1))
+            .running()
+            .build();
+        let mut copy = meeting.clone();
+        assert!(copy.is_running());
+        assert_eq!(copy, meeting);
+
+        clock.advance(Duration::from_mins(2));
+        assert_eq!(copy.duration(), Duration::from_mins(3));
+        assert_eq!(meeting.duration(), Duration::from_mins(3));
+
+        copy.stop();
+        assert_ne!(copy, meeting);
+        clock.advance(Duration::from_mins(1));
+        assert_eq!(copy.duration(), Duration::from_mins(3));
+        assert_eq!(meeting.duration(), Duration::from_mins(4));
+    }
+
+    #[test]
+    fn equality_ignores_start_instant_of_running_meetings() {
+        let clock = ManualClock::new();
+        let mut a = Meeting::with_clock(clock.clone());
+        let mut b = Meeting::with_clock(clock.clone());
+        a.start();
+        clock.advance(Duration::from_secs(30));
+        b.start();
+        assert_eq!(a, b);
+        assert_ne!(a.duration(), b.duration());
+    }
+
+    #[test]
+    fn clone_drops_subscribers() {
         let mut meeting = Meeting::new();
-        meeting.add_attendee(&cat, 1);
+        let updates = meeting.subscribe();
+        meeting.start();
+        let mut copy = meeting.clone();
+        copy.tick();
+        assert!(updates.try_recv().is_err());
+        meeting.tick();
+        assert!(updates.try_recv().is_ok());
+    }
+
+    #[test]
+    fn approximate_flag_reaches_summary() {
+        let mut banded = sample_category();
+        banded.set_approximate(true);
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&EmployeeCategory::new("qa", 60_000).unwrap(), 1);
+        assert!(!meeting.summary().approximate);
+
+        meeting.add_attendee(&banded, 1);
+        meeting.remove_attendee("dev", 1);
+        let summary = meeting.summary();
+        assert!(summary.approximate);
+        assert!(serde_json::to_string(&summary)
+            .unwrap()
+            .contains("\"approximate\":true"));
+
+        meeting.reset();
+        assert!(!meeting.summary().approximate);
+        meeting.swap_attendees(&[(banded, 2)]);
+        assert!(meeting.is_approximate());
+    }
+
+    #[test]
+    fn state_at_replays_scripted_timeline() {
+        let (meeting, _clock) = MeetingFixture::new()
+            .attendees(&engineer(), 2)
+            .run_for(Duration::from_mins(10))
+            .attendees(&director(), 1)
+            .run_for(Duration::from_mins(10))
+            .leave("Engineer", 2)
+            .run_for(Duration::from_mins(10))
+            .build();
+        let roster = |offset: u64| {
+            let state = meeting.state_at(Duration::from_mins(offset));
+            let mut groups: Vec<(String, u32)> = state
+                .attendees
+                .iter()
+                .map(|a| (a.title.clone(), a.count))
+                .collect();
+            groups.sort();
+            (groups, state)
+        };
+
+        let (groups, start) = roster(0);
+        assert_eq!(groups, vec![("Engineer".to_string(), 2)]);
+        assert_cost_eq(start.total_cost, 0.0, 1e-9);
+        assert_cost_eq(start.burn_rate_per_minute, 2.0, 1e-9);
+
+        let (groups, middle) = roster(15);
+        assert_eq!(
+            groups,
+            vec![("Director".to_string(), 1), ("Engineer".to_string(), 2)]
+        );
+        assert_eq!(middle.elapsed_millis, 15 * 60_000);
+        assert!(!middle.running);
+        assert_cost_eq(middle.total_cost, 20.0 + 30.0, 1e-9);
+        assert_cost_eq(middle.burn_rate_per_minute, 6.0, 1e-9);
+
+        // The change at exactly 20 minutes is included.
+        let (groups, change) = roster(20);
+        assert_eq!(groups, vec![("Director".to_string(), 1)]);
+        assert_cost_eq(change.total_cost, 80.0, 1e-9);
+        assert_cost_eq(change.burn_rate_per_minute, 4.0, 1e-9);
+
+        let (_, end) = roster(45);
+        assert_eq!(end.elapsed_millis, 30 * 60_000);
+        assert_cost_eq(end.total_cost, meeting.total_cost(), 1e-9);
+        assert_eq!(meeting.duration(), Duration::from_mins(30));
+    }
+
+    #[test]
+    fn cost_interval_spans_salary_ranges() {
+        let ranged = engineer().with_salary_range(60_000, 180_000).unwrap();
+        let (mut meeting, _clock) = MeetingFixture::new()
+            .attendees(&ranged, 2)
+            .attendees(&manager(), 1)
+            .run_for(Duration::from_mins(10))
+            .build();
+        assert_cost_eq(meeting.total_cost(), 40.0, 1e-9);
+        let (low, high) = meeting.cost_interval(0.95);
+        assert_cost_eq(low, 10.0 + 20.0, 1e-9);
+        assert_cost_eq(high, 30.0 + 20.0, 1e-9);
+        assert!(low < meeting.total_cost() && meeting.total_cost() < high);
+
+        let (low, high) = meeting.cost_interval(0.0);
+        assert_cost_eq(low, 40.0, 1e-9);
+        assert_cost_eq(high, 40.0, 1e-9);
+
+        meeting.reset();
+        assert_eq!(meeting.cost_interval(1.0), (0.0, 0.0));
+    }
+
+    #[test]
+    fn growing_a_group_keeps_its_salary_and_markers() {
+        let mut estimate = EmployeeCategory::new("Engineer", 150_000)
+            .unwrap()
+            .with_salary_range(100_000, 200_000)
+            .unwrap();
+        estimate.set_approximate(true);
+        let (mut meeting, _clock) = MeetingFixture::new()
+            .attendees(&engineer(), 1)
+            .run_for(Duration::from_mins(10))
+            .build();
+        // The group stays at the exact $120k salary, so no estimate applies.
+        meeting.add_attendee(&estimate, 1);
+        assert_eq!(meeting.attendee_count("Engineer"), Some(2));
+        assert_cost_eq(meeting.burn_rate_per_minute(), 2.0, 1e-9);
+        assert!(!meeting.is_approximate());
+        assert_eq!(meeting.cost_interval(1.0), (10.0, 10.0));
+        meeting.swap_attendees(&[(engineer(), 1), (estimate.clone(), 1)]);
+        assert!(!meeting.is_approximate());
+
+        // An estimated group keeps its range when exact figures are added.
+        let (mut meeting, _clock) = MeetingFixture::new()
+            .attendees(&estimate, 1)
+            .run_for(Duration::from_mins(10))
+            .build();
+        meeting.add_attendee(&engineer(), 1);
+        assert_cost_eq(meeting.burn_rate_per_minute(), 2.5, 1e-9);
+        assert!(meeting.is_approximate());
+        let (low, high) = meeting.cost_interval(1.0);
+        assert!(low < 15.0 && 15.0 < high, "{low}..{high}");
+    }
+
+    #[test]
+    fn cost_interval_without_ranges_is_a_point() {
+        let (meeting, _clock) = MeetingFixture::new()
+            .attendees(&engineer(), 3)
+            .attendees(&guest(), 2)
+            .run_for(Duration::from_mins(5))
+            .build();
+        let (low, high) = meeting.cost_interval(0.9);
+        assert_cost_eq(low, 15.0, 1e-9);
+        assert_cost_eq(high, 15.0, 1e-9);
+    }
+
+    #[test]
+    fn cost_per_attendee_averages_mixed_groups() {
+        let empty = Meeting::new();
+        assert_eq!(empty.total_attendees(), 0);
+        assert_cost_eq(empty.cost_per_attendee(), 0.0, 0.0);
+
+        // $1/min and $2/min attendees for 30 minutes: (2 * 30 + 60) / 3 = $40.
+        let (meeting, _clock) = MeetingFixture::new()
+            .attendees(&engineer(), 2)
+            .attendees(&manager(), 1)
+            .run_for(Duration::from_mins(30))
+            .build();
+        assert_cost_eq(meeting.cost_per_attendee(), 40.0, 1e-9);
+    }
+
+    #[test]
+    fn segments_bill_attendees_present_during_each_slice() {
+        let clock = ManualClock::new();
+        let mut meeting = Meeting::with_clock(clock.clone());
+        meeting.add_attendee(&engineer(), 2);
+        assert_eq!(
+            meeting.begin_segment("Intro"),
+            Err(MeetingError::NotRunning)
+        );
         meeting.start();
-        sleep(Duration::from_millis(5));
+        meeting.begin_segment("Intro").unwrap();
+        clock.advance(Duration::from_mins(5));
+        meeting.begin_segment("Design").unwrap();
+        clock.advance(Duration::from_mins(10));
+        meeting.add_attendee(&manager(), 1);
+        clock.advance(Duration::from_mins(10));
+        assert!(meeting.end_segment());
+        assert!(!meeting.end_segment());
+        clock.advance(Duration::from_mins(5));
+        meeting.begin_segment("Wrap-up").unwrap();
+        clock.advance(Duration::from_mins(3));
+
+        let segments = meeting.segments();
+        let names: Vec<&str> = segments.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["Intro", "Design", "Wrap-up"]);
+        assert_eq!(segments[0].duration, Duration::from_mins(5));
+        assert_cost_eq(segments[0].cost, 10.0, CENT);
+        assert_eq!(segments[1].duration, Duration::from_mins(20));
+        assert_cost_eq(segments[1].cost, 60.0, CENT);
+        assert_cost_eq(segments[2].cost, 12.0, CENT);
+
+        clock.advance(Duration::from_mins(2));
         meeting.stop();
-        let duration = meeting.duration();
-        meeting.clear_attendees();
-        assert_eq!(meeting.attendees().count(), 0);
-        assert_eq!(meeting.duration(), duration);
+        clock.advance(Duration::from_mins(10));
+        let wrap_up = &meeting.segments()[2];
+        assert_eq!(wrap_up.duration, Duration::from_mins(5));
+        assert_cost_eq(wrap_up.cost, 20.0, CENT);
+
+        meeting.reset();
+        assert!(meeting.segments().is_empty());
+    }
+
+    #[test]
+    fn segments_survive_summary_serialization() {
+        let (mut meeting, clock) = MeetingFixture::new()
+            .attendees(&engineer(), 1)
+            .running()
+            .build();
+        assert!(!serde_json::to_string(&meeting.summary())
+            .unwrap()
+            .contains("segments"));
+        meeting.begin_segment("Demo").unwrap();
+        clock.advance(Duration::from_mins(4));
+        meeting.stop();
+
+        let summary = meeting.summary();
+        let json = serde_json::to_string(&summary).unwrap();
+        let restored: MeetingSummary = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.segments, summary.segments);
+        assert_eq!(restored.segments[0].name, "Demo");
+        assert_cost_eq(restored.segments[0].cost, 4.0, CENT);
+    }
+
+    #[test]
+    fn meeting_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Meeting>();
+    }
+
+    #[test]
+    fn cost_if_added_matches_adding_from_the_start() {
+        let (meeting, _clock) = MeetingFixture::new()
+            .attendees(&engineer(), 2)
+            .run_for(Duration::from_mins(10))
+            .attendees(&director(), 1)
+            .run_for(Duration::from_mins(20))
+            .build();
+        let (with_managers, _clock) = MeetingFixture::new()
+            .attendees(&engineer(), 2)
+            .attendees(&manager(), 2)
+            .run_for(Duration::from_mins(10))
+            .attendees(&director(), 1)
+            .run_for(Duration::from_mins(20))
+            .build();
+        let before = meeting.clone();
+        let preview = meeting.cost_if_added(&manager(), 2);
+        assert_cost_eq(preview, with_managers.total_cost(), 1e-9);
+        assert_cost_eq(preview, 260.0, 1e-9);
+        assert_eq!(meeting, before);
+        assert_cost_eq(meeting.cost_if_added(&guest(), 5), 140.0, 1e-9);
+
+        let mut flat = meeting.clone();
+        flat.set_cost_model(CostModel::FlatFromStart);
+        flat.set_overhead(0.25).unwrap();
+        let preview = flat.cost_if_added(&manager(), 2);
+        flat.add_attendee(&manager(), 2);
+        assert_cost_eq(preview, flat.total_cost(), 1e-9);
     }
 }
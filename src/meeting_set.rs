@@ -0,0 +1,329 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::meeting::{Meeting, MeetingError};
+use crate::snapshot::MeetingSnapshot;
+
+/// Serialized form of a [`MeetingSet`].
+#[derive(Serialize, Deserialize)]
+struct MeetingSetState {
+    meetings: BTreeMap<String, MeetingSnapshot>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    active: Option<String>,
+}
+
+/// Named meetings tracked side by side, e.g. a main meeting and a huddle
+/// running at the same time.
+///
+/// Names are unique. One meeting may be marked active, for frontends that
+/// show or control one meeting at a time; removing it clears the mark.
+///
+/// The set serializes as a [`MeetingSnapshot`] per meeting, so it can be
+/// saved in one file. Each meeting keeps its roster history, attention,
+/// budget, duration cap, cost model, strict duplicate mode and estimate
+/// markers; agenda segments and undo history are lost. As with
+/// [`Meeting::from_state`], meetings that were running when the set was saved
+/// are running again after loading, with the time in between counted for
+/// meetings with [wall-clock resume](Meeting::set_wall_clock_resume) enabled.
+///
+/// ## Example
+/// ```
+/// use meeting_cost_tracker::{EmployeeCategory, MeetingSet};
+/// let dev = EmployeeCategory::new("Dev", 120_000).unwrap();
+/// let mut set = MeetingSet::new();
+/// set.create("Planning").unwrap().add_attendee(&dev, 4);
+/// set.create("Huddle").unwrap().add_attendee(&dev, 2);
+/// set.set_active("Huddle").unwrap();
+/// set.active_mut().unwrap().start();
+/// assert_eq!(set.running_count(), 1);
+/// assert!(set.create("Huddle").is_err());
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(into = "MeetingSetState", try_from = "MeetingSetState")]
+pub struct MeetingSet {
+    meetings: BTreeMap<String, Meeting>,
+    active: Option<String>,
+}
+
+impl MeetingSet {
+    /// Creates an empty set.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a new, empty meeting named `name`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the meeting, unique within the set.
+    ///
+    /// # Returns
+    ///
+    /// The new meeting, for adding attendees.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeetingError::MeetingExists`] if the set already has a
+    /// meeting named `name`.
+    pub fn create<T: Into<String>>(&mut self, name: T) -> Result<&mut Meeting, MeetingError> {
+        let name = name.into();
+        if self.meetings.contains_key(&name) {
+            return Err(MeetingError::MeetingExists(name));
+        }
+        Ok(self.meetings.entry(name).or_default())
+    }
+
+    /// Returns the meeting named `name`, if any.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&Meeting> {
+        self.meetings.get(name)
+    }
+
+    /// Returns the meeting named `name` for changing it, if any.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut Meeting> {
+        self.meetings.get_mut(name)
+    }
+
+    /// Removes the meeting named `name`, clearing the active mark if it was
+    /// the active meeting.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the meeting.
+    ///
+    /// # Returns
+    ///
+    /// The removed meeting, or `None` if there was none by that name.
+    pub fn remove(&mut self, name: &str) -> Option<Meeting> {
+        if self.active.as_deref() == Some(name) {
+            self.active = None;
+        }
+        self.meetings.remove(name)
+    }
+
+    /// Returns the names of the meetings in sorted order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.meetings.keys().map(String::as_str)
+    }
+
+    /// Returns the number of meetings in the set.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.meetings.len()
+    }
+
+    /// Whether the set has no meetings.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.meetings.is_empty()
+    }
+
+    /// Marks the meeting named `name` as active.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the meeting.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` once the meeting is active.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeetingError::MeetingNotFound`] if the set has no meeting
+    /// named `name`; the active meeting is then unchanged.
+    pub fn set_active(&mut self, name: &str) -> Result<(), MeetingError> {
+        if !self.meetings.contains_key(name) {
+            return Err(MeetingError::MeetingNotFound(name.to_string()));
+        }
+        self.active = Some(name.to_string());
+        Ok(())
+    }
+
+    /// Clears the active mark without removing any meeting.
+    pub fn clear_active(&mut self) {
+        self.active = None;
+    }
+
+    /// Returns the name of the active meeting, if one is marked.
+    #[must_use]
+    pub fn active_name(&self) -> Option<&str> {
+        self.active.as_deref()
+    }
+
+    /// Returns the active meeting, if one is marked.
+    #[must_use]
+    pub fn active(&self) -> Option<&Meeting> {
+        self.active.as_deref().and_then(|name| self.get(name))
+    }
+
+    /// Returns the active meeting for changing it, if one is marked.
+    pub fn active_mut(&mut self) -> Option<&mut Meeting> {
+        let name = self.active.as_deref()?;
+        self.meetings.get_mut(name)
+    }
+
+    /// Returns the combined cost of every meeting in the set.
+    ///
+    /// # Returns
+    ///
+    /// The sum of each meeting's [`Meeting::total_cost`] in dollars.
+    #[must_use]
+    pub fn total_cost_all(&self) -> f64 {
+        self.meetings.values().map(Meeting::total_cost).sum()
+    }
+
+    /// Returns how many meetings in the set are running.
+    #[must_use]
+    pub fn running_count(&self) -> usize {
+        self.meetings.values().filter(|m| m.is_running()).count()
+    }
+}
+
+impl From<MeetingSet> for MeetingSetState {
+    fn from(set: MeetingSet) -> Self {
+        Self {
+            meetings: set
+                .meetings
+                .iter()
+                .map(|(name, meeting)| (name.clone(), MeetingSnapshot::from(meeting)))
+                .collect(),
+            active: set.active,
+        }
+    }
+}
+
+impl TryFrom<MeetingSetState> for MeetingSet {
+    type Error = MeetingError;
+
+    fn try_from(state: MeetingSetState) -> Result<Self, Self::Error> {
+        let meetings = state
+            .meetings
+            .into_iter()
+            .map(|(name, snapshot)| Ok((name, Meeting::from_state(snapshot)?)))
+            .collect::<Result<BTreeMap<_, _>, MeetingError>>()?;
+        let active = state.active.filter(|name| meetings.contains_key(name));
+        Ok(Self { meetings, active })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    use crate::testing::{assert_cost_eq, engineer, manager, CENT};
+
+    #[test]
+    fn names_are_unique_and_removal_clears_active() {
+        let mut set = MeetingSet::new();
+        assert!(set.is_empty());
+        set.create("Main").unwrap();
+        set.create("Huddle").unwrap();
+        assert_eq!(
+            set.create("Main").unwrap_err(),
+            MeetingError::MeetingExists("Main".into())
+        );
+        assert_eq!(set.names().collect::<Vec<_>>(), ["Huddle", "Main"]);
+
+        assert!(set.active().is_none());
+        assert_eq!(
+            set.set_active("Retro"),
+            Err(MeetingError::MeetingNotFound("Retro".into()))
+        );
+        set.set_active("Huddle").unwrap();
+        set.active_mut().unwrap().set_title("Side channel");
+        assert_eq!(set.get("Huddle").unwrap().title(), Some("Side channel"));
+
+        assert!(set.remove("Main").is_some());
+        assert_eq!(set.active_name(), Some("Huddle"));
+        assert!(set.remove("Huddle").is_some());
+        assert_eq!(set.active_name(), None);
+        assert!(set.remove("Huddle").is_none());
+        assert_eq!(set.len(), 0);
+    }
+
+    #[test]
+    fn aggregates_cover_every_meeting() {
+        let mut set = MeetingSet::new();
+        let main = set.create("Main").unwrap();
+        main.add_attendee(&engineer(), 3);
+        main.set_elapsed(Duration::from_mins(10));
+        let huddle = set.create("Huddle").unwrap();
+        huddle.add_attendee(&manager(), 1);
+        huddle.set_elapsed(Duration::from_mins(5));
+        assert_cost_eq(set.total_cost_all(), 40.0, CENT);
+        assert_eq!(set.running_count(), 0);
+
+        set.get_mut("Main").unwrap().start();
+        assert_eq!(set.running_count(), 1);
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let mut set = MeetingSet::new();
+        let main = set.create("Main").unwrap();
+        main.add_attendee(&engineer(), 2);
+        main.set_elapsed(Duration::from_mins(30));
+        set.create("Huddle").unwrap().start();
+        set.set_active("Huddle").unwrap();
+
+        let json = serde_json::to_string(&set).unwrap();
+        let restored: MeetingSet = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.names().collect::<Vec<_>>(), ["Huddle", "Main"]);
+        assert_eq!(restored.active_name(), Some("Huddle"));
+        assert!(restored.active().unwrap().is_running());
+        let main = restored.get("Main").unwrap();
+        assert_eq!(main.attendee_count("Engineer"), Some(2));
+        assert_cost_eq(main.total_cost(), 60.0, CENT);
+
+        let dangling = r#"{"meetings":{},"active":"Gone"}"#;
+        let empty: MeetingSet = serde_json::from_str(dangling).unwrap();
+        assert!(empty.is_empty() && empty.active_name().is_none());
+    }
+
+    #[test]
+    fn round_trip_keeps_mid_meeting_joins_and_settings() {
+        let mut set = MeetingSet::new();
+        let main = set.create("Main").unwrap();
+        main.set_budget(100.0).unwrap();
+        main.set_max_duration(Duration::from_hours(1));
+        main.set_strict_duplicate_names(true);
+        main.add_attendee(&engineer(), 2);
+        main.set_elapsed(Duration::from_mins(20));
+        main.add_attendee_attention(&manager(), 1, 0.5).unwrap();
+        main.set_elapsed(Duration::from_mins(30));
+        let before = main.total_cost_cents();
+
+        let json = serde_json::to_string(&set).unwrap();
+        let restored: MeetingSet = serde_json::from_str(&json).unwrap();
+        let main = restored.get("Main").unwrap();
+        assert_eq!(main.total_cost_cents(), before);
+        assert_eq!(main.attention("Manager"), Some(0.5));
+        assert_eq!(main.budget(), Some(100.0));
+        assert_eq!(main.max_duration(), Some(Duration::from_hours(1)));
+        assert!(main.strict_duplicate_names());
+    }
+
+    #[test]
+    fn round_trip_keeps_estimate_markers() {
+        let mut banded = engineer().with_salary_range(100_000, 140_000).unwrap();
+        banded.set_approximate(true);
+        let mut set = MeetingSet::new();
+        let main = set.create("Main").unwrap();
+        main.add_attendee(&banded, 2);
+        main.add_attendee(&manager(), 1);
+        main.set_elapsed(Duration::from_mins(10));
+        let (low, high) = main.cost_interval(0.9);
+
+        let json = serde_json::to_string(&set).unwrap();
+        let restored: MeetingSet = serde_json::from_str(&json).unwrap();
+        let main = restored.get("Main").unwrap();
+        assert!(main.is_approximate());
+        assert_cost_eq(main.cost_interval(0.9).0, low, CENT);
+        assert_cost_eq(main.cost_interval(0.9).1, high, CENT);
+        assert!(main.describe().contains(crate::report::APPROXIMATE_NOTE));
+    }
+}
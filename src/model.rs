@@ -1,8 +1,21 @@
+use std::hash::{Hash, Hasher};
+use std::time::{Duration, SystemTime};
+
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-/// Number of milliseconds in a typical work year (2,000 hours).
-pub const MILLIS_PER_WORK_YEAR: f64 = 2000.0 * 60.0 * 60.0 * 1000.0;
+/// Number of working hours in a typical work year.
+pub const DEFAULT_WORK_HOURS_PER_YEAR: f64 = 2000.0;
+
+/// Number of milliseconds in one hour.
+pub const MILLIS_PER_HOUR: f64 = 60.0 * 60.0 * 1000.0;
+
+/// Number of milliseconds in a work year of [`DEFAULT_WORK_HOURS_PER_YEAR`]
+/// hours, for exact integer cost arithmetic.
+pub const MILLIS_PER_WORK_YEAR: u128 = 2000 * 60 * 60 * 1000;
+
+/// Number of working hours in a working day, used by [`WorkYear::calendar`].
+pub const HOURS_PER_WORK_DAY: u32 = 8;
 
 /// Errors related to employee category validation.
 #[derive(Debug, Error)]
@@ -14,13 +27,77 @@ pub enum EmployeeCategoryError {
     /// Title must not be empty.
     #[error("Title must not be empty")]
     EmptyTitle,
+
+    /// Hourly rates and working hours must be positive numbers.
+    #[error("Hourly rate and hours per year must be positive")]
+    InvalidHourlyRate,
+
+    /// A salary range must contain the salary.
+    #[error("Salary range must contain the salary")]
+    InvalidSalaryRange,
 }
 
 /// Represents an employee category (e.g., Engineer, Manager) with a yearly salary.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+///
+/// Categories compare equal when their title, salary, salary range, source,
+/// default count, observer flag and approximate flag match; when the salary
+/// was last updated does not matter.
+///
+/// Observers, such as customers or external guests, are created with
+/// [`EmployeeCategory::observer`] and have no salary, so they are listed in a
+/// meeting without adding to its cost.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EmployeeCategory {
     title: String,
     salary: u64,
+    /// Where the salary figure came from, for auditing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    source: Option<String>,
+    /// Attendee count suggested when adding this category without a count.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    default_count: Option<u32>,
+    /// When the salary was last set, for spotting stale figures.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    updated_at: Option<SystemTime>,
+    /// When attendees of the category were last added, for evicting unused
+    /// categories.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    last_used: Option<SystemTime>,
+    /// Whether the category lists guests who cost nothing.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    observer: bool,
+    /// Whether the salary is an estimate, such as the midpoint of a band.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    approximate: bool,
+    /// Lowest and highest salary the true figure may be, in dollars.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    salary_range: Option<(u64, u64)>,
+}
+
+impl PartialEq for EmployeeCategory {
+    fn eq(&self, other: &Self) -> bool {
+        self.title == other.title
+            && self.salary == other.salary
+            && self.source == other.source
+            && self.default_count == other.default_count
+            && self.observer == other.observer
+            && self.approximate == other.approximate
+            && self.salary_range == other.salary_range
+    }
+}
+
+impl Eq for EmployeeCategory {}
+
+impl Hash for EmployeeCategory {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.title.hash(state);
+        self.salary.hash(state);
+        self.source.hash(state);
+        self.default_count.hash(state);
+        self.observer.hash(state);
+        self.approximate.hash(state);
+        self.salary_range.hash(state);
+    }
 }
 
 impl EmployeeCategory {
@@ -59,7 +136,266 @@ impl EmployeeCategory {
         if salary == 0 {
             return Err(EmployeeCategoryError::InvalidSalary);
         }
-        Ok(Self { title, salary })
+        Ok(Self {
+            title,
+            salary,
+            source: None,
+            default_count: None,
+            updated_at: Some(SystemTime::now()),
+            last_used: None,
+            observer: false,
+            approximate: false,
+            salary_range: None,
+        })
+    }
+
+    /// Creates a category from an hourly rate, such as a contractor's quote,
+    /// by converting it to the equivalent annual salary.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::EmployeeCategory;
+    /// let contractor = EmployeeCategory::from_hourly("Contractor", 85.0, 2000.0).unwrap();
+    /// assert_eq!(contractor.salary(), 170_000);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - Category title.
+    /// * `hourly_rate` - Rate in dollars per hour.
+    /// * `hours_per_year` - Working hours the rate is billed for in a year.
+    ///
+    /// # Returns
+    ///
+    /// A new [`EmployeeCategory`] whose salary is `hourly_rate * hours_per_year`
+    /// rounded to whole dollars.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmployeeCategoryError::InvalidHourlyRate`] if `hourly_rate` or
+    /// `hours_per_year` is not a positive finite number, and otherwise the
+    /// errors of [`EmployeeCategory::new`].
+    ///
+    /// # See Also
+    /// * [`EmployeeCategory::hourly_rate`]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn from_hourly<T: Into<String>>(
+        title: T,
+        hourly_rate: f64,
+        hours_per_year: f64,
+    ) -> Result<Self, EmployeeCategoryError> {
+        let valid = |value: f64| value.is_finite() && value > 0.0;
+        if !valid(hourly_rate) || !valid(hours_per_year) {
+            return Err(EmployeeCategoryError::InvalidHourlyRate);
+        }
+        Self::new(title, (hourly_rate * hours_per_year).round() as u64)
+    }
+
+    /// Creates an observer category, for attendees such as customers or
+    /// external guests who are listed in a meeting but cost nothing.
+    ///
+    /// Observers have a salary of zero, which [`EmployeeCategory::new`]
+    /// rejects, and are counted apart from paid attendees by
+    /// [`Meeting::observer_count`](crate::Meeting::observer_count).
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::EmployeeCategory;
+    /// let guest = EmployeeCategory::observer("Customer").unwrap();
+    /// assert!(guest.is_observer());
+    /// assert_eq!(guest.salary(), 0);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - Category title.
+    ///
+    /// # Returns
+    ///
+    /// A new observer [`EmployeeCategory`] on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmployeeCategoryError::EmptyTitle`] if `title` is empty.
+    ///
+    /// # See Also
+    /// * [`EmployeeCategory::is_observer`]
+    pub fn observer<T: Into<String>>(title: T) -> Result<Self, EmployeeCategoryError> {
+        let title = title.into();
+        if title.trim().is_empty() {
+            return Err(EmployeeCategoryError::EmptyTitle);
+        }
+        Ok(Self {
+            title,
+            salary: 0,
+            source: None,
+            default_count: None,
+            updated_at: None,
+            last_used: None,
+            observer: true,
+            approximate: false,
+            salary_range: None,
+        })
+    }
+
+    /// Returns `true` for categories created with [`EmployeeCategory::observer`].
+    #[must_use]
+    pub fn is_observer(&self) -> bool {
+        self.observer
+    }
+
+    /// Returns the category with its salary source set to `source`.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::EmployeeCategory;
+    /// let cat = EmployeeCategory::new("Engineer", 120_000)
+    ///     .unwrap()
+    ///     .with_source("HR band sheet v7");
+    /// assert_eq!(cat.source(), Some("HR band sheet v7"));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Where the salary figure came from.
+    ///
+    /// # Returns
+    ///
+    /// The updated category.
+    ///
+    /// # See Also
+    /// * [`EmployeeCategory::set_source`]
+    #[must_use]
+    pub fn with_source<T: Into<String>>(mut self, source: T) -> Self {
+        self.set_source(Some(source.into()));
+        self
+    }
+
+    /// Sets or clears where the salary figure came from.
+    ///
+    /// Blank sources are stored as `None`.
+    pub fn set_source(&mut self, source: Option<String>) {
+        self.source = source.filter(|s| !s.trim().is_empty());
+    }
+
+    /// Returns where the salary figure came from, e.g. `"levels.fyi 2024-03"`.
+    #[must_use]
+    pub fn source(&self) -> Option<&str> {
+        self.source.as_deref()
+    }
+
+    /// Returns the category with `count` attendees suggested when it is added
+    /// without a count.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::EmployeeCategory;
+    /// let cat = EmployeeCategory::new("Engineer", 120_000)
+    ///     .unwrap()
+    ///     .with_default_count(4);
+    /// assert_eq!(cat.default_count(), Some(4));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - Suggested number of attendees; `0` clears the suggestion.
+    ///
+    /// # Returns
+    ///
+    /// The updated category.
+    ///
+    /// # See Also
+    /// * [`Settings::attendee_count_default`](crate::Settings::attendee_count_default)
+    #[must_use]
+    pub fn with_default_count(mut self, count: u32) -> Self {
+        self.set_default_count(Some(count));
+        self
+    }
+
+    /// Sets or clears the suggested attendee count.
+    ///
+    /// A count of zero is stored as `None`.
+    pub fn set_default_count(&mut self, count: Option<u32>) {
+        self.default_count = count.filter(|&c| c > 0);
+    }
+
+    /// Returns the attendee count suggested when adding this category.
+    #[must_use]
+    pub fn default_count(&self) -> Option<u32> {
+        self.default_count
+    }
+
+    /// Returns `true` if the salary is an estimate rather than an exact
+    /// figure, e.g. for categories loaded with
+    /// [`load_categories_banded`](crate::load_categories_banded).
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::EmployeeCategory;
+    /// let mut cat = EmployeeCategory::new("Engineer", 112_500).unwrap();
+    /// cat.set_approximate(true);
+    /// assert!(cat.is_approximate());
+    /// cat.set_salary(115_000).unwrap();
+    /// assert!(!cat.is_approximate());
+    /// ```
+    ///
+    /// # See Also
+    /// * [`Meeting::is_approximate`](crate::Meeting::is_approximate)
+    #[must_use]
+    pub fn is_approximate(&self) -> bool {
+        self.approximate
+    }
+
+    /// Returns the category with the salary known to lie between `min` and
+    /// `max`, for estimating a meeting's cost as a range.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::EmployeeCategory;
+    /// let cat = EmployeeCategory::new("Engineer", 120_000)
+    ///     .unwrap()
+    ///     .with_salary_range(100_000, 150_000)
+    ///     .unwrap();
+    /// assert_eq!(cat.salary_range(), Some((100_000, 150_000)));
+    /// assert!(cat.clone().with_salary_range(125_000, 150_000).is_err());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - Lowest likely annual salary in dollars.
+    /// * `max` - Highest likely annual salary in dollars.
+    ///
+    /// # Returns
+    ///
+    /// The updated category.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmployeeCategoryError::InvalidSalaryRange`] unless
+    /// `min <= salary <= max`, and for observers.
+    ///
+    /// # See Also
+    /// * [`Meeting::cost_interval`](crate::Meeting::cost_interval)
+    pub fn with_salary_range(mut self, min: u64, max: u64) -> Result<Self, EmployeeCategoryError> {
+        if self.observer || !(min <= self.salary && self.salary <= max) {
+            return Err(EmployeeCategoryError::InvalidSalaryRange);
+        }
+        self.salary_range = Some((min, max));
+        Ok(self)
+    }
+
+    /// Returns the lowest and highest salary the category may have, if known.
+    #[must_use]
+    pub fn salary_range(&self) -> Option<(u64, u64)> {
+        self.salary_range
+    }
+
+    /// Marks the salary as an estimate or as exact.
+    ///
+    /// Setting a salary with [`EmployeeCategory::set_salary`] marks it exact
+    /// again.
+    pub fn set_approximate(&mut self, approximate: bool) {
+        self.approximate = approximate;
     }
 
     /// Returns the title of the employee category.
@@ -111,7 +447,138 @@ impl EmployeeCategory {
         self.salary
     }
 
-    /// Computes the cost in dollars for each millisecond of time.
+    /// Returns the hourly rate equivalent to the annual salary.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::EmployeeCategory;
+    /// let cat = EmployeeCategory::new("Engineer", 100_000).unwrap();
+    /// assert!((cat.hourly_rate(2000.0) - 50.0).abs() < 1e-9);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `hours_per_year` - Working hours the salary is spread across.
+    ///
+    /// # Returns
+    ///
+    /// The rate in dollars per hour.
+    ///
+    /// # See Also
+    /// * [`EmployeeCategory::from_hourly`]
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn hourly_rate(&self, hours_per_year: f64) -> f64 {
+        self.salary as f64 / hours_per_year
+    }
+
+    /// Changes the annual salary and records the time of the change.
+    ///
+    /// Giving an observer a salary turns it into a paid category, and the
+    /// new salary counts as exact, so any salary range is cleared.
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::EmployeeCategory;
+    /// let mut cat = EmployeeCategory::new("Engineer", 100_000).unwrap();
+    /// cat.set_salary(110_000).unwrap();
+    /// assert_eq!(cat.salary(), 110_000);
+    /// assert!(cat.set_salary(0).is_err());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `salary` - New annual salary in dollars.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the salary was changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmployeeCategoryError::InvalidSalary`] if `salary` is zero.
+    /// The previous salary is kept in that case.
+    ///
+    /// # See Also
+    /// * [`EmployeeCategory::updated_at`]
+    pub fn set_salary(&mut self, salary: u64) -> Result<(), EmployeeCategoryError> {
+        if salary == 0 {
+            return Err(EmployeeCategoryError::InvalidSalary);
+        }
+        self.salary = salary;
+        self.updated_at = Some(SystemTime::now());
+        self.observer = false;
+        self.approximate = false;
+        self.salary_range = None;
+        Ok(())
+    }
+
+    /// Returns when the salary was last set, or `None` for categories saved
+    /// before the time was recorded.
+    #[must_use]
+    pub fn updated_at(&self) -> Option<SystemTime> {
+        self.updated_at
+    }
+
+    /// Overrides when the salary was last set, e.g. to keep the original
+    /// time when only the title changes.
+    pub fn set_updated_at(&mut self, at: Option<SystemTime>) {
+        self.updated_at = at;
+    }
+
+    /// Returns when the category was last used, or `None` if it never was.
+    ///
+    /// # See Also
+    /// * [`CategoryUsage`](crate::CategoryUsage)
+    #[must_use]
+    pub fn last_used(&self) -> Option<SystemTime> {
+        self.last_used
+    }
+
+    /// Sets when the category was last used, so it is saved with the
+    /// category.
+    pub fn set_last_used(&mut self, at: Option<SystemTime>) {
+        self.last_used = at;
+    }
+
+    /// Checks whether the salary is older than `max_age`.
+    ///
+    /// Categories without a recorded update time are of unknown age and
+    /// count as stale, so salaries from older files get reviewed too.
+    /// Observers have no salary and are never stale.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::EmployeeCategory;
+    /// use std::time::{Duration, SystemTime};
+    /// let mut cat = EmployeeCategory::new("Engineer", 100_000).unwrap();
+    /// let year = Duration::from_hours(365 * 24);
+    /// assert!(!cat.is_stale(year));
+    /// cat.set_updated_at(SystemTime::now().checked_sub(2 * year));
+    /// assert!(cat.is_stale(year));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `max_age` - Longest time a salary may go without an update.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the salary should be refreshed.
+    ///
+    /// # See Also
+    /// * [`EmployeeCategory::set_salary`]
+    #[must_use]
+    pub fn is_stale(&self, max_age: Duration) -> bool {
+        !self.observer
+            && self.updated_at.is_none_or(|at| {
+                SystemTime::now()
+                    .duration_since(at)
+                    .is_ok_and(|age| age > max_age)
+            })
+    }
+
+    /// Computes the cost in dollars for each millisecond of time, assuming a
+    /// work year of [`DEFAULT_WORK_HOURS_PER_YEAR`] hours.
     ///
     /// ## Example
     /// ```
@@ -131,16 +598,130 @@ impl EmployeeCategory {
     ///
     /// # See Also
     /// * [`EmployeeCategory::salary`]
+    /// * [`EmployeeCategory::cost_per_millisecond_for`]
     #[must_use]
-    #[allow(clippy::cast_precision_loss)]
     pub fn cost_per_millisecond(&self) -> f64 {
-        self.salary as f64 / MILLIS_PER_WORK_YEAR
+        self.cost_per_millisecond_for(DEFAULT_WORK_HOURS_PER_YEAR)
+    }
+
+    /// Computes the cost in dollars for each millisecond of time given the
+    /// number of working hours in a year.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::EmployeeCategory;
+    /// let cat = EmployeeCategory::new("Contractor", 180_000).unwrap();
+    /// let per_hour = cat.cost_per_millisecond_for(1800.0) * 3_600_000.0;
+    /// assert!((per_hour - 100.0).abs() < 1e-9);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `work_hours_per_year` - Working hours the salary is spread across.
+    ///
+    /// # Returns
+    ///
+    /// The cost per millisecond in dollars.
+    ///
+    /// # See Also
+    /// * [`EmployeeCategory::cost_per_millisecond`]
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn cost_per_millisecond_for(&self, work_hours_per_year: f64) -> f64 {
+        self.salary as f64 / (work_hours_per_year * MILLIS_PER_HOUR)
+    }
+}
+
+/// The working time in one calendar year, for costing meetings against the
+/// days actually worked in that year rather than
+/// [`DEFAULT_WORK_HOURS_PER_YEAR`].
+///
+/// ## Example
+/// ```
+/// use meeting_cost_tracker::WorkYear;
+/// let year = WorkYear::calendar(2024).with_holidays(10);
+/// assert_eq!(year.days(), 252);
+/// assert_eq!(year.hours(), 2016);
+/// ```
+///
+/// # See Also
+/// * [`Meeting::total_cost_with`](crate::Meeting::total_cost_with)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WorkYear {
+    days: u32,
+}
+
+impl WorkYear {
+    /// Creates the work year of `year`, with one working day of
+    /// [`HOURS_PER_WORK_DAY`] hours for each Monday to Friday.
+    ///
+    /// Leap years are taken into account, so a year has 260 to 262 weekdays
+    /// depending on the weekday it starts on.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - Year of the Gregorian calendar.
+    ///
+    /// # Returns
+    ///
+    /// A [`WorkYear`] without holidays; see [`WorkYear::with_holidays`].
+    #[must_use]
+    pub fn calendar(year: i32) -> Self {
+        let leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+        let length = if leap { 366 } else { 365 };
+        // 0 is Sunday; see Sakamoto's day-of-week algorithm.
+        let y = i64::from(year) - 1;
+        let first = (y + y.div_euclid(4) - y.div_euclid(100) + y.div_euclid(400) + 1).rem_euclid(7);
+        // Whole weeks contribute five weekdays; the one or two days left over
+        // have the weekday of January 1st and the day after.
+        let extra = (0..length - 364)
+            .filter(|offset| !matches!((first + offset) % 7, 0 | 6))
+            .count();
+        Self {
+            days: 260 + u32::try_from(extra).unwrap_or(0),
+        }
+    }
+
+    /// Removes `count` public holidays or other days off from the year.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - Working days not worked.
+    ///
+    /// # Returns
+    ///
+    /// The shortened [`WorkYear`], with no fewer than zero days.
+    #[must_use]
+    pub fn with_holidays(self, count: u32) -> Self {
+        Self {
+            days: self.days.saturating_sub(count),
+        }
+    }
+
+    /// Returns the number of working days.
+    #[must_use]
+    pub fn days(&self) -> u32 {
+        self.days
+    }
+
+    /// Returns the number of working hours.
+    #[must_use]
+    pub fn hours(&self) -> u32 {
+        self.days * HOURS_PER_WORK_DAY
+    }
+
+    /// Returns the number of working milliseconds, for exact integer cost
+    /// arithmetic like [`MILLIS_PER_WORK_YEAR`].
+    #[must_use]
+    pub fn millis(&self) -> u128 {
+        u128::from(self.hours()) * 60 * 60 * 1000
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::assert_cost_eq;
 
     #[test]
     fn new_validates_input() {
@@ -151,6 +732,150 @@ mod tests {
         assert_eq!(cat.salary(), 1);
     }
 
+    #[test]
+    fn calendar_years_count_weekdays() {
+        // 2023 starts on a Sunday, 2025 on a Wednesday.
+        assert_eq!(WorkYear::calendar(2023).days(), 260);
+        assert_eq!(WorkYear::calendar(2025).days(), 261);
+        // Leap years: 2024 starts on a Monday, 2028 on a Saturday and 2032
+        // on a Thursday.
+        assert_eq!(WorkYear::calendar(2024).days(), 262);
+        assert_eq!(WorkYear::calendar(2028).days(), 260);
+        assert_eq!(WorkYear::calendar(2032).days(), 262);
+        // 2000 is a leap year starting on a Saturday; 2100 is not a leap year.
+        assert_eq!(WorkYear::calendar(2000).days(), 260);
+        assert_eq!(WorkYear::calendar(2100).days(), 261);
+    }
+
+    #[test]
+    fn holidays_shorten_the_year() {
+        let year = WorkYear::calendar(2025).with_holidays(11);
+        assert_eq!(year.days(), 250);
+        assert_eq!(year.hours(), 2000);
+        assert_eq!(year.millis(), MILLIS_PER_WORK_YEAR);
+        assert_eq!(year.with_holidays(u32::MAX).days(), 0);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn hourly_rate_round_trips() {
+        for rate in [12.5, 50.0, 85.0, 212.25] {
+            let cat = EmployeeCategory::from_hourly("Contractor", rate, 2000.0).unwrap();
+            assert_eq!(cat.hourly_rate(2000.0), rate);
+        }
+        let cat = EmployeeCategory::new("Engineer", 123_457).unwrap();
+        let back = EmployeeCategory::from_hourly("Engineer", cat.hourly_rate(2000.0), 2000.0);
+        assert_eq!(back.unwrap().salary(), 123_457);
+        assert_eq!(
+            EmployeeCategory::from_hourly("Temp", 33.333, 1800.0)
+                .unwrap()
+                .salary(),
+            59_999
+        );
+    }
+
+    #[test]
+    fn from_hourly_rejects_bad_rates() {
+        for (rate, hours) in [
+            (0.0, 2000.0),
+            (-5.0, 2000.0),
+            (f64::NAN, 2000.0),
+            (50.0, 0.0),
+        ] {
+            assert!(matches!(
+                EmployeeCategory::from_hourly("Contractor", rate, hours),
+                Err(EmployeeCategoryError::InvalidHourlyRate)
+            ));
+        }
+        assert!(matches!(
+            EmployeeCategory::from_hourly("", 50.0, 2000.0),
+            Err(EmployeeCategoryError::EmptyTitle)
+        ));
+        assert!(matches!(
+            EmployeeCategory::from_hourly("Intern", 0.0001, 2000.0),
+            Err(EmployeeCategoryError::InvalidSalary)
+        ));
+    }
+
+    #[test]
+    fn observer_allows_zero_salary() {
+        let guest = EmployeeCategory::observer("Customer").unwrap();
+        assert!(guest.is_observer());
+        assert_eq!(guest.salary(), 0);
+        assert!((guest.cost_per_millisecond()).abs() < f64::EPSILON);
+        assert!(!guest.is_stale(Duration::ZERO));
+        assert!(matches!(
+            EmployeeCategory::observer(" "),
+            Err(EmployeeCategoryError::EmptyTitle)
+        ));
+        assert!(!EmployeeCategory::new("dev", 1).unwrap().is_observer());
+
+        let mut paid = guest.clone();
+        paid.set_salary(50_000).unwrap();
+        assert!(!paid.is_observer());
+        assert_ne!(paid, guest);
+    }
+
+    #[test]
+    fn observer_flag_is_persisted() {
+        let guest = EmployeeCategory::observer("Customer").unwrap();
+        let text = toml::to_string(&guest).unwrap();
+        assert!(text.contains("observer = true"));
+        let loaded: EmployeeCategory = toml::from_str(&text).unwrap();
+        assert!(loaded.is_observer());
+        assert_eq!(loaded, guest);
+
+        let paid = EmployeeCategory::new("dev", 1).unwrap();
+        assert!(!toml::to_string(&paid).unwrap().contains("observer"));
+    }
+
+    #[test]
+    fn source_is_optional_and_persisted() {
+        let cat = EmployeeCategory::new("dev", 1).unwrap();
+        assert_eq!(cat.source(), None);
+        assert!(!toml::to_string(&cat).unwrap().contains("source"));
+
+        let cat = cat.with_source("levels.fyi 2024-03");
+        let text = toml::to_string(&cat).unwrap();
+        assert!(text.contains("source = \"levels.fyi 2024-03\""));
+        let parsed: EmployeeCategory = toml::from_str(&text).unwrap();
+        assert_eq!(parsed, cat);
+
+        let legacy: EmployeeCategory = toml::from_str("title = \"qa\"\nsalary = 5").unwrap();
+        assert_eq!(legacy.source(), None);
+
+        let mut cat = cat;
+        cat.set_source(Some("  ".into()));
+        assert_eq!(cat.source(), None);
+    }
+
+    #[test]
+    fn staleness_follows_salary_updates() {
+        let year = Duration::from_hours(365 * 24);
+        let mut cat = EmployeeCategory::new("dev", 100_000).unwrap();
+        assert!(!cat.is_stale(year));
+
+        cat.set_updated_at(SystemTime::now().checked_sub(year * 2));
+        assert!(cat.is_stale(year));
+        assert!(!cat.is_stale(year * 3));
+
+        let text = toml::to_string(&cat).unwrap();
+        let parsed: EmployeeCategory = toml::from_str(&text).unwrap();
+        assert_eq!(parsed.updated_at(), cat.updated_at());
+
+        cat.set_salary(110_000).unwrap();
+        assert!(!cat.is_stale(year));
+        assert!(matches!(
+            cat.set_salary(0),
+            Err(EmployeeCategoryError::InvalidSalary)
+        ));
+        assert_eq!(cat.salary(), 110_000);
+
+        let legacy: EmployeeCategory = toml::from_str("title = \"qa\"\nsalary = 5").unwrap();
+        assert_eq!(legacy.updated_at(), None);
+        assert!(legacy.is_stale(year));
+    }
+
     #[test]
     fn accessors_work() {
         let cat = EmployeeCategory::new("manager", 10_000).unwrap();
@@ -162,10 +887,21 @@ mod tests {
     fn cost_per_millisecond_calculates_float() {
         let cat = EmployeeCategory::new("engineer", 720_000_000).unwrap();
         let cost = cat.cost_per_millisecond();
-        assert!((cost - 0.1).abs() < f64::EPSILON);
+        assert_cost_eq(cost, 0.1, f64::EPSILON);
 
         let small = EmployeeCategory::new("low", 100_000).unwrap();
         let cost = small.cost_per_millisecond();
         assert!(cost > 0.0);
     }
+
+    #[test]
+    fn cost_per_millisecond_for_respects_work_hours() {
+        let cat = EmployeeCategory::new("engineer", 176_000).unwrap();
+        let hourly = cat.cost_per_millisecond_for(1760.0) * MILLIS_PER_HOUR;
+        assert_cost_eq(hourly, 100.0, 1e-9);
+
+        let cat = EmployeeCategory::new("engineer", 208_000).unwrap();
+        let hourly = cat.cost_per_millisecond_for(2080.0) * MILLIS_PER_HOUR;
+        assert_cost_eq(hourly, 100.0, 1e-9);
+    }
 }
@@ -0,0 +1,806 @@
+use std::fmt::{self, Write as _};
+use std::io::{self, Write};
+use std::time::Duration;
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::meeting::{CategoryCost, Meeting};
+use crate::model::EmployeeCategory;
+
+/// Disclaimer added to reports and exports of an
+/// [approximate](Meeting::is_approximate) meeting.
+pub const APPROXIMATE_NOTE: &str = "Approximate figures: some salaries are estimates.";
+
+/// Formats a duration as `HH:MM:SS`, dropping fractions of a second.
+///
+/// Hours are not wrapped at a day, so 25 hours is `25:00:00`.
+///
+/// ## Example
+/// ```
+/// use std::time::Duration;
+/// use meeting_cost_tracker::format_duration;
+/// assert_eq!(format_duration(Duration::from_secs(3661)), "01:01:01");
+/// assert_eq!(format_duration(Duration::from_hours(25)), "25:00:00");
+/// ```
+///
+/// # Arguments
+///
+/// * `d` - Duration to format.
+///
+/// # Returns
+///
+/// The formatted duration, with at least two digits for the hours.
+///
+/// # See Also
+/// * [`Meeting::elapsed_formatted`]
+#[must_use]
+pub fn format_duration(d: Duration) -> String {
+    let secs = d.as_secs();
+    format!(
+        "{:02}:{:02}:{:02}",
+        secs / 3600,
+        (secs % 3600) / 60,
+        secs % 60
+    )
+}
+
+/// Pads `text` to `width` terminal columns, on the right or the left.
+fn pad(text: &str, width: usize, right_align: bool) -> String {
+    let fill = " ".repeat(width.saturating_sub(text.width()));
+    if right_align {
+        format!("{fill}{text}")
+    } else {
+        format!("{text}{fill}")
+    }
+}
+
+/// Block characters from lowest to highest, used by [`mini_sparkline`].
+const SPARK_LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Renders `values` as a one-line sparkline of `width` block characters.
+///
+/// Longer series are sampled down to the last value of each slot and shorter
+/// ones are stretched, so the result always has `width` characters. Values
+/// are scaled between the smallest and largest of them; a constant series is
+/// drawn at the lowest level, as are values that are not finite.
+///
+/// ## Example
+/// ```
+/// use meeting_cost_tracker::mini_sparkline;
+/// assert_eq!(mini_sparkline(&[0.0, 1.0, 2.0, 3.0], 4), "▁▃▆█");
+/// assert_eq!(mini_sparkline(&[5.0, 5.0], 3), "▁▁▁");
+/// ```
+///
+/// # Arguments
+///
+/// * `values` - Series to draw, oldest first.
+/// * `width` - Number of characters to draw.
+///
+/// # Returns
+///
+/// The sparkline, or an empty string if `values` is empty or `width` is zero.
+///
+/// # See Also
+/// * [`Meeting::category_cost_series`]
+#[must_use]
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+pub fn mini_sparkline(values: &[f64], width: usize) -> String {
+    if values.is_empty() || width == 0 {
+        return String::new();
+    }
+    let sampled: Vec<f64> = (1..=width)
+        .map(|slot| values[(slot * values.len()).div_ceil(width) - 1])
+        .collect();
+    let finite = sampled.iter().copied().filter(|v| v.is_finite());
+    let min = finite.clone().fold(f64::INFINITY, f64::min);
+    let max = finite.fold(f64::NEG_INFINITY, f64::max);
+    let top = (SPARK_LEVELS.len() - 1) as f64;
+    sampled
+        .into_iter()
+        .map(|v| {
+            let level = if v.is_finite() && max > min {
+                ((v - min) / (max - min) * top).round() as usize
+            } else {
+                0
+            };
+            SPARK_LEVELS[level]
+        })
+        .collect()
+}
+
+/// Column titles of the rows returned by [`breakdown_rows`].
+pub const BREAKDOWN_HEADER: [&str; 4] = ["Title", "Count", "Salary", "Cost"];
+
+/// Formats a cost in dollars, to the cent or rounded to whole dollars.
+fn cost_cell(cost: f64, dollars_only: bool) -> String {
+    if dollars_only {
+        format!("${:.0}", cost.round())
+    } else {
+        format!("${cost:.2}")
+    }
+}
+
+/// Formats one group of [`Meeting::breakdown`] as table cells.
+fn breakdown_cells(row: CategoryCost, dollars_only: bool) -> [String; 4] {
+    [
+        row.title,
+        row.count.to_string(),
+        format!("${}", row.salary),
+        cost_cell(row.cost, dollars_only),
+    ]
+}
+
+/// Formats the total row of a breakdown table.
+fn total_cells(meeting: &Meeting, dollars_only: bool) -> [String; 4] {
+    [
+        "Total".to_string(),
+        meeting.total_attendees().to_string(),
+        String::new(),
+        cost_cell(meeting.total_cost(), dollars_only),
+    ]
+}
+
+/// Returns the cost breakdown as rows of text cells, ready to feed a table
+/// widget such as a ratatui `Table`.
+///
+/// The cells are formatted like [`Meeting::describe`]: one row per attendee
+/// group, then a total row with the paid headcount and total cost. Column
+/// titles are in [`BREAKDOWN_HEADER`].
+///
+/// ## Example
+/// ```
+/// use meeting_cost_tracker::{breakdown_rows, EmployeeCategory, Meeting};
+/// let mut meeting = Meeting::new();
+/// meeting.add_attendee(&EmployeeCategory::new("Engineer", 120_000).unwrap(), 3);
+/// let rows = breakdown_rows(&meeting);
+/// assert_eq!(rows[0], ["Engineer", "3", "$120000", "$0.00"]);
+/// assert_eq!(rows[1], ["Total", "3", "", "$0.00"]);
+/// ```
+///
+/// # Arguments
+///
+/// * `meeting` - Meeting to describe.
+///
+/// # Returns
+///
+/// Rows of [`BREAKDOWN_HEADER`] cells, ending with the total row.
+///
+/// # See Also
+/// * [`breakdown_rows_with`]
+/// * [`Meeting::breakdown`]
+#[must_use]
+pub fn breakdown_rows(meeting: &Meeting) -> Vec<Vec<String>> {
+    breakdown_rows_with(meeting, false)
+}
+
+/// Like [`breakdown_rows`], optionally rounding every cost cell, the total
+/// included, to whole dollars for rough reports.
+///
+/// Each cell is rounded on its own, so the rounded group costs need not add
+/// up to the rounded total.
+///
+/// ## Example
+/// ```
+/// use std::time::Duration;
+/// use meeting_cost_tracker::{breakdown_rows_with, EmployeeCategory, Meeting};
+/// let mut meeting = Meeting::new();
+/// meeting.add_attendee(&EmployeeCategory::new("Engineer", 100_000).unwrap(), 3);
+/// meeting.set_elapsed(Duration::from_mins(10));
+/// let rows = breakdown_rows_with(&meeting, true);
+/// assert_eq!(rows[0], ["Engineer", "3", "$100000", "$25"]);
+/// ```
+///
+/// # Arguments
+///
+/// * `meeting` - Meeting to describe.
+/// * `dollars_only` - Whether to round costs to whole dollars.
+///
+/// # Returns
+///
+/// Rows of [`BREAKDOWN_HEADER`] cells, ending with the total row.
+///
+/// # See Also
+/// * [`breakdown_rows`]
+/// * [`Meeting::to_markdown_summary_formatted`]
+#[must_use]
+pub fn breakdown_rows_with(meeting: &Meeting, dollars_only: bool) -> Vec<Vec<String>> {
+    meeting
+        .breakdown()
+        .into_iter()
+        .map(|row| breakdown_cells(row, dollars_only))
+        .chain(std::iter::once(total_cells(meeting, dollars_only)))
+        .map(Vec::from)
+        .collect()
+}
+
+impl Meeting {
+    /// Returns how long the meeting has run as `HH:MM:SS`, for use as a plain
+    /// stopwatch without attendees.
+    ///
+    /// ## Example
+    /// ```
+    /// use std::time::Duration;
+    /// use meeting_cost_tracker::Meeting;
+    /// let mut meeting = Meeting::new();
+    /// meeting.set_elapsed(Duration::from_secs(23 * 60 + 14));
+    /// assert_eq!(meeting.elapsed_formatted(), "00:23:14");
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// [`Meeting::duration`] formatted by [`format_duration`].
+    #[must_use]
+    pub fn elapsed_formatted(&self) -> String {
+        format_duration(self.duration())
+    }
+
+    /// Describes the meeting as a boxed table of its cost breakdown.
+    ///
+    /// The table has one row per attendee group and a total row, followed by
+    /// the duration, the burn rate and, when a budget is set, how long it
+    /// lasts. Approximate meetings end with [`APPROXIMATE_NOTE`]. Columns are
+    /// measured in terminal cells, so wide characters in titles stay aligned.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&EmployeeCategory::new("Engineer", 120_000).unwrap(), 3);
+    /// let report = meeting.describe();
+    /// assert!(report.contains("Engineer"));
+    /// assert!(report.contains("Total"));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// The multi-line report, ending in a newline.
+    ///
+    /// # See Also
+    /// * [`Meeting::describe_with`]
+    /// * [`Meeting::pretty_print`]
+    /// * [`Meeting::breakdown`]
+    #[must_use]
+    pub fn describe(&self) -> String {
+        self.describe_with(true)
+    }
+
+    /// Like [`Meeting::describe`], optionally leaving out groups that have
+    /// not cost anything, such as observers.
+    ///
+    /// The total row always covers the whole meeting.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&EmployeeCategory::new("Engineer", 120_000).unwrap(), 3);
+    /// assert!(!meeting.describe_with(false).contains("Engineer"));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `include_zero_cost` - Whether to list groups with a cost of $0.
+    ///
+    /// # Returns
+    ///
+    /// The multi-line report, ending in a newline.
+    ///
+    /// # See Also
+    /// * [`Meeting::describe`]
+    #[must_use]
+    pub fn describe_with(&self, include_zero_cost: bool) -> String {
+        let header = BREAKDOWN_HEADER;
+        let mut rows: Vec<[String; 4]> = self
+            .breakdown()
+            .into_iter()
+            .filter(|row| include_zero_cost || row.cost > 0.0)
+            .map(|row| breakdown_cells(row, false))
+            .collect();
+        let total = total_cells(self, false);
+
+        let mut widths = header.map(UnicodeWidthStr::width);
+        for row in rows.iter().chain(std::iter::once(&total)) {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.width());
+            }
+        }
+        let rule = |left: &str, mid: &str, right: &str| {
+            let parts: Vec<String> = widths.iter().map(|w| "─".repeat(w + 2)).collect();
+            format!("{left}{}{right}\n", parts.join(mid))
+        };
+        let line = |cells: [&str; 4]| {
+            let parts: Vec<String> = cells
+                .iter()
+                .zip(widths)
+                .enumerate()
+                .map(|(i, (cell, width))| format!(" {} ", pad(cell, width, i > 0)))
+                .collect();
+            format!("│{}│\n", parts.join("│"))
+        };
+
+        let mut out = rule("┌", "┬", "┐");
+        out.push_str(&line(header));
+        out.push_str(&rule("├", "┼", "┤"));
+        for row in rows.drain(..) {
+            out.push_str(&line(row.each_ref().map(String::as_str)));
+        }
+        out.push_str(&rule("├", "┼", "┤"));
+        out.push_str(&line(total.each_ref().map(String::as_str)));
+        out.push_str(&rule("└", "┴", "┘"));
+        let _ = writeln!(
+            out,
+            "Duration: {}  Burn rate: ${:.2}/min",
+            format_duration(self.duration()),
+            self.burn_rate_per_minute()
+        );
+        if let Some(eta) = self.time_until_budget_exhausted() {
+            let _ = writeln!(out, "Budget lasts another {}", format_duration(eta));
+        }
+        if self.is_approximate() {
+            let _ = writeln!(out, "{APPROXIMATE_NOTE}");
+        }
+        out
+    }
+
+    /// Summarizes the meeting as Markdown, e.g. for pasting into a wiki.
+    ///
+    /// The summary starts with the [title](Meeting::title) as a heading when
+    /// one is set, followed by a table of categories sorted by title and a
+    /// footer with the duration and total cost. Pipes in titles are escaped
+    /// so they do not split table cells.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let mut meeting = Meeting::new();
+    /// meeting.set_title("Standup");
+    /// meeting.add_attendee(&EmployeeCategory::new("Engineer", 120_000).unwrap(), 3);
+    /// let summary = meeting.to_markdown_summary();
+    /// assert!(summary.starts_with("## Standup\n"));
+    /// assert!(summary.contains("| Engineer | 3 | $0.00 |"));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// The Markdown summary, ending in a newline.
+    ///
+    /// # See Also
+    /// * [`Meeting::to_markdown_summary_with`]
+    /// * [`Meeting::describe`]
+    /// * [`Meeting::breakdown`]
+    #[must_use]
+    pub fn to_markdown_summary(&self) -> String {
+        self.to_markdown_summary_with(true)
+    }
+
+    /// Like [`Meeting::to_markdown_summary`], optionally leaving out groups
+    /// that have not cost anything, such as observers.
+    ///
+    /// # Arguments
+    ///
+    /// * `include_zero_cost` - Whether to list groups with a cost of $0.
+    ///
+    /// # Returns
+    ///
+    /// The Markdown summary, ending in a newline.
+    ///
+    /// # See Also
+    /// * [`Meeting::describe_with`]
+    /// * [`Meeting::to_markdown_summary_formatted`]
+    #[must_use]
+    pub fn to_markdown_summary_with(&self, include_zero_cost: bool) -> String {
+        self.to_markdown_summary_formatted(include_zero_cost, false)
+    }
+
+    /// Like [`Meeting::to_markdown_summary_with`], optionally rounding every
+    /// cost, the total included, to whole dollars for rough reports.
+    ///
+    /// ## Example
+    /// ```
+    /// use std::time::Duration;
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&EmployeeCategory::new("Engineer", 100_000).unwrap(), 3);
+    /// meeting.set_elapsed(Duration::from_mins(10));
+    /// let summary = meeting.to_markdown_summary_formatted(true, true);
+    /// assert!(summary.contains("| Engineer | 3 | $25 |"));
+    /// assert!(summary.contains("**Total cost:** $25"));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `include_zero_cost` - Whether to list groups with a cost of $0.
+    /// * `dollars_only` - Whether to round costs to whole dollars.
+    ///
+    /// # Returns
+    ///
+    /// The Markdown summary, ending in a newline.
+    ///
+    /// # See Also
+    /// * [`breakdown_rows_with`]
+    #[must_use]
+    pub fn to_markdown_summary_formatted(
+        &self,
+        include_zero_cost: bool,
+        dollars_only: bool,
+    ) -> String {
+        let mut out = String::new();
+        if let Some(title) = self.title() {
+            let _ = writeln!(out, "## {title}\n");
+        }
+        out.push_str("| Category | Count | Cost |\n| --- | ---: | ---: |\n");
+        for row in self
+            .breakdown()
+            .into_iter()
+            .filter(|row| include_zero_cost || row.cost > 0.0)
+        {
+            let _ = writeln!(
+                out,
+                "| {} | {} | {} |",
+                row.title.replace('|', "\\|"),
+                row.count,
+                cost_cell(row.cost, dollars_only)
+            );
+        }
+        let _ = writeln!(
+            out,
+            "\n**Duration:** {}  \n**Total cost:** {}",
+            format_duration(self.duration()),
+            cost_cell(self.total_cost(), dollars_only)
+        );
+        if self.is_approximate() {
+            let _ = writeln!(out, "\n*{APPROXIMATE_NOTE}*");
+        }
+        out
+    }
+
+    /// Like [`Meeting::to_markdown_summary`], followed by an appendix listing
+    /// where each category's salary figure came from.
+    ///
+    /// The appendix has one row per category in the breakdown. Sources are
+    /// looked up by title in `categories`; categories without a known source
+    /// are listed as "unknown".
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let dev = EmployeeCategory::new("Dev", 120_000).unwrap().with_source("HR band sheet v7");
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&dev, 2);
+    /// let report = meeting.to_markdown_report(&[dev]);
+    /// assert!(report.contains("| Dev | $120000 | HR band sheet v7 |"));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `categories` - Categories to take salary sources from.
+    ///
+    /// # Returns
+    ///
+    /// The Markdown report, ending in a newline.
+    ///
+    /// # See Also
+    /// * [`EmployeeCategory::source`](crate::EmployeeCategory::source)
+    #[must_use]
+    pub fn to_markdown_report(&self, categories: &[EmployeeCategory]) -> String {
+        let mut out = self.to_markdown_summary();
+        out.push_str(
+            "\n### Salary sources\n\n| Category | Salary | Source |\n| --- | ---: | --- |\n",
+        );
+        for row in self.breakdown() {
+            let source = categories
+                .iter()
+                .find(|c| c.title() == row.title)
+                .and_then(EmployeeCategory::source)
+                .unwrap_or("unknown");
+            let _ = writeln!(
+                out,
+                "| {} | ${} | {} |",
+                row.title.replace('|', "\\|"),
+                row.salary,
+                source.replace('|', "\\|")
+            );
+        }
+        out
+    }
+
+    /// Writes [`Meeting::describe`] to `out`.
+    ///
+    /// # Arguments
+    ///
+    /// * `out` - Destination of the report.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` once the report has been written.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error produced while writing to `out`.
+    ///
+    /// # See Also
+    /// * [`Meeting::pretty_print`]
+    pub fn pretty_print_to<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        out.write_all(self.describe().as_bytes())
+    }
+
+    /// Prints [`Meeting::describe`] to stdout, for non-interactive reports.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&EmployeeCategory::new("Engineer", 120_000).unwrap(), 3);
+    /// meeting.pretty_print();
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * None
+    ///
+    /// # Returns
+    ///
+    /// Nothing. Write errors, such as a closed pipe, are ignored.
+    ///
+    /// # See Also
+    /// * [`Meeting::pretty_print_to`]
+    pub fn pretty_print(&self) {
+        let _ = self.pretty_print_to(&mut io::stdout().lock());
+    }
+}
+
+/// Formats the meeting as a one-line status, e.g. for logs:
+/// `Meeting [Running] 00:23:14 — 7 attendees — $312.44`.
+///
+/// The state is `Running` or `Stopped`, the attendee count includes
+/// observers, and the cost is prefixed with `~` when the meeting is
+/// [approximate](Meeting::is_approximate).
+///
+/// ## Example
+/// ```
+/// use std::time::Duration;
+/// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+/// let mut meeting = Meeting::new();
+/// meeting.add_attendee(&EmployeeCategory::new("Engineer", 120_000).unwrap(), 1);
+/// meeting.set_elapsed(Duration::from_mins(90));
+/// assert_eq!(meeting.to_string(), "Meeting [Stopped] 01:30:00 — 1 attendee — $90.00");
+/// ```
+impl fmt::Display for Meeting {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = if self.is_running() {
+            "Running"
+        } else {
+            "Stopped"
+        };
+        let count = self.total_attendees();
+        let plural = if count == 1 { "" } else { "s" };
+        let approximate = if self.is_approximate() { "~" } else { "" };
+        write!(
+            f,
+            "Meeting [{state}] {} — {count} attendee{plural} — {approximate}${:.2}",
+            self.elapsed_formatted(),
+            self.total_cost()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{engineer, MeetingFixture};
+
+    #[test]
+    fn printed_report_has_total_line() {
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&EmployeeCategory::new("dev", 120_000).unwrap(), 2);
+        let mut out = Vec::new();
+        meeting.pretty_print_to(&mut out).unwrap();
+        let report = String::from_utf8(out).unwrap();
+        assert!(report
+            .lines()
+            .any(|l| l.starts_with("│ Total") && l.contains("│     2 │")));
+        assert!(report.contains("Duration: 00:00:00  Burn rate: $2.00/min"));
+    }
+
+    #[test]
+    fn breakdown_rows_end_with_total() {
+        let mut meeting = Meeting::new();
+        assert_eq!(
+            breakdown_rows(&meeting),
+            vec![vec!["Total", "0", "", "$0.00"]]
+        );
+        meeting.add_attendee(&EmployeeCategory::new("dev", 120_000).unwrap(), 2);
+        meeting.add_attendee(&EmployeeCategory::new("pm", 240_000).unwrap(), 1);
+        meeting.add_attendee(&EmployeeCategory::observer("guest").unwrap(), 3);
+        meeting.set_elapsed(Duration::from_mins(30));
+        let rows = breakdown_rows(&meeting);
+        assert_eq!(rows.len(), meeting.breakdown().len() + 1);
+        assert!(rows.iter().all(|row| row.len() == BREAKDOWN_HEADER.len()));
+        assert_eq!(rows[0], ["dev", "2", "$120000", "$60.00"]);
+        assert_eq!(rows[3], ["Total", "3", "", "$120.00"]);
+    }
+
+    #[test]
+    fn dollars_only_rounds_every_cost_cell() {
+        let (meeting, _clock) = MeetingFixture::new()
+            .attendees(&engineer(), 1)
+            .run_for(Duration::from_secs(90))
+            .attendees(&EmployeeCategory::new("pm", 100_000).unwrap(), 1)
+            .run_for(Duration::from_secs(84))
+            .build();
+        let precise = breakdown_rows(&meeting);
+        assert_eq!(precise[0][3], "$2.90");
+        assert_eq!(precise[2][3], "$4.07");
+
+        let rounded = breakdown_rows_with(&meeting, true);
+        let costs: Vec<&str> = rounded.iter().map(|row| row[3].as_str()).collect();
+        assert_eq!(costs, ["$3", "$1", "$4"]);
+        assert_eq!(rounded[0][..3], precise[0][..3]);
+
+        let summary = meeting.to_markdown_summary_formatted(true, true);
+        assert!(summary.contains("| Engineer | 1 | $3 |"));
+        assert!(summary.contains("| pm | 1 | $1 |"));
+        assert!(summary.contains("**Total cost:** $4\n"));
+        assert_eq!(
+            meeting.to_markdown_summary_formatted(true, false),
+            meeting.to_markdown_summary()
+        );
+    }
+
+    #[test]
+    fn sparkline_scales_between_min_and_max() {
+        let rising: Vec<f64> = (0..8).map(f64::from).collect();
+        assert_eq!(mini_sparkline(&rising, 8), "▁▂▃▄▅▆▇█");
+        assert_eq!(mini_sparkline(&[-2.0, 0.0, 2.0], 3), "▁▅█");
+        assert_eq!(mini_sparkline(&[10.0, 0.0], 2), "█▁");
+    }
+
+    #[test]
+    fn sparkline_of_constant_series_is_flat() {
+        assert_eq!(mini_sparkline(&[3.5; 12], 10), "▁".repeat(10));
+        assert_eq!(mini_sparkline(&[0.0, f64::NAN, 0.0], 3), "▁▁▁");
+    }
+
+    #[test]
+    fn sparkline_has_requested_width() {
+        let long: Vec<f64> = (0..100).map(f64::from).collect();
+        let line = mini_sparkline(&long, 10);
+        assert_eq!(line.chars().count(), 10);
+        assert!(line.ends_with('█'));
+        assert_eq!(mini_sparkline(&[1.0, 2.0], 4), "▁▁██");
+        assert_eq!(mini_sparkline(&[], 8), "");
+        assert_eq!(mini_sparkline(&[1.0], 0), "");
+    }
+
+    #[test]
+    fn wide_titles_stay_aligned() {
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&EmployeeCategory::new("開発者", 120_000).unwrap(), 1);
+        meeting.add_attendee(&EmployeeCategory::new("QA", 60_000).unwrap(), 1);
+        let report = meeting.describe();
+        let table: Vec<&str> = report
+            .lines()
+            .filter(|l| l.starts_with(['│', '┌', '├', '└']))
+            .collect();
+        let width = table[0].width();
+        assert!(table.iter().all(|l| l.width() == width));
+    }
+
+    #[test]
+    fn zero_cost_groups_can_be_hidden() {
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&EmployeeCategory::new("dev", 120_000).unwrap(), 1);
+        meeting.add_attendee(&EmployeeCategory::observer("observer").unwrap(), 2);
+        meeting.set_elapsed(Duration::from_mins(10));
+
+        assert!(meeting.describe().contains("observer"));
+        assert!(meeting
+            .to_markdown_summary()
+            .contains("| observer | 2 | $0.00 |"));
+
+        let report = meeting.describe_with(false);
+        assert!(!report.contains("observer"));
+        assert!(report.contains("dev"));
+        let summary = meeting.to_markdown_summary_with(false);
+        assert!(!summary.contains("observer"));
+        assert!(summary.contains("| dev | 1 | $10.00 |"));
+        assert!(summary.contains("**Total cost:** $10.00"));
+    }
+
+    #[test]
+    fn markdown_report_lists_salary_sources() {
+        let dev = EmployeeCategory::new("dev", 120_000)
+            .unwrap()
+            .with_source("levels.fyi | 2024");
+        let qa = EmployeeCategory::new("qa", 60_000).unwrap();
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&dev, 1);
+        meeting.add_attendee(&qa, 1);
+        let report = meeting.to_markdown_report(&[dev, qa]);
+        assert!(report.starts_with(&meeting.to_markdown_summary()));
+        assert!(report.ends_with(
+            "### Salary sources\n\n| Category | Salary | Source |\n| --- | ---: | --- |\n\
+             | dev | $120000 | levels.fyi \\| 2024 |\n| qa | $60000 | unknown |\n"
+        ));
+    }
+
+    #[test]
+    fn report_includes_budget_eta() {
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&EmployeeCategory::new("dev", 120_000).unwrap(), 1);
+        meeting.set_budget(90.0).unwrap();
+        assert!(meeting.describe().contains("Budget lasts another 01:30:00"));
+    }
+
+    #[test]
+    fn approximate_meetings_carry_the_note() {
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&EmployeeCategory::new("dev", 120_000).unwrap(), 1);
+        assert!(!meeting.describe().contains(APPROXIMATE_NOTE));
+        assert!(!meeting.to_markdown_summary().contains(APPROXIMATE_NOTE));
+
+        let mut pm = EmployeeCategory::new("pm", 112_500).unwrap();
+        pm.set_approximate(true);
+        meeting.add_attendee(&pm, 1);
+        assert!(meeting
+            .describe()
+            .ends_with(&format!("{APPROXIMATE_NOTE}\n")));
+        assert!(meeting
+            .to_markdown_summary()
+            .ends_with(&format!("*{APPROXIMATE_NOTE}*\n")));
+    }
+
+    #[test]
+    fn elapsed_formatted_pads_and_does_not_wrap_days() {
+        let mut meeting = Meeting::new();
+        assert_eq!(meeting.elapsed_formatted(), "00:00:00");
+        meeting.set_elapsed(Duration::from_millis(59_999));
+        assert_eq!(meeting.elapsed_formatted(), "00:00:59");
+        meeting.set_elapsed(Duration::from_secs(3 * 3600 + 25 * 60 + 7));
+        assert_eq!(meeting.elapsed_formatted(), "03:25:07");
+        meeting.set_elapsed(Duration::from_hours(25));
+        assert_eq!(meeting.elapsed_formatted(), "25:00:00");
+        assert_eq!(format_duration(Duration::from_hours(100)), "100:00:00");
+    }
+
+    #[test]
+    fn display_is_a_one_line_status() {
+        let meeting = Meeting::new();
+        assert_eq!(
+            meeting.to_string(),
+            "Meeting [Stopped] 00:00:00 — 0 attendees — $0.00"
+        );
+        assert!(format!("{meeting:?}").starts_with("Meeting {"));
+
+        let (mut meeting, _clock) = MeetingFixture::new()
+            .attendees(&engineer(), 7)
+            .run_for(Duration::from_secs(23 * 60 + 14))
+            .running()
+            .build();
+        assert_eq!(
+            meeting.to_string(),
+            "Meeting [Running] 00:23:14 — 7 attendees — $162.63"
+        );
+        meeting.stop();
+        assert_eq!(
+            meeting.to_string(),
+            "Meeting [Stopped] 00:23:14 — 7 attendees — $162.63"
+        );
+    }
+}
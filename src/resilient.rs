@@ -0,0 +1,471 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+use crate::model::EmployeeCategory;
+use crate::storage::{
+    save_attendees, save_categories, AttendeeInfo, CategoryStore, RosterStore, StorageError,
+    CATEGORIES_FILE,
+};
+
+/// How often and how patiently a [`ResilientStore`] retries a failed save.
+///
+/// Only I/O errors are retried, since a vanished network mount or a timeout
+/// may clear up; serialization errors fail at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Number of attempts per save, including the first.
+    pub attempts: u32,
+    /// Pause after the first failed attempt; each later pause doubles.
+    pub backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            attempts: 3,
+            backoff: Duration::from_millis(100),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Returns the pause after failed attempt number `attempt`, counting from
+    /// one.
+    ///
+    /// ## Example
+    /// ```
+    /// use std::time::Duration;
+    /// use meeting_cost_tracker::RetryPolicy;
+    /// let policy = RetryPolicy { attempts: 4, backoff: Duration::from_millis(50) };
+    /// assert_eq!(policy.delay(1), Duration::from_millis(50));
+    /// assert_eq!(policy.delay(3), Duration::from_millis(200));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `attempt` - Number of the attempt that just failed.
+    ///
+    /// # Returns
+    ///
+    /// The pause before the next attempt, saturating instead of overflowing.
+    #[must_use]
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let factor = 1_u32
+            .checked_shl(attempt.saturating_sub(1))
+            .unwrap_or(u32::MAX);
+        self.backoff.saturating_mul(factor)
+    }
+
+    /// Runs `save` until it succeeds, fails with a non-I/O error or runs out
+    /// of attempts, pausing between attempts.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the last attempt.
+    fn run<F>(&self, mut save: F) -> Result<(), StorageError>
+    where
+        F: FnMut() -> Result<(), StorageError>,
+    {
+        let mut attempt = 1;
+        loop {
+            match save() {
+                Err(StorageError::Io(_)) if attempt < self.attempts => {
+                    thread::sleep(self.delay(attempt));
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// Wraps a store so that saves survive a storage location that comes and
+/// goes, such as a data directory on a flaky network mount.
+///
+/// Failed saves are retried per the [`RetryPolicy`]. A save that still fails
+/// with an I/O error is reported to the caller but its data is kept in
+/// memory: loads return it, [`ResilientStore::flush`] retries it, and as a
+/// last resort [`ResilientStore::dump_pending`] writes it somewhere else.
+/// Other errors, such as data that cannot be serialized, would fail the same
+/// way on every retry, so they are returned without keeping the data.
+///
+/// ## Example
+/// ```
+/// use meeting_cost_tracker::{CategoryStore, EmployeeCategory, MemoryStore, ResilientStore};
+/// let mut store = ResilientStore::new(MemoryStore::default());
+/// let categories = vec![EmployeeCategory::new("Dev", 100_000).unwrap()];
+/// store.save_categories(&categories).unwrap();
+/// assert!(!store.has_pending());
+/// store.flush().unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ResilientStore<S> {
+    inner: S,
+    policy: RetryPolicy,
+    pending_categories: Option<Vec<EmployeeCategory>>,
+    pending_rosters: BTreeMap<String, Vec<AttendeeInfo>>,
+}
+
+impl<S> ResilientStore<S> {
+    /// Wraps `inner` with the default [`RetryPolicy`].
+    #[must_use]
+    pub fn new(inner: S) -> Self {
+        Self::with_policy(inner, RetryPolicy::default())
+    }
+
+    /// Wraps `inner`, retrying failed saves per `policy`.
+    #[must_use]
+    pub fn with_policy(inner: S, policy: RetryPolicy) -> Self {
+        Self {
+            inner,
+            policy,
+            pending_categories: None,
+            pending_rosters: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the wrapped store.
+    #[must_use]
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// Returns `true` if some saved data has not reached the wrapped store.
+    #[must_use]
+    pub fn has_pending(&self) -> bool {
+        self.pending_categories.is_some() || !self.pending_rosters.is_empty()
+    }
+
+    /// Writes all unsaved data as TOML files into `dir`, creating it if
+    /// needed.
+    ///
+    /// Categories are written to [`CATEGORIES_FILE`] and each roster to a
+    /// file named after it, the same layout a
+    /// [`FileStore`](crate::FileStore) uses, so the files can be copied back
+    /// into the data directory.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{MemoryStore, ResilientStore};
+    /// let dir = tempfile::tempdir().unwrap();
+    /// let store = ResilientStore::new(MemoryStore::default());
+    /// assert!(store.dump_pending(dir.path()).unwrap().is_empty());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - Directory to write the files to.
+    ///
+    /// # Returns
+    ///
+    /// The paths of the files written.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StorageError`] if the directory or a file cannot be
+    /// written.
+    ///
+    /// # See Also
+    /// * [`fallback_dump_dir`]
+    pub fn dump_pending(&self, dir: &Path) -> Result<Vec<PathBuf>, StorageError> {
+        let mut written = Vec::new();
+        if !self.has_pending() {
+            return Ok(written);
+        }
+        fs::create_dir_all(dir)?;
+        if let Some(categories) = &self.pending_categories {
+            let path = dir.join(CATEGORIES_FILE);
+            save_categories(&path, categories)?;
+            written.push(path);
+        }
+        for (name, attendees) in &self.pending_rosters {
+            let path = dir.join(name);
+            save_attendees(&path, attendees)?;
+            written.push(path);
+        }
+        Ok(written)
+    }
+}
+
+impl<S: CategoryStore + RosterStore> ResilientStore<S> {
+    /// Retries every save that has not reached the wrapped store yet.
+    ///
+    /// Data that is saved this time is no longer kept in memory, and neither
+    /// is data that fails with an error other than I/O, since retrying it
+    /// cannot help.
+    ///
+    /// # Errors
+    ///
+    /// Returns the last error if any data could still not be saved; data that
+    /// failed with an I/O error stays pending.
+    pub fn flush(&mut self) -> Result<(), StorageError> {
+        let mut result = Ok(());
+        if let Some(categories) = self.pending_categories.take() {
+            if let Err(err) = self.policy.run(|| self.inner.save_categories(&categories)) {
+                if matches!(err, StorageError::Io(_)) {
+                    self.pending_categories = Some(categories);
+                }
+                result = Err(err);
+            }
+        }
+        for (name, attendees) in std::mem::take(&mut self.pending_rosters) {
+            if let Err(err) = self
+                .policy
+                .run(|| self.inner.save_roster(&name, &attendees))
+            {
+                if matches!(err, StorageError::Io(_)) {
+                    self.pending_rosters.insert(name, attendees);
+                }
+                result = Err(err);
+            }
+        }
+        result
+    }
+}
+
+impl<S: CategoryStore> CategoryStore for ResilientStore<S> {
+    fn load_categories(&self) -> Result<Vec<EmployeeCategory>, StorageError> {
+        match &self.pending_categories {
+            Some(categories) => Ok(categories.clone()),
+            None => self.inner.load_categories(),
+        }
+    }
+
+    fn save_categories(&mut self, categories: &[EmployeeCategory]) -> Result<(), StorageError> {
+        let result = self.policy.run(|| self.inner.save_categories(categories));
+        match result {
+            Ok(()) => self.pending_categories = None,
+            Err(StorageError::Io(_)) => self.pending_categories = Some(categories.to_vec()),
+            Err(_) => {}
+        }
+        result
+    }
+}
+
+impl<S: RosterStore> RosterStore for ResilientStore<S> {
+    fn list_rosters(&self) -> Result<Vec<String>, StorageError> {
+        let mut names = self.inner.list_rosters().unwrap_or_default();
+        names.extend(self.pending_rosters.keys().cloned());
+        names.sort();
+        names.dedup();
+        Ok(names)
+    }
+
+    fn load_roster(&self, name: &str) -> Result<Vec<AttendeeInfo>, StorageError> {
+        match self.pending_rosters.get(name) {
+            Some(attendees) => Ok(attendees.clone()),
+            None => self.inner.load_roster(name),
+        }
+    }
+
+    fn save_roster(&mut self, name: &str, attendees: &[AttendeeInfo]) -> Result<(), StorageError> {
+        let result = self.policy.run(|| self.inner.save_roster(name, attendees));
+        match result {
+            Ok(()) => {
+                self.pending_rosters.remove(name);
+            }
+            Err(StorageError::Io(_)) => {
+                self.pending_rosters
+                    .insert(name.to_string(), attendees.to_vec());
+            }
+            Err(_) => {}
+        }
+        result
+    }
+}
+
+/// Returns a fresh directory under the system's temporary directory, e.g.
+/// `/tmp/mct-unsaved-1234`, for [`ResilientStore::dump_pending`] when the
+/// data directory cannot be written.
+#[must_use]
+pub fn fallback_dump_dir() -> PathBuf {
+    std::env::temp_dir().join(format!("mct-unsaved-{}", std::process::id()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::*;
+    use crate::storage::{load_attendees, load_categories, MemoryStore};
+
+    /// Store whose saves fail with an I/O error until `failures` runs out,
+    /// or always fail with a non-I/O error if `rejects` is set.
+    #[derive(Default)]
+    struct FlakyStore {
+        failures: u32,
+        rejects: bool,
+        attempts: u32,
+        saved: MemoryStore,
+    }
+
+    impl FlakyStore {
+        fn failing(failures: u32) -> Self {
+            Self {
+                failures,
+                ..Self::default()
+            }
+        }
+
+        fn rejecting() -> Self {
+            Self {
+                rejects: true,
+                ..Self::default()
+            }
+        }
+
+        fn attempt(&mut self) -> Result<(), StorageError> {
+            self.attempts += 1;
+            if self.rejects {
+                return Err(StorageError::InvalidCode("unserializable".into()));
+            }
+            if self.failures > 0 {
+                self.failures -= 1;
+                return Err(io::Error::new(io::ErrorKind::NotFound, "mount gone").into());
+            }
+            Ok(())
+        }
+    }
+
+    impl CategoryStore for FlakyStore {
+        fn load_categories(&self) -> Result<Vec<EmployeeCategory>, StorageError> {
+            self.saved.load_categories()
+        }
+
+        fn save_categories(&mut self, categories: &[EmployeeCategory]) -> Result<(), StorageError> {
+            self.attempt()?;
+            self.saved.save_categories(categories)
+        }
+    }
+
+    impl RosterStore for FlakyStore {
+        fn list_rosters(&self) -> Result<Vec<String>, StorageError> {
+            self.saved.list_rosters()
+        }
+
+        fn load_roster(&self, name: &str) -> Result<Vec<AttendeeInfo>, StorageError> {
+            self.saved.load_roster(name)
+        }
+
+        fn save_roster(
+            &mut self,
+            name: &str,
+            attendees: &[AttendeeInfo],
+        ) -> Result<(), StorageError> {
+            self.attempt()?;
+            self.saved.save_roster(name, attendees)
+        }
+    }
+
+    fn quick(attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            attempts,
+            backoff: Duration::ZERO,
+        }
+    }
+
+    fn roster() -> Vec<AttendeeInfo> {
+        vec![AttendeeInfo {
+            title: "Dev".into(),
+            count: 2,
+            name: None,
+            salary: None,
+            attention: 1.0,
+        }]
+    }
+
+    #[test]
+    fn backoff_doubles_and_saturates() {
+        let policy = RetryPolicy {
+            attempts: 5,
+            backoff: Duration::from_millis(10),
+        };
+        let delays: Vec<Duration> = (1..=4).map(|a| policy.delay(a)).collect();
+        assert_eq!(delays, [10, 20, 40, 80].map(Duration::from_millis).to_vec());
+        assert_eq!(policy.delay(100), Duration::from_millis(10) * u32::MAX);
+    }
+
+    #[test]
+    fn transient_failures_are_retried() {
+        let mut store = ResilientStore::with_policy(FlakyStore::failing(2), quick(3));
+        let categories = vec![EmployeeCategory::new("Dev", 1).unwrap()];
+        store.save_categories(&categories).unwrap();
+        assert_eq!(store.inner().attempts, 3);
+        assert!(!store.has_pending());
+        assert_eq!(store.inner().saved.load_categories().unwrap(), categories);
+    }
+
+    #[test]
+    fn unsaved_data_is_kept_and_flushed_later() {
+        let mut store = ResilientStore::with_policy(FlakyStore::failing(4), quick(2));
+        assert!(store.save_roster("standup", &roster()).is_err());
+        assert!(store.has_pending());
+        assert_eq!(store.load_roster("standup").unwrap(), roster());
+        assert_eq!(store.list_rosters().unwrap(), vec!["standup"]);
+
+        assert!(store.flush().is_err());
+        assert!(store.has_pending());
+        store.flush().unwrap();
+        assert!(!store.has_pending());
+        assert_eq!(
+            store.inner().saved.load_roster("standup").unwrap(),
+            roster()
+        );
+    }
+
+    #[test]
+    fn non_io_failures_are_not_kept() {
+        let mut store = ResilientStore::with_policy(FlakyStore::rejecting(), quick(3));
+        let categories = vec![EmployeeCategory::new("Dev", 1).unwrap()];
+        assert!(matches!(
+            store.save_categories(&categories),
+            Err(StorageError::InvalidCode(_))
+        ));
+        assert!(matches!(
+            store.save_roster("standup", &roster()),
+            Err(StorageError::InvalidCode(_))
+        ));
+        assert_eq!(store.inner().attempts, 2);
+        assert!(!store.has_pending());
+        assert!(store.load_categories().unwrap().is_empty());
+        assert!(store.list_rosters().unwrap().is_empty());
+    }
+
+    #[test]
+    fn flush_drops_data_that_fails_for_good() {
+        let mut store = ResilientStore::with_policy(FlakyStore::failing(u32::MAX), quick(1));
+        let categories = vec![EmployeeCategory::new("Dev", 1).unwrap()];
+        assert!(store.save_categories(&categories).is_err());
+        assert!(store.save_roster("standup", &roster()).is_err());
+        assert!(store.has_pending());
+
+        // The store now rejects the data instead of being unreachable.
+        store.inner.rejects = true;
+        assert!(matches!(store.flush(), Err(StorageError::InvalidCode(_))));
+        assert!(!store.has_pending());
+        assert!(store.flush().is_ok());
+    }
+
+    #[test]
+    fn pending_data_is_dumped_to_fallback_dir() {
+        let mut store = ResilientStore::with_policy(FlakyStore::failing(u32::MAX), quick(1));
+        let categories = vec![EmployeeCategory::new("Dev", 1).unwrap()];
+        assert!(store.save_categories(&categories).is_err());
+        assert!(store.save_roster("standup", &roster()).is_err());
+        assert_eq!(store.load_categories().unwrap(), categories);
+
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().join("dump");
+        let written = store.dump_pending(&dir).unwrap();
+        assert_eq!(
+            written,
+            vec![dir.join(CATEGORIES_FILE), dir.join("standup")]
+        );
+        assert_eq!(load_categories(&written[0]).unwrap(), categories);
+        assert_eq!(load_attendees(&written[1]).unwrap(), roster());
+        assert!(fallback_dump_dir().starts_with(std::env::temp_dir()));
+    }
+}
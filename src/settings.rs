@@ -0,0 +1,259 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::EmployeeCategory;
+use crate::resilient::RetryPolicy;
+use crate::warnings::Thresholds;
+
+/// User preferences for the terminal application.
+///
+/// Every field has a default, so a settings file only needs to list the
+/// values that should differ from it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Limits that trigger a confirmation after loading an attendee roster.
+    pub roster_warnings: Thresholds,
+    /// Directory exports are written to; see [`resolve_export_dir`](crate::resolve_export_dir).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub export_dir: Option<PathBuf>,
+    /// Minutes after which a running meeting stops by itself.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_meeting_minutes: Option<u64>,
+    /// Largest number of categories to keep; the least recently used are
+    /// removed when a new category would exceed it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_categories: Option<usize>,
+    /// Granularity salaries are rounded to when a category is entered, e.g.
+    /// `1000` to turn `123456` into `123000`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub round_salary_to: Option<u64>,
+    /// Attendees added when the count prompt is left empty; see
+    /// [`Settings::attendee_count_default`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_attendee_count: Option<u32>,
+    /// Bill people named under several groups only once instead of just
+    /// warning; see [`Meeting::set_strict_duplicate_names`](crate::Meeting::set_strict_duplicate_names).
+    pub strict_duplicate_names: bool,
+    /// Days after which a category's salary is flagged as due for a refresh;
+    /// `0` or `None` turns the flag off.
+    pub stale_salary_days: Option<u64>,
+    /// Attendee groups, most expensive first, that get a cost sparkline in
+    /// the TUI; `0` turns the sparklines off.
+    pub sparkline_categories: usize,
+    /// Attempts per save before the data is kept in memory for a retry at
+    /// exit; see [`Settings::retry_policy`].
+    pub save_attempts: u32,
+    /// Milliseconds to wait after the first failed save attempt; each later
+    /// wait doubles.
+    pub save_backoff_ms: u64,
+    /// Salaries above this many times the median are marked as possible
+    /// typos; `0` or `None` turns the marker off. See
+    /// [`flag_outlier_categories`](crate::flag_outlier_categories).
+    pub salary_outlier_factor: Option<f64>,
+    /// Minutes local time is ahead of UTC, e.g. `-300` for UTC-5, used to name
+    /// untitled meetings by their local start time; see
+    /// [`Meeting::default_name`](crate::Meeting::default_name). Names are in
+    /// UTC when unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub utc_offset_minutes: Option<i32>,
+    /// Keyboard macros recorded in the TUI, as key specs by slot; see
+    /// [`MacroRecorder`](crate::MacroRecorder).
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub macros: BTreeMap<String, Vec<String>>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            roster_warnings: Thresholds {
+                max_burn_rate_per_minute: Some(25.0),
+                max_headcount: Some(12),
+            },
+            export_dir: None,
+            max_meeting_minutes: None,
+            max_categories: None,
+            round_salary_to: None,
+            default_attendee_count: None,
+            strict_duplicate_names: false,
+            stale_salary_days: Some(365),
+            sparkline_categories: 5,
+            save_attempts: 3,
+            save_backoff_ms: 100,
+            salary_outlier_factor: Some(3.0),
+            utc_offset_minutes: None,
+            macros: BTreeMap::new(),
+        }
+    }
+}
+
+impl Settings {
+    /// Returns the [`RetryPolicy`] for saving data, from
+    /// [`Settings::save_attempts`] and [`Settings::save_backoff_ms`].
+    ///
+    /// At least one attempt is always made.
+    #[must_use]
+    pub fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy {
+            attempts: self.save_attempts.max(1),
+            backoff: Duration::from_millis(self.save_backoff_ms),
+        }
+    }
+
+    /// Returns how old a salary may get before it is flagged as stale, or
+    /// `None` if stale salaries are not flagged.
+    #[must_use]
+    pub fn stale_salary_age(&self) -> Option<Duration> {
+        self.stale_salary_days
+            .filter(|&days| days > 0)
+            .map(|days| Duration::from_hours(days.saturating_mul(24)))
+    }
+
+    /// Returns the number of attendees of `category` added for an empty count.
+    ///
+    /// [`Settings::default_attendee_count`] takes precedence, then the
+    /// category's [`default_count`](EmployeeCategory::default_count), and
+    /// otherwise one attendee is added.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Settings};
+    /// let cat = EmployeeCategory::new("Engineer", 120_000)
+    ///     .unwrap()
+    ///     .with_default_count(4);
+    /// assert_eq!(Settings::default().attendee_count_default(&cat), 4);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - Category being added.
+    ///
+    /// # Returns
+    ///
+    /// The count to add, at least one.
+    #[must_use]
+    pub fn attendee_count_default(&self, category: &EmployeeCategory) -> u32 {
+        self.default_attendee_count
+            .filter(|&c| c > 0)
+            .or(category.default_count())
+            .unwrap_or(1)
+    }
+
+    /// Rounds `salary` to the nearest multiple of [`Settings::round_salary_to`].
+    ///
+    /// Halfway values round up. Without a granularity, or with a granularity
+    /// of zero, the salary is returned unchanged.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::Settings;
+    /// let settings = Settings {
+    ///     round_salary_to: Some(1_000),
+    ///     ..Settings::default()
+    /// };
+    /// assert_eq!(settings.round_salary(123_456), 123_000);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `salary` - Salary as entered by the user.
+    ///
+    /// # Returns
+    ///
+    /// The rounded salary, saturating at `u64::MAX`.
+    #[must_use]
+    pub fn round_salary(&self, salary: u64) -> u64 {
+        match self.round_salary_to {
+            Some(step) if step > 0 => {
+                let down = salary - salary % step;
+                if salary % step >= step - step / 2 {
+                    down.saturating_add(step)
+                } else {
+                    down
+                }
+            }
+            _ => salary,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rounding_to(step: u64) -> Settings {
+        Settings {
+            round_salary_to: Some(step),
+            ..Settings::default()
+        }
+    }
+
+    #[test]
+    fn rounds_to_nearest_multiple() {
+        assert_eq!(rounding_to(1_000).round_salary(123_456), 123_000);
+        assert_eq!(rounding_to(1_000).round_salary(123_500), 124_000);
+        assert_eq!(rounding_to(1_000).round_salary(123_499), 123_000);
+        assert_eq!(rounding_to(5).round_salary(12), 10);
+        assert_eq!(rounding_to(5).round_salary(13), 15);
+        assert_eq!(rounding_to(1_000).round_salary(u64::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn empty_count_default_precedence() {
+        let plain = EmployeeCategory::new("dev", 120_000).unwrap();
+        let team = plain.clone().with_default_count(5);
+        let settings = Settings::default();
+        assert_eq!(settings.attendee_count_default(&plain), 1);
+        assert_eq!(settings.attendee_count_default(&team), 5);
+
+        let settings = Settings {
+            default_attendee_count: Some(2),
+            ..Settings::default()
+        };
+        assert_eq!(settings.attendee_count_default(&plain), 2);
+        assert_eq!(settings.attendee_count_default(&team), 2);
+    }
+
+    #[test]
+    fn stale_salary_age_defaults_to_a_year() {
+        assert_eq!(
+            Settings::default().stale_salary_age(),
+            Some(Duration::from_hours(365 * 24))
+        );
+        let settings = Settings {
+            stale_salary_days: None,
+            ..Settings::default()
+        };
+        assert_eq!(settings.stale_salary_age(), None);
+        let settings = Settings {
+            stale_salary_days: Some(0),
+            ..Settings::default()
+        };
+        assert_eq!(settings.stale_salary_age(), None);
+    }
+
+    #[test]
+    fn retry_policy_makes_at_least_one_attempt() {
+        assert_eq!(
+            Settings::default().retry_policy(),
+            RetryPolicy {
+                attempts: 3,
+                backoff: Duration::from_millis(100),
+            }
+        );
+        let settings = Settings {
+            save_attempts: 0,
+            ..Settings::default()
+        };
+        assert_eq!(settings.retry_policy().attempts, 1);
+    }
+
+    #[test]
+    fn no_granularity_keeps_salary() {
+        assert_eq!(Settings::default().round_salary(123_456), 123_456);
+        assert_eq!(rounding_to(0).round_salary(123_456), 123_456);
+    }
+}
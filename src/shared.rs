@@ -0,0 +1,169 @@
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+use crate::meeting::Meeting;
+use crate::model::EmployeeCategory;
+
+/// A [`Meeting`] that can be shared between threads.
+///
+/// Clones are cheap handles to the same meeting, so one thread can own the
+/// timer while another reads the cost. Every method takes `&self` and locks
+/// an internal [`Mutex`] for the duration of the call only; no lock is held
+/// between calls. Because each call is atomic on its own, a sequence of
+/// calls is not: use [`SharedMeeting::with`] to run several operations under
+/// one lock.
+///
+/// A thread that panics while holding the lock does not make the meeting
+/// unusable; later calls see the state as the panicking call left it.
+///
+/// ## Example
+/// ```
+/// use meeting_cost_tracker::{EmployeeCategory, SharedMeeting};
+/// let meeting = SharedMeeting::default();
+/// let timer = meeting.clone();
+/// std::thread::spawn(move || timer.start()).join().unwrap();
+/// meeting.add_attendee(&EmployeeCategory::new("Dev", 120_000).unwrap(), 2);
+/// assert!(meeting.is_running());
+/// assert_eq!(meeting.with(|m| m.total_attendees()), 2);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SharedMeeting {
+    inner: Arc<Mutex<Meeting>>,
+}
+
+impl SharedMeeting {
+    /// Wraps `meeting` for sharing between threads.
+    #[must_use]
+    pub fn new(meeting: Meeting) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(meeting)),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Meeting> {
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Runs `f` with exclusive access to the meeting.
+    ///
+    /// Other threads block until `f` returns, so keep it short.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::SharedMeeting;
+    /// let meeting = SharedMeeting::default();
+    /// meeting.with(|m| m.set_title("Standup"));
+    /// assert_eq!(meeting.with(|m| m.title().map(str::to_string)), Some("Standup".into()));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Operation to run on the meeting.
+    ///
+    /// # Returns
+    ///
+    /// Whatever `f` returns.
+    pub fn with<R>(&self, f: impl FnOnce(&mut Meeting) -> R) -> R {
+        f(&mut self.lock())
+    }
+
+    /// Starts the meeting; see [`Meeting::start`].
+    pub fn start(&self) {
+        self.lock().start();
+    }
+
+    /// Stops the meeting; see [`Meeting::stop`].
+    pub fn stop(&self) {
+        self.lock().stop();
+    }
+
+    /// Resets the meeting; see [`Meeting::reset`].
+    pub fn reset(&self) {
+        self.lock().reset();
+    }
+
+    /// Adds attendees; see [`Meeting::add_attendee`].
+    pub fn add_attendee(&self, category: &EmployeeCategory, count: u32) {
+        self.lock().add_attendee(category, count);
+    }
+
+    /// Removes attendees; see [`Meeting::remove_attendee`].
+    pub fn remove_attendee(&self, title: &str, count: u32) {
+        self.lock().remove_attendee(title, count);
+    }
+
+    /// Returns whether the meeting is running; see [`Meeting::is_running`].
+    #[must_use]
+    pub fn is_running(&self) -> bool {
+        self.lock().is_running()
+    }
+
+    /// Returns the meeting duration; see [`Meeting::duration`].
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.lock().duration()
+    }
+
+    /// Returns the total cost; see [`Meeting::total_cost`].
+    #[must_use]
+    pub fn total_cost(&self) -> f64 {
+        self.lock().total_cost()
+    }
+}
+
+impl From<Meeting> for SharedMeeting {
+    fn from(meeting: Meeting) -> Self {
+        Self::new(meeting)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn concurrent_adds_are_not_lost() {
+        let meeting = SharedMeeting::default();
+        meeting.start();
+        let cat = EmployeeCategory::new("Dev", 120_000).unwrap();
+        let writers: Vec<_> = (0..8)
+            .map(|_| {
+                let meeting = meeting.clone();
+                let cat = cat.clone();
+                thread::spawn(move || {
+                    for _ in 0..100 {
+                        meeting.add_attendee(&cat, 1);
+                    }
+                })
+            })
+            .collect();
+        let reader = {
+            let meeting = meeting.clone();
+            thread::spawn(move || {
+                let mut last = 0.0;
+                for _ in 0..200 {
+                    let cost = meeting.total_cost();
+                    assert!(cost >= last);
+                    last = cost;
+                }
+            })
+        };
+        for writer in writers {
+            writer.join().unwrap();
+        }
+        reader.join().unwrap();
+        assert_eq!(meeting.with(|m| m.attendee_count("Dev")), Some(800));
+        meeting.stop();
+        assert!(!meeting.is_running());
+    }
+
+    #[test]
+    fn survives_a_panicking_holder() {
+        let meeting = SharedMeeting::default();
+        let poisoner = meeting.clone();
+        let _ = thread::spawn(move || poisoner.with(|_| panic!("boom"))).join();
+        meeting.add_attendee(&EmployeeCategory::new("Dev", 120_000).unwrap(), 1);
+        assert_eq!(meeting.with(|m| m.total_attendees()), 1);
+    }
+}
@@ -0,0 +1,554 @@
+use std::collections::BTreeMap;
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+
+use crate::meeting::{CostModel, Meeting, MeetingError};
+use crate::model::{EmployeeCategory, DEFAULT_WORK_HOURS_PER_YEAR};
+use crate::storage::AttendeeInfo;
+
+/// Serializable state of a [`Meeting`], for storing a meeting in progress.
+///
+/// A running meeting measures time with [`std::time::Instant`], which only
+/// means something inside the process that created it and cannot be saved.
+/// The snapshot therefore records the elapsed time instead, and a meeting
+/// restored from it starts out stopped with that elapsed time; call
+/// [`Meeting::start`] to carry on if [`MeetingSnapshot::running`] is set.
+///
+/// The roster history is kept as [`RosterChange`] entries, so attendees who
+/// joined or left mid-meeting are billed for the same time after restoring.
+/// Snapshots saved before the history was kept bill their attendees as if
+/// they had been present for the whole elapsed time.
+///
+/// The budget, the duration cap, the [`CostModel`], strict duplicate mode and
+/// which groups have an approximate salary or a salary range are kept as
+/// well. Agenda segments, undo history and fired cost alerts are not.
+///
+/// The cost and burn rate at the time of the snapshot are recorded for
+/// display, e.g. by [`Meeting::state_at`]; restoring recomputes them.
+///
+/// Snapshots of running meetings with
+/// [wall-clock resume](Meeting::set_wall_clock_resume) enabled also record the
+/// wall-clock time they were taken, so [`Meeting::from_state`] can resume the
+/// meeting after a crash or reboot and count the time that passed in between.
+///
+/// ## Example
+/// ```
+/// use meeting_cost_tracker::{EmployeeCategory, Meeting, MeetingSnapshot};
+/// use std::time::Duration;
+/// let cat = EmployeeCategory::new("Dev", 120_000).unwrap();
+/// let mut meeting = Meeting::new();
+/// meeting.add_attendee(&cat, 2);
+/// meeting.set_elapsed(Duration::from_secs(600));
+///
+/// let snapshot = MeetingSnapshot::from(&meeting);
+/// let restored = Meeting::try_from(snapshot).unwrap();
+/// assert_eq!(restored.duration(), Duration::from_secs(600));
+/// assert_eq!(restored.attendee_count("Dev"), Some(2));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MeetingSnapshot {
+    /// Attendee groups and named people, each with its salary.
+    pub attendees: Vec<AttendeeInfo>,
+    /// Milliseconds the meeting had run when the snapshot was taken.
+    pub elapsed_millis: u64,
+    /// Whether the meeting was running when the snapshot was taken.
+    pub running: bool,
+    /// Name of the meeting, if one was set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Fraction added on top of salaries; see [`Meeting::set_overhead`].
+    #[serde(default)]
+    pub overhead: f64,
+    /// Hours salaries are spread across; see [`Meeting::set_work_hours`].
+    #[serde(default = "default_work_hours")]
+    pub work_hours_per_year: f64,
+    /// Wall-clock time of the first start; see [`Meeting::started_at`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<SystemTime>,
+    /// Wall-clock time of the last stop; see [`Meeting::ended_at`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ended_at: Option<SystemTime>,
+    /// Wall-clock time the snapshot of a running meeting was taken, recorded
+    /// only with [`MeetingSnapshot::wall_clock_resume`] set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub saved_at: Option<SystemTime>,
+    /// Whether restoring counts the time since
+    /// [`MeetingSnapshot::saved_at`]; see [`Meeting::set_wall_clock_resume`].
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub wall_clock_resume: bool,
+    /// Changes to the attendee groups in the order they happened.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub roster_log: Vec<RosterChange>,
+    /// Dollars the meeting may cost; see [`Meeting::set_budget`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub budget: Option<f64>,
+    /// Milliseconds after which the meeting stops by itself; see
+    /// [`Meeting::set_max_duration`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_duration_millis: Option<u64>,
+    /// How roster changes are billed; see [`Meeting::set_cost_model`].
+    #[serde(default)]
+    pub cost_model: CostModel,
+    /// Whether people named under several groups are billed once; see
+    /// [`Meeting::set_strict_duplicate_names`].
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub strict_duplicate_names: bool,
+    /// Titles of groups billed from an approximate salary; see
+    /// [`Meeting::is_approximate`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub approximate_groups: Vec<String>,
+    /// Lowest and highest likely salary by group title; see
+    /// [`Meeting::cost_interval`].
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub salary_ranges: BTreeMap<String, (u64, u64)>,
+    /// Dollars the meeting had cost when the snapshot was taken.
+    #[serde(default)]
+    pub total_cost: f64,
+    /// Burn rate in dollars per minute when the snapshot was taken.
+    #[serde(default)]
+    pub burn_rate_per_minute: f64,
+}
+
+/// A change in the size of one attendee group, as kept in a
+/// [`MeetingSnapshot`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RosterChange {
+    /// Milliseconds into the meeting at which the change happened.
+    pub at_millis: u64,
+    /// Title of the attendee group.
+    pub title: String,
+    /// Annual salary of each attendee in the group.
+    pub salary: u64,
+    /// Group size from then on; zero once the group left.
+    pub count: u32,
+    /// Share of the group's cost billed from then on, within `(0, 1]`.
+    #[serde(default = "full_attention")]
+    pub attention: f64,
+}
+
+fn default_work_hours() -> f64 {
+    DEFAULT_WORK_HOURS_PER_YEAR
+}
+
+fn full_attention() -> f64 {
+    1.0
+}
+
+impl From<&Meeting> for MeetingSnapshot {
+    fn from(meeting: &Meeting) -> Self {
+        let attendees = meeting
+            .roster_entries()
+            .into_iter()
+            .map(|entry| AttendeeInfo {
+                salary: meeting
+                    .attendees()
+                    .find(|(title, _, _)| *title == entry.title)
+                    .map(|(_, salary, _)| salary),
+                ..entry
+            })
+            .collect();
+        let running = meeting.is_running();
+        let (approximate_groups, salary_ranges) = meeting.estimate_markers();
+        Self {
+            attendees,
+            elapsed_millis: u64::try_from(meeting.elapsed_millis()).unwrap_or(u64::MAX),
+            running,
+            title: meeting.title().map(str::to_string),
+            overhead: meeting.overhead(),
+            work_hours_per_year: meeting.work_hours_per_year(),
+            started_at: meeting.started_at(),
+            ended_at: meeting.ended_at(),
+            saved_at: (running && meeting.wall_clock_resume()).then(SystemTime::now),
+            wall_clock_resume: meeting.wall_clock_resume(),
+            roster_log: meeting.roster_changes(),
+            budget: meeting.budget(),
+            max_duration_millis: meeting
+                .max_duration()
+                .map(|max| u64::try_from(max.as_millis()).unwrap_or(u64::MAX)),
+            cost_model: meeting.cost_model(),
+            strict_duplicate_names: meeting.strict_duplicate_names(),
+            approximate_groups,
+            salary_ranges,
+            total_cost: meeting.total_cost(),
+            burn_rate_per_minute: meeting.burn_rate_per_minute(),
+        }
+    }
+}
+
+impl Meeting {
+    /// Rebuilds a meeting from `snapshot` and keeps it running if it was.
+    ///
+    /// Unlike [`Meeting::try_from`], a snapshot of a running meeting is
+    /// started again. With [wall-clock resume](Meeting::set_wall_clock_resume)
+    /// enabled, the wall-clock time since [`MeetingSnapshot::saved_at`] is
+    /// first added to the elapsed time, as if the meeting had kept running
+    /// while the process was down; otherwise the meeting carries on from the
+    /// elapsed time it was saved with.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{Meeting, MeetingSnapshot};
+    /// let mut meeting = Meeting::new();
+    /// meeting.set_wall_clock_resume(true);
+    /// meeting.start();
+    /// let snapshot = MeetingSnapshot::from(&meeting);
+    /// let resumed = Meeting::from_state(snapshot).unwrap();
+    /// assert!(resumed.is_running());
+    /// assert!(resumed.wall_clock_resume());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `snapshot` - State saved with [`MeetingSnapshot::from`].
+    ///
+    /// # Returns
+    ///
+    /// The restored meeting.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Meeting::try_from`].
+    ///
+    /// # See Also
+    /// * [`Meeting::from_state_at`]
+    pub fn from_state(snapshot: MeetingSnapshot) -> Result<Self, MeetingError> {
+        Self::from_state_at(snapshot, SystemTime::now())
+    }
+
+    /// Like [`Meeting::from_state`], measuring the downtime up to `now`
+    /// instead of the current time.
+    ///
+    /// If the clock went backwards, so that `now` is before the snapshot was
+    /// saved, no downtime is added.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{Meeting, MeetingSnapshot};
+    /// use std::time::Duration;
+    /// let mut meeting = Meeting::new();
+    /// meeting.set_wall_clock_resume(true);
+    /// meeting.start();
+    /// let snapshot = MeetingSnapshot::from(&meeting);
+    /// let later = snapshot.saved_at.unwrap() + Duration::from_secs(600);
+    /// let resumed = Meeting::from_state_at(snapshot, later).unwrap();
+    /// assert!(resumed.duration() >= Duration::from_secs(600));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `snapshot` - State saved with [`MeetingSnapshot::from`].
+    /// * `now` - Wall-clock time the meeting is resumed at.
+    ///
+    /// # Returns
+    ///
+    /// The restored meeting.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Meeting::try_from`].
+    ///
+    /// # See Also
+    /// * [`Meeting::from_state`]
+    pub fn from_state_at(snapshot: MeetingSnapshot, now: SystemTime) -> Result<Self, MeetingError> {
+        let running = snapshot.running;
+        let saved_at = snapshot
+            .saved_at
+            .filter(|_| running && snapshot.wall_clock_resume);
+        let mut meeting = Self::try_from(snapshot)?;
+        if let Some(saved_at) = saved_at {
+            let downtime = now.duration_since(saved_at).unwrap_or_default();
+            meeting.add_elapsed(downtime);
+        }
+        if running {
+            meeting.start();
+        }
+        Ok(meeting)
+    }
+}
+
+impl TryFrom<MeetingSnapshot> for Meeting {
+    type Error = MeetingError;
+
+    /// Rebuilds a stopped meeting from `snapshot`.
+    ///
+    /// Entries with a salary of zero are restored as observers.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeetingError::InvalidAttendee`] for an entry without a
+    /// title or salary, [`MeetingError::InvalidFraction`] for a
+    /// [`RosterChange`] with an attention outside `(0, 1]`, and the errors of
+    /// [`Meeting::set_overhead`], [`Meeting::set_work_hours`] and
+    /// [`Meeting::add_roster_entry`] and [`Meeting::set_budget`] for values
+    /// they reject.
+    fn try_from(snapshot: MeetingSnapshot) -> Result<Self, Self::Error> {
+        let mut meeting = Meeting::with_work_hours(snapshot.work_hours_per_year)?;
+        meeting.set_overhead(snapshot.overhead)?;
+        if let Some(title) = snapshot.title {
+            meeting.set_title(title);
+        }
+        if let Some(budget) = snapshot.budget {
+            meeting.set_budget(budget)?;
+        }
+        if let Some(max) = snapshot.max_duration_millis {
+            meeting.set_max_duration(Duration::from_millis(max));
+        }
+        meeting.set_cost_model(snapshot.cost_model);
+        meeting.set_strict_duplicate_names(snapshot.strict_duplicate_names);
+        for entry in &snapshot.attendees {
+            let category = entry
+                .salary
+                .and_then(|salary| match salary {
+                    0 => EmployeeCategory::observer(entry.title.as_str()).ok(),
+                    _ => EmployeeCategory::new(entry.title.as_str(), salary).ok(),
+                })
+                .ok_or_else(|| MeetingError::InvalidAttendee(entry.title.clone()))?;
+            meeting.add_roster_entry(&category, entry)?;
+        }
+        if !snapshot.roster_log.is_empty() {
+            meeting.restore_roster_log(&snapshot.roster_log)?;
+        }
+        meeting.restore_estimate_markers(snapshot.approximate_groups, snapshot.salary_ranges);
+        meeting.set_elapsed(Duration::from_millis(snapshot.elapsed_millis));
+        meeting.restore_wall_clock(snapshot.started_at, snapshot.ended_at);
+        meeting.set_wall_clock_resume(snapshot.wall_clock_resume);
+        Ok(meeting)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{assert_cost_eq, CENT};
+
+    fn sample_meeting() -> Meeting {
+        let dev = EmployeeCategory::new("Dev", 120_000).unwrap();
+        let pm = EmployeeCategory::new("PM", 150_000).unwrap();
+        let mut meeting = Meeting::with_work_hours(1_800.0).unwrap();
+        meeting.set_title("Planning");
+        meeting.set_overhead(0.25).unwrap();
+        meeting.add_attendee(&dev, 2);
+        meeting.add_person("Alice", &pm).unwrap();
+        meeting.start();
+        meeting.stop();
+        meeting.set_elapsed(Duration::from_mins(30));
+        meeting
+    }
+
+    #[test]
+    fn round_trips_through_json_and_toml() {
+        let meeting = sample_meeting();
+        let snapshot = MeetingSnapshot::from(&meeting);
+        assert_eq!(snapshot.elapsed_millis, 30 * 60 * 1000);
+        assert!(!snapshot.running);
+        assert_eq!(snapshot.attendees.len(), 2);
+        assert_eq!(snapshot.attendees[1].salary, Some(150_000));
+
+        let toml = toml::to_string(&snapshot).unwrap();
+        assert_eq!(toml::from_str::<MeetingSnapshot>(&toml).unwrap(), snapshot);
+
+        let restored = Meeting::try_from(snapshot.clone()).unwrap();
+        assert!(!restored.is_running());
+        assert_eq!(restored.duration(), meeting.duration());
+        assert_eq!(restored.title(), Some("Planning"));
+        assert_eq!(restored.total_cost_cents(), meeting.total_cost_cents());
+        assert_eq!(restored.people().collect::<Vec<_>>(), vec![("Alice", "PM")]);
+        assert_eq!(restored.started_at(), meeting.started_at());
+        assert_eq!(restored.ended_at(), meeting.ended_at());
+        assert_eq!(MeetingSnapshot::from(&restored), snapshot);
+    }
+
+    #[test]
+    fn round_trip_keeps_meeting_settings() {
+        let mut meeting = sample_meeting();
+        meeting.set_budget(250.0).unwrap();
+        meeting.set_max_duration(Duration::from_mins(90));
+        meeting.set_cost_model(CostModel::FlatFromStart);
+        meeting.set_strict_duplicate_names(true);
+        let mut banded = EmployeeCategory::new("QA", 90_000)
+            .unwrap()
+            .with_salary_range(80_000, 100_000)
+            .unwrap();
+        banded.set_approximate(true);
+        meeting.add_attendee(&banded, 1);
+
+        let snapshot = MeetingSnapshot::from(&meeting);
+        let toml = toml::to_string(&snapshot).unwrap();
+        assert_eq!(toml::from_str::<MeetingSnapshot>(&toml).unwrap(), snapshot);
+        let json = serde_json::to_string(&snapshot).unwrap();
+        assert!(json.contains(r#""cost_model":"flat_from_start""#));
+        let restored =
+            Meeting::try_from(serde_json::from_str::<MeetingSnapshot>(&json).unwrap()).unwrap();
+        assert_eq!(restored.budget(), Some(250.0));
+        assert_eq!(restored.max_duration(), Some(Duration::from_mins(90)));
+        assert_eq!(restored.cost_model(), CostModel::FlatFromStart);
+        assert!(restored.strict_duplicate_names());
+        assert!(restored.is_approximate());
+        let (low, high) = meeting.cost_interval(1.0);
+        assert_cost_eq(restored.cost_interval(1.0).0, low, CENT);
+        assert_cost_eq(restored.cost_interval(1.0).1, high, CENT);
+        assert_eq!(restored.total_cost_cents(), meeting.total_cost_cents());
+
+        // Snapshots saved before the settings were kept restore the defaults.
+        let old = r#"{"attendees":[],"elapsed_millis":0,"running":false}"#;
+        let restored =
+            Meeting::try_from(serde_json::from_str::<MeetingSnapshot>(old).unwrap()).unwrap();
+        assert_eq!(restored.budget(), None);
+        assert_eq!(restored.max_duration(), None);
+        assert_eq!(restored.cost_model(), CostModel::TimeWeighted);
+        assert!(!restored.strict_duplicate_names());
+        assert!(!restored.is_approximate());
+    }
+
+    #[test]
+    fn running_meeting_restores_stopped() {
+        let mut meeting = sample_meeting();
+        meeting.start();
+        let snapshot = MeetingSnapshot::from(&meeting);
+        assert!(snapshot.running);
+        let restored = Meeting::try_from(snapshot).unwrap();
+        assert!(!restored.is_running());
+        assert!(restored.duration() >= Duration::from_mins(30));
+    }
+
+    fn running_snapshot(saved_at: SystemTime) -> MeetingSnapshot {
+        let mut meeting = sample_meeting();
+        meeting.set_wall_clock_resume(true);
+        meeting.start();
+        MeetingSnapshot {
+            saved_at: Some(saved_at),
+            ..MeetingSnapshot::from(&meeting)
+        }
+    }
+
+    #[test]
+    fn late_joiners_keep_their_join_time() {
+        let dev = EmployeeCategory::new("Dev", 120_000).unwrap();
+        let lead = EmployeeCategory::new("Lead", 240_000).unwrap();
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&dev, 2);
+        meeting.set_elapsed(Duration::from_mins(20));
+        meeting.add_attendee(&lead, 1);
+        meeting.set_elapsed(Duration::from_mins(30));
+        meeting.remove_attendee("Dev", 1);
+        meeting.set_elapsed(Duration::from_mins(40));
+        // 2 devs for 30 minutes, 1 for 10, and the lead for 20 at $2 a minute.
+        assert_eq!(meeting.total_cost_cents(), 11_000);
+
+        let snapshot = MeetingSnapshot::from(&meeting);
+        assert_eq!(snapshot.roster_log.len(), 3);
+        assert_eq!(snapshot.roster_log[1].at_millis, 20 * 60 * 1000);
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let restored = Meeting::from_state(serde_json::from_str(&json).unwrap()).unwrap();
+        assert_eq!(restored.total_cost_cents(), meeting.total_cost_cents());
+        assert_eq!(restored.timeline(), meeting.timeline());
+        assert_eq!(restored, meeting);
+    }
+
+    #[test]
+    fn round_trip_keeps_partial_attention() {
+        let dev = EmployeeCategory::new("Dev", 120_000).unwrap();
+        let pm = EmployeeCategory::new("PM", 120_000).unwrap();
+        let mut meeting = Meeting::new();
+        meeting.add_attendee_attention(&dev, 2, 0.5).unwrap();
+        meeting.add_person("Alice", &dev).unwrap();
+        meeting.add_person("Bob", &pm).unwrap();
+        meeting.add_attendee_attention(&pm, 1, 0.25).unwrap();
+        meeting.set_elapsed(Duration::from_hours(1));
+        // Three devs at half attention and two PMs at a quarter, $1 a minute each.
+        assert_eq!(meeting.total_cost_cents(), 12_000);
+
+        let snapshot = MeetingSnapshot::from(&meeting);
+        assert!(snapshot.attendees.iter().all(|a| a.attention < 1.0));
+        let toml = toml::to_string(&snapshot).unwrap();
+        let mut restored_snapshot: MeetingSnapshot = toml::from_str(&toml).unwrap();
+        assert_eq!(restored_snapshot, snapshot);
+
+        // Without the roster history, the attendees alone carry the attention.
+        restored_snapshot.roster_log.clear();
+        let restored = Meeting::try_from(restored_snapshot).unwrap();
+        assert_eq!(restored.attention("Dev"), Some(0.5));
+        assert_eq!(restored.attention("PM"), Some(0.25));
+        assert_eq!(restored.total_cost_cents(), meeting.total_cost_cents());
+
+        let restored = Meeting::try_from(snapshot).unwrap();
+        assert_eq!(restored.total_cost_cents(), meeting.total_cost_cents());
+    }
+
+    #[test]
+    fn rejects_roster_changes_with_invalid_attention() {
+        let mut snapshot = MeetingSnapshot::from(&sample_meeting());
+        snapshot.roster_log[0].attention = 0.0;
+        assert_eq!(
+            Meeting::try_from(snapshot).unwrap_err(),
+            MeetingError::InvalidFraction(0.0)
+        );
+    }
+
+    #[test]
+    fn wall_clock_resume_is_opt_in() {
+        let mut meeting = sample_meeting();
+        meeting.start();
+        let snapshot = MeetingSnapshot::from(&meeting);
+        assert!(!snapshot.wall_clock_resume);
+        assert_eq!(snapshot.saved_at, None);
+
+        // A saved time without the opt-in, e.g. set by hand, is ignored too.
+        let saved_at = SystemTime::UNIX_EPOCH + Duration::from_hours(500_000);
+        let snapshot = MeetingSnapshot {
+            saved_at: Some(saved_at),
+            ..snapshot
+        };
+        let elapsed = Duration::from_millis(snapshot.elapsed_millis);
+        let resumed = Meeting::from_state_at(snapshot, saved_at + Duration::from_hours(2)).unwrap();
+        assert!(resumed.is_running());
+        assert!(!resumed.wall_clock_resume());
+        assert!(resumed.duration() < elapsed + Duration::from_mins(1));
+    }
+
+    #[test]
+    fn from_state_adds_downtime_and_resumes() {
+        let saved_at = SystemTime::UNIX_EPOCH + Duration::from_hours(500_000);
+        let snapshot = running_snapshot(saved_at);
+        let elapsed = Duration::from_millis(snapshot.elapsed_millis);
+
+        let resumed = Meeting::from_state_at(snapshot, saved_at + Duration::from_mins(7)).unwrap();
+        assert!(resumed.is_running());
+        let downtime = resumed.duration().saturating_sub(elapsed);
+        assert!(downtime >= Duration::from_mins(7));
+        assert!(downtime < Duration::from_mins(8));
+    }
+
+    #[test]
+    fn from_state_clamps_backwards_clock() {
+        let saved_at = SystemTime::UNIX_EPOCH + Duration::from_hours(500_000);
+        let snapshot = running_snapshot(saved_at);
+        let elapsed = Duration::from_millis(snapshot.elapsed_millis);
+
+        let resumed = Meeting::from_state_at(snapshot, saved_at - Duration::from_hours(3)).unwrap();
+        assert!(resumed.is_running());
+        assert!(resumed.duration() >= elapsed);
+        assert!(resumed.duration() < elapsed + Duration::from_mins(1));
+    }
+
+    #[test]
+    fn from_state_keeps_stopped_meetings_stopped() {
+        let snapshot = MeetingSnapshot::from(&sample_meeting());
+        assert_eq!(snapshot.saved_at, None);
+        let later = SystemTime::now() + Duration::from_hours(1);
+        let restored = Meeting::from_state_at(snapshot, later).unwrap();
+        assert!(!restored.is_running());
+        assert_eq!(restored.duration(), Duration::from_mins(30));
+    }
+
+    #[test]
+    fn rejects_entries_without_salary() {
+        let mut snapshot = MeetingSnapshot::from(&sample_meeting());
+        snapshot.attendees[0].salary = None;
+        assert_eq!(
+            Meeting::try_from(snapshot).unwrap_err(),
+            MeetingError::InvalidAttendee("Dev".into())
+        );
+    }
+}
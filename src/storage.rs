@@ -1,10 +1,21 @@
+use std::collections::BTreeMap;
 use std::fs;
 use std::io::{self, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
 
 use crate::model::EmployeeCategory;
+use crate::settings::Settings;
 use thiserror::Error;
 
+/// File name of the category database inside a [`FileStore`] directory.
+pub const CATEGORIES_FILE: &str = "categories.toml";
+
+/// File name of the settings file inside the data directory.
+pub const SETTINGS_FILE: &str = "settings.toml";
+
 /// Errors that may occur during loading or saving categories.
 #[derive(Debug, Error)]
 pub enum StorageError {
@@ -16,6 +27,18 @@ pub enum StorageError {
 
     #[error("TOML write error: {0}")]
     TomlWrite(#[from] toml::ser::Error),
+
+    #[error("Invalid roster code: {0}")]
+    InvalidCode(String),
+
+    #[error("Invalid CSV: {0}")]
+    InvalidCsv(String),
+
+    #[error("Invalid salary band: {0}")]
+    InvalidBand(String),
+
+    #[error("Category '{0}' has different salaries in different files")]
+    ConflictingCategory(String),
 }
 
 /// Internal helper struct used for serializing and deserializing the collection
@@ -28,13 +51,54 @@ struct CategoryWrapper {
     categories: Vec<EmployeeCategory>,
 }
 
+/// A category in a salary band file, without its exact salary.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct BandedCategory {
+    title: String,
+    /// Salary band such as `"100k–125k"`; absent for observers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    band: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    observer: bool,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct BandedWrapper {
+    categories: Vec<BandedCategory>,
+}
+
 /// Represents a saved attendee entry of a specific title and count.
-#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct AttendeeInfo {
     /// Title of the attendee category.
     pub title: String,
     /// Number of attendees in this category.
     pub count: u32,
+    /// Name of the person, for an entry describing one named attendee.
+    ///
+    /// Files written before names were supported load with `None`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Annual salary of each attendee, for entries that do not rely on a
+    /// category list to look it up, such as a
+    /// [`MeetingSnapshot`](crate::MeetingSnapshot).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub salary: Option<u64>,
+    /// Share of the group's cost that is billed, within `(0.0, 1.0]`; see
+    /// [`Meeting::add_attendee_attention`](crate::Meeting::add_attendee_attention).
+    ///
+    /// Files written before attention was recorded load at full attention.
+    #[serde(default = "full_attention", skip_serializing_if = "is_full_attention")]
+    pub attention: f64,
+}
+
+fn full_attention() -> f64 {
+    1.0
+}
+
+#[allow(clippy::float_cmp, clippy::trivially_copy_pass_by_ref)]
+fn is_full_attention(attention: &f64) -> bool {
+    *attention == 1.0
 }
 
 #[derive(serde::Serialize, serde::Deserialize)]
@@ -42,15 +106,30 @@ struct AttendeesWrapper {
     attendees: Vec<AttendeeInfo>,
 }
 
+/// Represents a saved named attendee and the category they are billed as.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PersonInfo {
+    /// Name of the person.
+    pub name: String,
+    /// Title of the person's category.
+    pub title: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct PeopleWrapper {
+    people: Vec<PersonInfo>,
+}
+
 /// Loads employee categories from a TOML file.
 ///
 /// If the file does not exist an empty collection is returned.
 ///
 /// ## Example
 /// ```
-/// use std::path::Path;
 /// use meeting_cost_tracker::load_categories;
-/// let categories = load_categories(Path::new("categories.toml")).unwrap();
+/// let dir = tempfile::tempdir().unwrap();
+/// let categories = load_categories(&dir.path().join("categories.toml")).unwrap();
+/// assert!(categories.is_empty());
 /// ```
 ///
 /// # Arguments
@@ -81,14 +160,87 @@ pub fn load_categories(path: &Path) -> Result<Vec<EmployeeCategory>, StorageErro
     Ok(wrapper.categories)
 }
 
+/// Loads employee categories from several TOML files, e.g. one per
+/// department, and merges them into one list.
+///
+/// Files are read like [`load_categories`], so missing files count as empty.
+/// Categories are matched by title. A title defined with the same salary in
+/// several files is kept once; one defined with different salaries is taken
+/// from the last file unless `strict` is set. Categories keep the position
+/// at which their title first appeared.
+///
+/// ## Example
+/// ```
+/// use meeting_cost_tracker::{load_categories_merged, save_categories, EmployeeCategory};
+/// let dir = tempfile::tempdir().unwrap();
+/// let (eng, ops) = (dir.path().join("eng.toml"), dir.path().join("ops.toml"));
+/// save_categories(&eng, &[EmployeeCategory::new("Dev", 100_000).unwrap()]).unwrap();
+/// save_categories(&ops, &[EmployeeCategory::new("Dev", 110_000).unwrap()]).unwrap();
+/// let merged = load_categories_merged(&[&eng, &ops], false).unwrap();
+/// assert_eq!(merged[0].salary(), 110_000);
+/// assert!(load_categories_merged(&[&eng, &ops], true).is_err());
+/// ```
+///
+/// # Arguments
+///
+/// * `paths` - TOML files to read, in increasing order of precedence.
+/// * `strict` - Whether a title with different salaries in two files is an
+///   error rather than resolved in favour of the later file.
+///
+/// # Returns
+///
+/// The merged collection of [`EmployeeCategory`] values.
+///
+/// # Errors
+///
+/// Returns a [`StorageError`] if a file cannot be read or parsed, or
+/// [`StorageError::ConflictingCategory`] for a conflicting title when
+/// `strict` is set.
+///
+/// # See Also
+/// * [`load_categories`]
+pub fn load_categories_merged(
+    paths: &[&Path],
+    strict: bool,
+) -> Result<Vec<EmployeeCategory>, StorageError> {
+    let mut merged: Vec<EmployeeCategory> = Vec::new();
+    for path in paths {
+        for category in load_categories(path)? {
+            match merged.iter_mut().find(|c| c.title() == category.title()) {
+                Some(existing) => {
+                    let conflict = existing.salary() != category.salary()
+                        || existing.is_observer() != category.is_observer();
+                    if conflict && strict {
+                        return Err(StorageError::ConflictingCategory(
+                            category.title().to_string(),
+                        ));
+                    }
+                    *existing = category;
+                }
+                None => merged.push(category),
+            }
+        }
+    }
+    Ok(merged)
+}
+
 /// Persists employee categories to a TOML file, overwriting any existing content.
 ///
+/// The categories are written to a temporary file next to `path`, which then
+/// replaces it in one step, so a crash or full disk mid-write leaves the old
+/// file intact. The previous contents are kept in a backup with `.bak`
+/// appended to the file name, e.g. `categories.toml.bak`.
+///
 /// ## Example
 /// ```
-/// use std::path::Path;
-/// use meeting_cost_tracker::{save_categories, EmployeeCategory};
+/// use meeting_cost_tracker::{load_categories, save_categories, EmployeeCategory};
+/// let dir = tempfile::tempdir().unwrap();
+/// let path = dir.path().join("categories.toml");
 /// let categories = vec![EmployeeCategory::new("Engineer", 100_000).unwrap()];
-/// save_categories(Path::new("categories.toml"), &categories).unwrap();
+/// save_categories(&path, &categories).unwrap();
+/// save_categories(&path, &[]).unwrap();
+/// let backup = dir.path().join("categories.toml.bak");
+/// assert_eq!(load_categories(&backup).unwrap(), categories);
 /// ```
 ///
 /// # Arguments
@@ -119,11 +271,327 @@ pub fn save_categories<P: AsRef<Path>>(
         categories: categories.to_vec(),
     };
     let toml = toml::to_string_pretty(&wrapper)?;
-    let mut file = fs::File::create(path)?;
-    file.write_all(toml.as_bytes())?;
+    replace_file(path.as_ref(), toml.as_bytes())?;
+    Ok(())
+}
+
+/// Saves employee categories with each salary replaced by the band it falls in,
+/// for sharing cost figures without revealing exact salaries.
+///
+/// Bands start at multiples of `band_width` and include their lower edge
+/// only, so with a width of $25,000 a salary of $125,000 is in the band
+/// `"125k–150k"` and $124,999 in `"100k–125k"`. Edges that are whole
+/// thousands are written with a `k` suffix. Observers are saved without a
+/// band, and salary sources are left out.
+///
+/// ## Example
+/// ```
+/// use meeting_cost_tracker::{load_categories_banded, save_categories_banded, EmployeeCategory};
+/// let dir = tempfile::tempdir().unwrap();
+/// let path = dir.path().join("bands.toml");
+/// let categories = vec![EmployeeCategory::new("Engineer", 117_000).unwrap()];
+/// save_categories_banded(&path, &categories, 25_000).unwrap();
+/// assert!(std::fs::read_to_string(&path).unwrap().contains("100k–125k"));
+/// let loaded = load_categories_banded(&path).unwrap();
+/// assert_eq!(loaded[0].salary(), 112_500);
+/// assert!(loaded[0].is_approximate());
+/// ```
+///
+/// # Arguments
+///
+/// * `path` - Destination TOML file, overwritten if it exists.
+/// * `categories` - Employee categories to store.
+/// * `band_width` - Width of each salary band in dollars.
+///
+/// # Returns
+///
+/// `Ok(())` once the file has been written.
+///
+/// # Errors
+///
+/// Returns [`StorageError::InvalidBand`] if `band_width` is zero or a band
+/// would end above `u64::MAX`, and other [`StorageError`] variants if the
+/// file cannot be written.
+///
+/// # See Also
+/// * [`load_categories_banded`]
+/// * [`save_categories`]
+pub fn save_categories_banded<P: AsRef<Path>>(
+    path: P,
+    categories: &[EmployeeCategory],
+    band_width: u64,
+) -> Result<(), StorageError> {
+    if band_width == 0 {
+        return Err(StorageError::InvalidBand(
+            "band width must be greater than zero".to_string(),
+        ));
+    }
+    let categories = categories
+        .iter()
+        .map(|category| {
+            let band = if category.is_observer() {
+                None
+            } else {
+                Some(salary_band(category.salary(), band_width).ok_or_else(|| {
+                    StorageError::InvalidBand(format!(
+                        "salary of {} is too large",
+                        category.title()
+                    ))
+                })?)
+            };
+            Ok(BandedCategory {
+                title: category.title().to_string(),
+                band,
+                observer: category.is_observer(),
+            })
+        })
+        .collect::<Result<_, StorageError>>()?;
+    let toml = toml::to_string_pretty(&BandedWrapper { categories })?;
+    fs::write(path, toml)?;
     Ok(())
 }
 
+/// Loads categories saved by [`save_categories_banded`], giving each the
+/// midpoint of its salary band.
+///
+/// The categories are marked [approximate](EmployeeCategory::is_approximate),
+/// their [salary range](EmployeeCategory::salary_range) is the band, and their
+/// source names the band, e.g. `"band 100k–125k"`. Bands may be written with
+/// an en dash or a hyphen.
+///
+/// ## Example
+/// ```
+/// use meeting_cost_tracker::load_categories_banded;
+/// let dir = tempfile::tempdir().unwrap();
+/// let path = dir.path().join("bands.toml");
+/// std::fs::write(&path, "[[categories]]\ntitle = \"PM\"\nband = \"90k-110k\"\n").unwrap();
+/// let categories = load_categories_banded(&path).unwrap();
+/// assert_eq!(categories[0].salary(), 100_000);
+/// assert_eq!(categories[0].source(), Some("band 90k-110k"));
+/// ```
+///
+/// # Arguments
+///
+/// * `path` - Path to the banded TOML file.
+///
+/// # Returns
+///
+/// The categories in file order.
+///
+/// # Errors
+///
+/// Returns [`StorageError::InvalidBand`] for a paid category without a band,
+/// a band that cannot be parsed or does not end above its start, or an empty
+/// title, and other [`StorageError`] variants if the file cannot be read or
+/// parsed.
+///
+/// # See Also
+/// * [`save_categories_banded`]
+pub fn load_categories_banded(path: &Path) -> Result<Vec<EmployeeCategory>, StorageError> {
+    let data = fs::read_to_string(path)?;
+    let wrapper: BandedWrapper = toml::from_str(&data)?;
+    wrapper
+        .categories
+        .into_iter()
+        .map(|entry| {
+            let invalid =
+                |reason: &str| StorageError::InvalidBand(format!("{}: {reason}", entry.title));
+            if entry.observer {
+                return EmployeeCategory::observer(&entry.title)
+                    .map_err(|e| invalid(&e.to_string()));
+            }
+            let band = entry
+                .band
+                .as_deref()
+                .ok_or_else(|| invalid("missing band"))?;
+            let (low, high) = parse_salary_band(band)
+                .ok_or_else(|| invalid(&format!("cannot read band '{band}'")))?;
+            let mut category = EmployeeCategory::new(&entry.title, low + (high - low) / 2)
+                .and_then(|c| c.with_salary_range(low, high))
+                .map_err(|e| invalid(&e.to_string()))?
+                .with_source(format!("band {band}"));
+            category.set_approximate(true);
+            Ok(category)
+        })
+        .collect()
+}
+
+/// Returns the label of the `width` wide band containing `salary`, or
+/// `None` if the band would end above `u64::MAX`.
+fn salary_band(salary: u64, width: u64) -> Option<String> {
+    let low = salary - salary % width;
+    let high = low.checked_add(width)?;
+    Some(format!("{}–{}", band_edge(low), band_edge(high)))
+}
+
+/// Formats a band edge, abbreviating whole thousands as e.g. `125k`.
+fn band_edge(dollars: u64) -> String {
+    if dollars > 0 && dollars.is_multiple_of(1000) {
+        format!("{}k", dollars / 1000)
+    } else {
+        dollars.to_string()
+    }
+}
+
+/// Parses a band label such as `"100k–125k"` into its edges in dollars.
+fn parse_salary_band(band: &str) -> Option<(u64, u64)> {
+    let (low, high) = band.split_once('–').or_else(|| band.split_once('-'))?;
+    let edge = |text: &str| {
+        let text = text.trim();
+        match text.strip_suffix(['k', 'K']) {
+            Some(thousands) => thousands.trim().parse::<u64>().ok()?.checked_mul(1000),
+            None => text.parse().ok(),
+        }
+    };
+    let (low, high) = (edge(low)?, edge(high)?);
+    (high > low).then_some((low, high))
+}
+
+/// Returns `path` with `suffix` appended to its file name.
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(suffix);
+    path.with_file_name(name)
+}
+
+/// Atomically replaces `path` with `contents`, copying the old file to a
+/// `.bak` backup first.
+fn replace_file(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let temp = with_suffix(path, ".tmp");
+    let result = (|| {
+        let mut file = fs::File::create(&temp)?;
+        file.write_all(contents)?;
+        file.sync_all()?;
+        if path.exists() {
+            fs::copy(path, with_suffix(path, ".bak"))?;
+        }
+        fs::rename(&temp, path)
+    })();
+    if result.is_err() {
+        let _ = fs::remove_file(&temp);
+    }
+    result
+}
+
+/// Derives categories from a CSV of employees with `name,role,salary` rows.
+///
+/// Rows are grouped by role and each role becomes one [`EmployeeCategory`]
+/// with the mean salary of its rows, rounded to the nearest dollar.
+/// Categories are returned in the order their role first appears.
+///
+/// A first row naming the columns is used to find the `role` and `salary`
+/// columns; without one, they are the second and third column. Fields may be
+/// quoted, and salaries may contain `$` signs and thousands separators.
+/// Rows with an empty role or a salary of zero are skipped, as are blank
+/// lines.
+///
+/// ## Example
+/// ```
+/// use meeting_cost_tracker::categories_from_employee_csv;
+/// let dir = tempfile::tempdir().unwrap();
+/// let path = dir.path().join("employees.csv");
+/// std::fs::write(
+///     &path,
+///     "name,role,salary\nAda,Engineer,120000\nBob,Engineer,100000\nCy,PM,\"$110,000\"\n",
+/// )
+/// .unwrap();
+/// let categories = categories_from_employee_csv(&path).unwrap();
+/// assert_eq!(categories[0].title(), "Engineer");
+/// assert_eq!(categories[0].salary(), 110_000);
+/// assert_eq!(categories[1].salary(), 110_000);
+/// ```
+///
+/// # Arguments
+///
+/// * `path` - Path to the CSV file.
+///
+/// # Returns
+///
+/// One category per role.
+///
+/// # Errors
+///
+/// Returns [`StorageError::Io`] if the file cannot be read, and
+/// [`StorageError::InvalidCsv`] for a row without a salary column or with a
+/// salary that is not a whole number.
+///
+/// # See Also
+/// * [`save_categories`]
+/// * [`ImportDiff`](crate::ImportDiff)
+pub fn categories_from_employee_csv<P: AsRef<Path>>(
+    path: P,
+) -> Result<Vec<EmployeeCategory>, StorageError> {
+    let data = fs::read_to_string(path)?;
+    let mut rows = data
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| (i + 1, split_csv_row(line)))
+        .peekable();
+    let (mut role_col, mut salary_col) = (1, 2);
+    if let Some((_, header)) = rows.peek() {
+        let column = |name: &str| header.iter().position(|f| f.eq_ignore_ascii_case(name));
+        if let (Some(role), Some(salary)) = (column("role"), column("salary")) {
+            (role_col, salary_col) = (role, salary);
+            rows.next();
+        }
+    }
+    // Role, salary sum and row count, in order of first appearance.
+    let mut roles: Vec<(String, u128, u128)> = Vec::new();
+    for (line, fields) in rows {
+        let role = fields.get(role_col).map_or("", String::as_str);
+        let salary = fields
+            .get(salary_col)
+            .ok_or_else(|| StorageError::InvalidCsv(format!("line {line}: missing salary")))?;
+        let digits: String = salary
+            .chars()
+            .filter(|c| !matches!(c, '$' | ',' | '_') && !c.is_whitespace())
+            .collect();
+        let salary: u64 = digits.parse().map_err(|_| {
+            StorageError::InvalidCsv(format!("line {line}: invalid salary \"{salary}\""))
+        })?;
+        if role.is_empty() || salary == 0 {
+            continue;
+        }
+        match roles.iter_mut().find(|(title, _, _)| title == role) {
+            Some(entry) => {
+                entry.1 += u128::from(salary);
+                entry.2 += 1;
+            }
+            None => roles.push((role.to_string(), u128::from(salary), 1)),
+        }
+    }
+    Ok(roles
+        .into_iter()
+        .filter_map(|(role, sum, rows)| {
+            let mean = u64::try_from((sum * 2 + rows) / (rows * 2)).ok()?;
+            EmployeeCategory::new(role, mean).ok()
+        })
+        .collect())
+}
+
+/// Splits one CSV line into trimmed fields, honouring double-quoted fields
+/// with `""` as an escaped quote.
+fn split_csv_row(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field).trim().to_string()),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field.trim().to_string());
+    fields
+}
+
 /// Loads meeting attendees from a TOML file.
 ///
 /// Missing files result in an empty collection being returned.
@@ -168,10 +636,10 @@ pub fn load_attendees(path: &Path) -> Result<Vec<AttendeeInfo>, StorageError> {
 ///
 /// ## Example
 /// ```
-/// use std::path::Path;
 /// use meeting_cost_tracker::{save_attendees, AttendeeInfo};
-/// let attendees = vec![AttendeeInfo { title: "Dev".into(), count: 1 }];
-/// save_attendees(Path::new("my_list.toml"), &attendees).unwrap();
+/// let dir = tempfile::tempdir().unwrap();
+/// let attendees = vec![AttendeeInfo { title: "Dev".into(), count: 1, name: None, salary: None, attention: 1.0 }];
+/// save_attendees(&dir.path().join("my_list.toml"), &attendees).unwrap();
 /// ```
 ///
 /// # Arguments
@@ -207,11 +675,533 @@ pub fn save_attendees<P: AsRef<Path>>(
     Ok(())
 }
 
+/// Finds rosters in `dir` that were saved more than once under different
+/// names, so they can be offered for cleanup.
+///
+/// Rosters are the files a [`FileStore`] for `dir` lists. Two rosters are
+/// duplicates when they hold the same entries, in any order. Files that
+/// cannot be read or parsed are skipped.
+///
+/// ## Example
+/// ```
+/// use meeting_cost_tracker::{find_duplicate_rosters, save_attendees, AttendeeInfo};
+/// let dir = tempfile::tempdir().unwrap();
+/// let roster = vec![AttendeeInfo { title: "Dev".into(), count: 3, name: None, salary: None, attention: 1.0 }];
+/// save_attendees(dir.path().join("standup"), &roster).unwrap();
+/// save_attendees(dir.path().join("daily"), &roster).unwrap();
+/// assert_eq!(find_duplicate_rosters(dir.path()), [["daily", "standup"]]);
+/// ```
+///
+/// # Arguments
+///
+/// * `dir` - Directory holding saved rosters.
+///
+/// # Returns
+///
+/// One sorted list of names per set of identical rosters, ordered by first
+/// name; empty if there are no duplicates or `dir` cannot be read.
+///
+/// # See Also
+/// * [`FileStore`]
+#[must_use]
+pub fn find_duplicate_rosters(dir: &Path) -> Vec<Vec<String>> {
+    let store = FileStore::new(dir);
+    let mut groups: Vec<(Vec<AttendeeInfo>, Vec<String>)> = Vec::new();
+    for name in store.list_rosters().unwrap_or_default() {
+        let Ok(mut roster) = store.load_roster(&name) else {
+            continue;
+        };
+        roster.sort_by(|a, b| {
+            (&a.title, &a.name, a.count, a.salary).cmp(&(&b.title, &b.name, b.count, b.salary))
+        });
+        match groups.iter_mut().find(|(content, _)| *content == roster) {
+            Some((_, names)) => names.push(name),
+            None => groups.push((roster, vec![name])),
+        }
+    }
+    groups
+        .into_iter()
+        .map(|(_, names)| names)
+        .filter(|names| names.len() > 1)
+        .collect()
+}
+
+/// Encodes a roster as a short code that can be shared in chat.
+///
+/// The code is the roster's TOML, base64 encoded with the URL-safe alphabet
+/// and no padding, so it survives being pasted into URLs and messages.
+///
+/// ## Example
+/// ```
+/// use meeting_cost_tracker::{roster_from_code, roster_to_code, AttendeeInfo};
+/// let roster = vec![AttendeeInfo { title: "Dev".into(), count: 3, name: None, salary: None, attention: 1.0 }];
+/// let code = roster_to_code(&roster);
+/// assert_eq!(roster_from_code(&code).unwrap(), roster);
+/// ```
+///
+/// # Arguments
+///
+/// * `attendees` - Roster entries to encode.
+///
+/// # Returns
+///
+/// The roster code.
+///
+/// # See Also
+/// * [`roster_from_code`]
+#[must_use]
+pub fn roster_to_code(attendees: &[AttendeeInfo]) -> String {
+    let wrapper = AttendeesWrapper {
+        attendees: attendees.to_vec(),
+    };
+    // Titles, counts and names always serialize, so this never falls back.
+    let toml = toml::to_string(&wrapper).unwrap_or_default();
+    URL_SAFE_NO_PAD.encode(toml)
+}
+
+/// Decodes a roster code produced by [`roster_to_code`].
+///
+/// Surrounding whitespace is ignored.
+///
+/// # Arguments
+///
+/// * `code` - The roster code.
+///
+/// # Returns
+///
+/// The decoded roster entries.
+///
+/// # Errors
+///
+/// Returns [`StorageError::InvalidCode`] if `code` is not valid base64 or does
+/// not decode to text, and [`StorageError::Toml`] if the text is not a roster.
+///
+/// # See Also
+/// * [`roster_to_code`]
+pub fn roster_from_code(code: &str) -> Result<Vec<AttendeeInfo>, StorageError> {
+    let bytes = URL_SAFE_NO_PAD
+        .decode(code.trim())
+        .map_err(|err| StorageError::InvalidCode(err.to_string()))?;
+    let toml = String::from_utf8(bytes)
+        .map_err(|_| StorageError::InvalidCode("not a text roster".to_string()))?;
+    let wrapper: AttendeesWrapper = toml::from_str(&toml)?;
+    Ok(wrapper.attendees)
+}
+
+/// Loads named attendees from a TOML file.
+///
+/// Missing files result in an empty collection being returned.
+///
+/// ## Example
+/// ```
+/// use std::path::Path;
+/// use meeting_cost_tracker::load_people;
+/// let people = load_people(Path::new("does_not_exist.toml")).unwrap();
+/// assert!(people.is_empty());
+/// ```
+///
+/// # Arguments
+///
+/// * `path` - Path to the people list to read.
+///
+/// # Returns
+///
+/// A vector of [`PersonInfo`] entries.
+///
+/// # Errors
+///
+/// Returns a [`StorageError`] if the file cannot be read or if the contents
+/// fail to parse as TOML.
+///
+/// # See Also
+/// * [`save_people`]
+pub fn load_people(path: &Path) -> Result<Vec<PersonInfo>, StorageError> {
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    let data = fs::read_to_string(path)?;
+    let wrapper: PeopleWrapper = toml::from_str(&data)?;
+    Ok(wrapper.people)
+}
+
+/// Persists named attendees to a TOML file, overwriting any existing file.
+///
+/// ## Example
+/// ```
+/// use meeting_cost_tracker::{save_people, PersonInfo};
+/// let dir = tempfile::tempdir().unwrap();
+/// let people = vec![PersonInfo { name: "Alice".into(), title: "Staff Engineer".into() }];
+/// save_people(dir.path().join("people.toml"), &people).unwrap();
+/// ```
+///
+/// # Arguments
+///
+/// * `path` - Destination file path.
+/// * `people` - Named attendees to store, e.g. from [`Meeting::people`](crate::Meeting::people).
+///
+/// # Returns
+///
+/// Result indicating success or failure.
+///
+/// # Errors
+///
+/// Returns a [`StorageError`] if the file cannot be created or written, or if
+/// serialization fails.
+///
+/// # See Also
+/// * [`load_people`]
+pub fn save_people<P: AsRef<Path>>(path: P, people: &[PersonInfo]) -> Result<(), StorageError> {
+    let wrapper = PeopleWrapper {
+        people: people.to_vec(),
+    };
+    let toml = toml::to_string_pretty(&wrapper)?;
+    fs::write(path, toml)?;
+    Ok(())
+}
+
+/// Loads application settings from a TOML file.
+///
+/// Missing files and missing keys fall back to [`Settings::default`].
+///
+/// ## Example
+/// ```
+/// use std::path::Path;
+/// use meeting_cost_tracker::{load_settings, Settings};
+/// let settings = load_settings(Path::new("does_not_exist.toml")).unwrap();
+/// assert_eq!(settings, Settings::default());
+/// ```
+///
+/// # Arguments
+///
+/// * `path` - Path to the settings file.
+///
+/// # Returns
+///
+/// The loaded [`Settings`].
+///
+/// # Errors
+///
+/// Returns a [`StorageError`] if the file cannot be read or if the contents
+/// fail to parse as TOML.
+///
+/// # See Also
+/// * [`save_settings`]
+///
+/// # Panics
+///
+/// This function does not panic.
+pub fn load_settings(path: &Path) -> Result<Settings, StorageError> {
+    if !path.exists() {
+        return Ok(Settings::default());
+    }
+    let data = fs::read_to_string(path)?;
+    Ok(toml::from_str(&data)?)
+}
+
+/// Persists application settings to a TOML file, overwriting any existing file.
+///
+/// # Arguments
+///
+/// * `path` - Destination file path.
+/// * `settings` - Settings to store.
+///
+/// # Returns
+///
+/// Result indicating success or failure.
+///
+/// # Errors
+///
+/// Returns a [`StorageError`] if the file cannot be created or written, or if
+/// serialization fails.
+///
+/// # See Also
+/// * [`load_settings`]
+///
+/// # Panics
+///
+/// This function does not panic.
+pub fn save_settings<P: AsRef<Path>>(path: P, settings: &Settings) -> Result<(), StorageError> {
+    let toml = toml::to_string_pretty(settings)?;
+    let mut file = fs::File::create(path)?;
+    file.write_all(toml.as_bytes())?;
+    Ok(())
+}
+
+/// Storage backend for the list of known [`EmployeeCategory`] values.
+pub trait CategoryStore {
+    /// Loads all stored categories, returning an empty list if none were saved.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StorageError`] if the backend cannot be read or parsed.
+    fn load_categories(&self) -> Result<Vec<EmployeeCategory>, StorageError>;
+
+    /// Replaces the stored categories.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StorageError`] if the backend cannot be written.
+    fn save_categories(&mut self, categories: &[EmployeeCategory]) -> Result<(), StorageError>;
+}
+
+/// Storage backend for named attendee rosters.
+pub trait RosterStore {
+    /// Lists the names of all saved rosters in sorted order.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StorageError`] if the backend cannot be read.
+    fn list_rosters(&self) -> Result<Vec<String>, StorageError>;
+
+    /// Loads the roster saved under `name`, returning an empty roster if it
+    /// does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StorageError`] if the backend cannot be read or parsed.
+    fn load_roster(&self, name: &str) -> Result<Vec<AttendeeInfo>, StorageError>;
+
+    /// Saves `attendees` under `name`, replacing any roster with that name.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StorageError`] if the backend cannot be written.
+    fn save_roster(&mut self, name: &str, attendees: &[AttendeeInfo]) -> Result<(), StorageError>;
+}
+
+/// Stores categories and rosters as TOML files in a directory.
+///
+/// Categories live in [`CATEGORIES_FILE`]; every other file in the directory
+/// except [`SETTINGS_FILE`] is treated as a roster named after the file.
+///
+/// ## Example
+/// ```
+/// use meeting_cost_tracker::{AttendeeInfo, FileStore, RosterStore};
+/// let dir = tempfile::tempdir().unwrap();
+/// let mut store = FileStore::new(dir.path());
+/// let roster = vec![AttendeeInfo { title: "Dev".into(), count: 2, name: None, salary: None, attention: 1.0 }];
+/// store.save_roster("standup.toml", &roster).unwrap();
+/// assert_eq!(store.load_roster("standup.toml").unwrap(), roster);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FileStore {
+    dir: PathBuf,
+}
+
+impl FileStore {
+    /// Creates a store backed by the files in `dir`.
+    ///
+    /// The directory is not created; callers should ensure it exists before
+    /// saving.
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Returns the directory holding the store's files.
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+}
+
+impl CategoryStore for FileStore {
+    fn load_categories(&self) -> Result<Vec<EmployeeCategory>, StorageError> {
+        load_categories(&self.dir.join(CATEGORIES_FILE))
+    }
+
+    fn save_categories(&mut self, categories: &[EmployeeCategory]) -> Result<(), StorageError> {
+        save_categories(self.dir.join(CATEGORIES_FILE), categories)
+    }
+}
+
+impl RosterStore for FileStore {
+    fn list_rosters(&self) -> Result<Vec<String>, StorageError> {
+        if !self.dir.exists() {
+            return Ok(vec![]);
+        }
+        let mut names = Vec::new();
+        for entry in fs::read_dir(&self.dir)? {
+            let entry = entry?;
+            if !entry.file_type()?.is_file() {
+                continue;
+            }
+            if let Some(name) = entry.file_name().to_str() {
+                // The category database, its backup and its temporary file.
+                let is_categories = name
+                    .strip_prefix(CATEGORIES_FILE)
+                    .is_some_and(|rest| matches!(rest, "" | ".bak" | ".tmp"));
+                if !is_categories && name != SETTINGS_FILE {
+                    names.push(name.to_string());
+                }
+            }
+        }
+        names.sort();
+        Ok(names)
+    }
+
+    fn load_roster(&self, name: &str) -> Result<Vec<AttendeeInfo>, StorageError> {
+        load_attendees(&self.dir.join(name))
+    }
+
+    fn save_roster(&mut self, name: &str, attendees: &[AttendeeInfo]) -> Result<(), StorageError> {
+        save_attendees(self.dir.join(name), attendees)
+    }
+}
+
+/// Keeps categories and rosters in memory without touching the filesystem.
+///
+/// Useful for tests and for embedding the library where persistence is
+/// handled elsewhere.
+///
+/// ## Example
+/// ```
+/// use meeting_cost_tracker::{CategoryStore, EmployeeCategory, MemoryStore};
+/// let mut store = MemoryStore::default();
+/// let categories = vec![EmployeeCategory::new("Dev", 100_000).unwrap()];
+/// store.save_categories(&categories).unwrap();
+/// assert_eq!(store.load_categories().unwrap(), categories);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStore {
+    categories: Vec<EmployeeCategory>,
+    rosters: BTreeMap<String, Vec<AttendeeInfo>>,
+}
+
+impl CategoryStore for MemoryStore {
+    fn load_categories(&self) -> Result<Vec<EmployeeCategory>, StorageError> {
+        Ok(self.categories.clone())
+    }
+
+    fn save_categories(&mut self, categories: &[EmployeeCategory]) -> Result<(), StorageError> {
+        self.categories = categories.to_vec();
+        Ok(())
+    }
+}
+
+impl RosterStore for MemoryStore {
+    fn list_rosters(&self) -> Result<Vec<String>, StorageError> {
+        Ok(self.rosters.keys().cloned().collect())
+    }
+
+    fn load_roster(&self, name: &str) -> Result<Vec<AttendeeInfo>, StorageError> {
+        Ok(self.rosters.get(name).cloned().unwrap_or_default())
+    }
+
+    fn save_roster(&mut self, name: &str, attendees: &[AttendeeInfo]) -> Result<(), StorageError> {
+        self.rosters.insert(name.to_string(), attendees.to_vec());
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use tempfile::NamedTempFile;
 
+    #[test]
+    fn duplicate_rosters_group_identical_content() {
+        let dir = tempfile::tempdir().unwrap();
+        let entry = |title: &str, count| AttendeeInfo {
+            title: title.into(),
+            count,
+            name: None,
+            salary: None,
+            attention: 1.0,
+        };
+        save_attendees(
+            dir.path().join("standup"),
+            &[entry("Dev", 3), entry("PM", 1)],
+        )
+        .unwrap();
+        save_attendees(dir.path().join("daily"), &[entry("PM", 1), entry("Dev", 3)]).unwrap();
+        save_attendees(dir.path().join("retro"), &[entry("Dev", 4), entry("PM", 1)]).unwrap();
+        fs::write(dir.path().join("notes"), "not a roster").unwrap();
+        save_categories(dir.path().join(CATEGORIES_FILE), &[]).unwrap();
+
+        assert_eq!(find_duplicate_rosters(dir.path()), [["daily", "standup"]]);
+        fs::remove_file(dir.path().join("daily")).unwrap();
+        assert!(find_duplicate_rosters(dir.path()).is_empty());
+        assert!(find_duplicate_rosters(&dir.path().join("missing")).is_empty());
+    }
+
+    #[test]
+    fn merged_categories_let_later_files_win() {
+        let dir = tempfile::tempdir().unwrap();
+        let eng = dir.path().join("eng.toml");
+        let ops = dir.path().join("ops.toml");
+        let dev = EmployeeCategory::new("Dev", 100_000).unwrap();
+        let qa = EmployeeCategory::new("QA", 90_000).unwrap();
+        let sre = EmployeeCategory::new("SRE", 130_000).unwrap();
+        let raised = EmployeeCategory::new("QA", 95_000).unwrap();
+        save_categories(&eng, &[dev.clone(), qa.clone()]).unwrap();
+        save_categories(&ops, &[raised.clone(), sre.clone(), dev.clone()]).unwrap();
+        let missing = dir.path().join("missing.toml");
+
+        let merged = load_categories_merged(&[&eng, &missing, &ops], false).unwrap();
+        assert_eq!(merged, [dev.clone(), raised, sre.clone()]);
+        let reversed = load_categories_merged(&[&ops, &eng], false).unwrap();
+        assert_eq!(reversed, [qa, sre, dev]);
+        assert!(load_categories_merged(&[&missing], true)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn strict_merge_rejects_conflicting_salaries() {
+        let dir = tempfile::tempdir().unwrap();
+        let eng = dir.path().join("eng.toml");
+        let ops = dir.path().join("ops.toml");
+        let same = dir.path().join("same.toml");
+        save_categories(&eng, &[EmployeeCategory::new("Dev", 100_000).unwrap()]).unwrap();
+        save_categories(&same, &[EmployeeCategory::new("Dev", 100_000).unwrap()]).unwrap();
+        save_categories(&ops, &[EmployeeCategory::new("Dev", 110_000).unwrap()]).unwrap();
+
+        assert_eq!(
+            load_categories_merged(&[&eng, &same], true).unwrap().len(),
+            1
+        );
+        let err = load_categories_merged(&[&eng, &same, &ops], true).unwrap_err();
+        assert!(matches!(err, StorageError::ConflictingCategory(ref t) if t == "Dev"));
+        assert_eq!(
+            err.to_string(),
+            "Category 'Dev' has different salaries in different files"
+        );
+    }
+
+    #[test]
+    fn save_categories_keeps_backup_of_previous_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(CATEGORIES_FILE);
+        let first = vec![EmployeeCategory::new("Dev", 100_000).unwrap()];
+        let second = vec![EmployeeCategory::new("Dev", 110_000).unwrap()];
+        save_categories(&path, &first).unwrap();
+        assert!(!dir.path().join("categories.toml.bak").exists());
+        let written = fs::read_to_string(&path).unwrap();
+
+        save_categories(&path, &second).unwrap();
+        let backup = dir.path().join("categories.toml.bak");
+        assert_eq!(fs::read_to_string(&backup).unwrap(), written);
+        assert_eq!(load_categories(&backup).unwrap(), first);
+        assert_eq!(load_categories(&path).unwrap(), second);
+        assert!(!dir.path().join("categories.toml.tmp").exists());
+
+        let mut store = FileStore::new(dir.path());
+        store.save_roster("team", &[]).unwrap();
+        assert_eq!(store.list_rosters().unwrap(), ["team"]);
+    }
+
+    #[test]
+    fn failed_save_leaves_file_intact() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(CATEGORIES_FILE);
+        let first = vec![EmployeeCategory::new("Dev", 100_000).unwrap()];
+        save_categories(&path, &first).unwrap();
+        // A directory in the way of the temporary file makes the write fail.
+        fs::create_dir(dir.path().join("categories.toml.tmp")).unwrap();
+        assert!(save_categories(&path, &[]).is_err());
+        assert_eq!(load_categories(&path).unwrap(), first);
+    }
+
     #[test]
     fn load_nonexistent_returns_empty() {
         let tmp = NamedTempFile::new().unwrap();
@@ -238,15 +1228,298 @@ mod tests {
         assert!(res.is_err());
     }
 
+    #[test]
+    fn employee_csv_averages_salary_per_role() {
+        let mut tmp = NamedTempFile::new().unwrap();
+        tmp.write_all(
+            b"name,role,salary\n\
+              Ada,Engineer,120000\n\
+              Bob,Manager,150000\n\
+              Cy,Engineer,100001\n\
+              \n\
+              Dee,Manager,\"$160,000\"\n\
+              \"Smith, Eve\",Engineer,110000\n\
+              Guest,,90000\n\
+              Intern,Engineer,0\n",
+        )
+        .unwrap();
+        let categories = categories_from_employee_csv(tmp.path()).unwrap();
+        let summary: Vec<(&str, u64)> =
+            categories.iter().map(|c| (c.title(), c.salary())).collect();
+        assert_eq!(summary, vec![("Engineer", 110_000), ("Manager", 155_000)]);
+    }
+
+    #[test]
+    fn employee_csv_without_header_and_with_bad_rows() {
+        let mut tmp = NamedTempFile::new().unwrap();
+        tmp.write_all(b"Ada,Engineer,1\nBob,Engineer,2\n").unwrap();
+        let categories = categories_from_employee_csv(tmp.path()).unwrap();
+        assert_eq!(categories[0].salary(), 2);
+
+        let mut tmp = NamedTempFile::new().unwrap();
+        tmp.write_all(b"name,role,salary\nAda,Engineer,lots\n")
+            .unwrap();
+        assert!(matches!(
+            categories_from_employee_csv(tmp.path()),
+            Err(StorageError::InvalidCsv(msg)) if msg.contains("line 2")
+        ));
+        assert_eq!(
+            split_csv_row(r#"a, "b ""c"", d",e"#),
+            vec!["a", r#"b "c", d"#, "e"]
+        );
+    }
+
+    #[test]
+    fn roster_code_round_trip() {
+        let roster = vec![
+            AttendeeInfo {
+                title: "Dev".into(),
+                count: 4,
+                name: None,
+                salary: None,
+                attention: 1.0,
+            },
+            AttendeeInfo {
+                title: "PM".into(),
+                count: 1,
+                name: Some("Alice".into()),
+                salary: None,
+                attention: 1.0,
+            },
+        ];
+        let code = roster_to_code(&roster);
+        assert!(code
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+        assert_eq!(roster_from_code(&format!(" {code}\n")).unwrap(), roster);
+        assert!(roster_from_code(&roster_to_code(&[])).unwrap().is_empty());
+    }
+
+    #[test]
+    fn invalid_roster_code_errors() {
+        assert!(matches!(
+            roster_from_code("not base64!"),
+            Err(StorageError::InvalidCode(_))
+        ));
+        assert!(matches!(
+            roster_from_code(&URL_SAFE_NO_PAD.encode([0xff, 0xfe])),
+            Err(StorageError::InvalidCode(_))
+        ));
+        assert!(matches!(
+            roster_from_code(&URL_SAFE_NO_PAD.encode("attendees = 3")),
+            Err(StorageError::Toml(_))
+        ));
+    }
+
     #[test]
     fn attendees_round_trip() {
         let tmp = NamedTempFile::new().unwrap();
         let attendees = vec![AttendeeInfo {
             title: "Dev".into(),
             count: 3,
+            name: None,
+            salary: None,
+            attention: 1.0,
         }];
         save_attendees(tmp.path(), &attendees).unwrap();
         let loaded = load_attendees(tmp.path()).unwrap();
         assert_eq!(attendees, loaded);
     }
+
+    #[test]
+    fn attendee_names_are_optional() {
+        let mut tmp = NamedTempFile::new().unwrap();
+        tmp.write_all(b"[[attendees]]\ntitle = \"Dev\"\ncount = 2\n")
+            .unwrap();
+        let loaded = load_attendees(tmp.path()).unwrap();
+        assert_eq!(loaded[0].name, None);
+
+        let attendees = vec![
+            AttendeeInfo {
+                title: "Dev".into(),
+                count: 2,
+                name: None,
+                salary: None,
+                attention: 1.0,
+            },
+            AttendeeInfo {
+                title: "Dev".into(),
+                count: 1,
+                name: Some("Alice".into()),
+                salary: None,
+                attention: 1.0,
+            },
+        ];
+        save_attendees(tmp.path(), &attendees).unwrap();
+        assert_eq!(load_attendees(tmp.path()).unwrap(), attendees);
+    }
+
+    #[test]
+    fn people_round_trip() {
+        let tmp = NamedTempFile::new().unwrap();
+        let people = vec![
+            PersonInfo {
+                name: "Alice".into(),
+                title: "Staff Engineer".into(),
+            },
+            PersonInfo {
+                name: "Bob".into(),
+                title: "PM".into(),
+            },
+        ];
+        save_people(tmp.path(), &people).unwrap();
+        assert_eq!(load_people(tmp.path()).unwrap(), people);
+    }
+
+    #[test]
+    fn settings_round_trip_and_partial_files() {
+        let mut tmp = NamedTempFile::new().unwrap();
+        tmp.write_all(b"[roster_warnings]\nmax_headcount = 3\n")
+            .unwrap();
+        let loaded = load_settings(tmp.path()).unwrap();
+        assert_eq!(loaded.roster_warnings.max_headcount, Some(3));
+        assert_eq!(loaded.roster_warnings.max_burn_rate_per_minute, None);
+
+        let settings = Settings::default();
+        save_settings(tmp.path(), &settings).unwrap();
+        assert_eq!(load_settings(tmp.path()).unwrap(), settings);
+    }
+
+    fn check_category_store<S: CategoryStore>(store: &mut S) {
+        assert!(store.load_categories().unwrap().is_empty());
+        let cats = vec![
+            EmployeeCategory::new("A", 1).unwrap(),
+            EmployeeCategory::new("B", 2).unwrap(),
+        ];
+        store.save_categories(&cats).unwrap();
+        assert_eq!(store.load_categories().unwrap(), cats);
+        store.save_categories(&cats[1..]).unwrap();
+        assert_eq!(store.load_categories().unwrap(), &cats[1..]);
+    }
+
+    fn check_roster_store<S: RosterStore>(store: &mut S) {
+        assert!(store.list_rosters().unwrap().is_empty());
+        assert!(store.load_roster("missing.toml").unwrap().is_empty());
+        let standup = vec![AttendeeInfo {
+            title: "Dev".into(),
+            count: 4,
+            name: None,
+            salary: None,
+            attention: 1.0,
+        }];
+        let review = vec![AttendeeInfo {
+            title: "PM".into(),
+            count: 1,
+            name: None,
+            salary: None,
+            attention: 1.0,
+        }];
+        store.save_roster("standup.toml", &standup).unwrap();
+        store.save_roster("review.toml", &review).unwrap();
+        assert_eq!(
+            store.list_rosters().unwrap(),
+            vec!["review.toml".to_string(), "standup.toml".to_string()]
+        );
+        assert_eq!(store.load_roster("standup.toml").unwrap(), standup);
+        store.save_roster("standup.toml", &review).unwrap();
+        assert_eq!(store.load_roster("standup.toml").unwrap(), review);
+    }
+
+    #[test]
+    fn file_store_behaves_like_a_store() {
+        let dir = tempfile::tempdir().unwrap();
+        check_category_store(&mut FileStore::new(dir.path()));
+        let dir = tempfile::tempdir().unwrap();
+        check_roster_store(&mut FileStore::new(dir.path()));
+    }
+
+    #[test]
+    fn memory_store_behaves_like_a_store() {
+        check_category_store(&mut MemoryStore::default());
+        check_roster_store(&mut MemoryStore::default());
+    }
+
+    #[test]
+    fn file_store_hides_categories_and_settings() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut store = FileStore::new(dir.path());
+        store
+            .save_categories(&[EmployeeCategory::new("A", 1).unwrap()])
+            .unwrap();
+        save_settings(dir.path().join(SETTINGS_FILE), &Settings::default()).unwrap();
+        assert!(store.list_rosters().unwrap().is_empty());
+    }
+
+    #[test]
+    fn salary_band_includes_lower_edge_only() {
+        assert_eq!(salary_band(100_000, 25_000).unwrap(), "100k–125k");
+        assert_eq!(salary_band(124_999, 25_000).unwrap(), "100k–125k");
+        assert_eq!(salary_band(125_000, 25_000).unwrap(), "125k–150k");
+        assert_eq!(salary_band(1, 25_000).unwrap(), "0–25k");
+        assert_eq!(salary_band(101_000, 2_500).unwrap(), "100k–102500");
+        assert_eq!(salary_band(u64::MAX, 25_000), None);
+    }
+
+    #[test]
+    fn parse_salary_band_reads_labels() {
+        assert_eq!(parse_salary_band("100k–125k"), Some((100_000, 125_000)));
+        assert_eq!(parse_salary_band("100K - 102500"), Some((100_000, 102_500)));
+        assert_eq!(parse_salary_band("0–25k"), Some((0, 25_000)));
+        assert_eq!(parse_salary_band("125k–100k"), None);
+        assert_eq!(parse_salary_band("about 100k"), None);
+    }
+
+    #[test]
+    fn banded_round_trip_uses_midpoints() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("bands.toml");
+        let categories = vec![
+            EmployeeCategory::new("Engineer", 124_999)
+                .unwrap()
+                .with_source("HR sheet"),
+            EmployeeCategory::new("Manager", 125_000).unwrap(),
+            EmployeeCategory::observer("Guest").unwrap(),
+        ];
+        save_categories_banded(&path, &categories, 25_000).unwrap();
+        let contents = fs::read_to_string(&path).unwrap();
+        assert!(!contents.contains("124999"));
+        assert!(!contents.contains("HR sheet"));
+
+        let loaded = load_categories_banded(&path).unwrap();
+        assert_eq!(loaded[0].title(), "Engineer");
+        assert_eq!(loaded[0].salary(), 112_500);
+        assert_eq!(loaded[1].salary(), 137_500);
+        assert!(loaded[0].is_approximate() && loaded[1].is_approximate());
+        assert_eq!(loaded[1].source(), Some("band 125k–150k"));
+        assert_eq!(loaded[1].salary_range(), Some((125_000, 150_000)));
+        assert!(loaded[2].is_observer());
+        assert!(!loaded[2].is_approximate());
+    }
+
+    #[test]
+    fn banded_rejects_zero_width_and_bad_bands() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("bands.toml");
+        let categories = [EmployeeCategory::new("Engineer", 100_000).unwrap()];
+        assert!(matches!(
+            save_categories_banded(&path, &categories, 0),
+            Err(StorageError::InvalidBand(_))
+        ));
+        for band in ["", "band = \"lots\"\n", "band = \"0–1\"\n"] {
+            fs::write(&path, format!("[[categories]]\ntitle = \"PM\"\n{band}")).unwrap();
+            assert!(matches!(
+                load_categories_banded(&path),
+                Err(StorageError::InvalidBand(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn approximate_flag_survives_save() {
+        let file = NamedTempFile::new().unwrap();
+        let mut category = EmployeeCategory::new("Engineer", 112_500).unwrap();
+        category.set_approximate(true);
+        save_categories(file.path(), std::slice::from_ref(&category)).unwrap();
+        assert!(load_categories(file.path()).unwrap()[0].is_approximate());
+    }
 }
@@ -0,0 +1,305 @@
+//! Helpers for testing code that uses a [`Meeting`](crate::Meeting).
+//!
+//! Available with the `test-util` feature. [`MeetingFixture`] builds meetings
+//! on a [`ManualClock`], so time passes exactly and instantly, and
+//! [`assert_cost_eq`] compares the resulting costs.
+//!
+//! ## Example
+//! ```
+//! use std::time::Duration;
+//! use meeting_cost_tracker::testing::{assert_cost_eq, engineer, MeetingFixture, CENT};
+//!
+//! let (meeting, _clock) = MeetingFixture::new()
+//!     .attendees(&engineer(), 3)
+//!     .run_for(Duration::from_mins(20))
+//!     .build();
+//! assert_cost_eq(meeting.total_cost(), 60.0, CENT);
+//! ```
+
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use crate::clock::Clock;
+use crate::meeting::Meeting;
+use crate::model::EmployeeCategory;
+
+/// Tolerance of one cent for [`assert_cost_eq`].
+pub const CENT: f64 = 0.01;
+
+/// Asserts that two costs in dollars differ by no more than `tolerance`.
+///
+/// ## Example
+/// ```
+/// use meeting_cost_tracker::testing::{assert_cost_eq, CENT};
+/// assert_cost_eq(0.1 + 0.2, 0.3, 1e-9);
+/// assert_cost_eq(10.004, 10.0, CENT);
+/// ```
+///
+/// # Arguments
+///
+/// * `actual` - The cost computed by the code under test.
+/// * `expected` - The cost it should be.
+/// * `tolerance` - Largest accepted difference, e.g. [`CENT`].
+///
+/// # Panics
+///
+/// Panics, reporting both costs, if they differ by more than `tolerance` or
+/// either is not a number.
+#[track_caller]
+pub fn assert_cost_eq(actual: f64, expected: f64, tolerance: f64) {
+    let difference = (actual - expected).abs();
+    assert!(
+        difference <= tolerance,
+        "cost {actual} differs from expected {expected} by {difference}, more than {tolerance}"
+    );
+}
+
+/// An engineer on $120,000 a year, costing $1 a minute over the default
+/// 2,000 hour work year.
+#[must_use]
+pub fn engineer() -> EmployeeCategory {
+    category("Engineer", 120_000)
+}
+
+/// A manager on $240,000 a year, costing $2 a minute.
+#[must_use]
+pub fn manager() -> EmployeeCategory {
+    category("Manager", 240_000)
+}
+
+/// A director on $480,000 a year, costing $4 a minute.
+#[must_use]
+pub fn director() -> EmployeeCategory {
+    category("Director", 480_000)
+}
+
+/// A guest who costs nothing; see [`EmployeeCategory::observer`].
+#[must_use]
+pub fn guest() -> EmployeeCategory {
+    EmployeeCategory::observer("Guest").unwrap_or_else(|_| unreachable!())
+}
+
+fn category(title: &str, salary: u64) -> EmployeeCategory {
+    EmployeeCategory::new(title, salary).unwrap_or_else(|_| unreachable!())
+}
+
+/// A [`Clock`] that only moves when told to.
+///
+/// Clones share the same time, so keep one handle to advance the clock after
+/// passing another to [`Meeting::with_clock`](crate::Meeting::with_clock).
+///
+/// ## Example
+/// ```
+/// use std::time::Duration;
+/// use meeting_cost_tracker::testing::ManualClock;
+/// use meeting_cost_tracker::{EmployeeCategory, Meeting};
+///
+/// let clock = ManualClock::new();
+/// let mut meeting = Meeting::with_clock(clock.clone());
+/// meeting.add_attendee(&EmployeeCategory::new("Dev", 120_000).unwrap(), 1);
+/// meeting.start();
+/// clock.advance(Duration::from_secs(600));
+/// assert_eq!(meeting.total_cost_cents(), 1_000);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ManualClock {
+    origin: Instant,
+    offset: Arc<Mutex<Duration>>,
+}
+
+impl ManualClock {
+    /// Creates a clock stopped at the current instant.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            origin: Instant::now(),
+            offset: Arc::new(Mutex::new(Duration::ZERO)),
+        }
+    }
+
+    /// Moves the clock forward by `by`.
+    ///
+    /// # Arguments
+    ///
+    /// * `by` - Time to advance.
+    pub fn advance(&self, by: Duration) {
+        *self.offset.lock().unwrap_or_else(PoisonError::into_inner) += by;
+    }
+
+    /// Returns how far the clock has been advanced since it was created.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        *self.offset.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Default for ManualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        self.origin + self.elapsed()
+    }
+}
+
+/// One scripted change made by [`MeetingFixture::build`].
+#[derive(Debug, Clone)]
+enum Step {
+    Join(EmployeeCategory, u32),
+    Leave(String, u32),
+    Run(Duration),
+}
+
+/// Builds a [`Meeting`] by replaying attendee changes and elapsed time on a
+/// [`ManualClock`].
+///
+/// The meeting starts before the first step, so attendees added after a
+/// [`run_for`](MeetingFixture::run_for) join late and are billed only from
+/// then on. It is stopped after the last step unless
+/// [`running`](MeetingFixture::running) is used.
+///
+/// ## Example
+/// ```
+/// use std::time::Duration;
+/// use meeting_cost_tracker::testing::{assert_cost_eq, engineer, manager, MeetingFixture, CENT};
+///
+/// // Two engineers for 30 minutes, joined by a manager for the last 10.
+/// let (meeting, clock) = MeetingFixture::new()
+///     .attendees(&engineer(), 2)
+///     .run_for(Duration::from_mins(20))
+///     .attendees(&manager(), 1)
+///     .run_for(Duration::from_mins(10))
+///     .running()
+///     .build();
+/// assert_cost_eq(meeting.total_cost(), 80.0, CENT);
+///
+/// clock.advance(Duration::from_mins(1));
+/// assert_cost_eq(meeting.total_cost(), 84.0, CENT);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MeetingFixture {
+    steps: Vec<Step>,
+    keep_running: bool,
+}
+
+impl MeetingFixture {
+    /// Creates a fixture for an empty meeting.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `count` attendees of `category` at this point of the meeting.
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - Category of the attendees.
+    /// * `count` - Number of attendees joining.
+    #[must_use]
+    pub fn attendees(mut self, category: &EmployeeCategory, count: u32) -> Self {
+        self.steps.push(Step::Join(category.clone(), count));
+        self
+    }
+
+    /// Removes `count` attendees titled `title` at this point of the meeting.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - Title of the attendee group.
+    /// * `count` - Number of attendees leaving.
+    #[must_use]
+    pub fn leave(mut self, title: &str, count: u32) -> Self {
+        self.steps.push(Step::Leave(title.to_string(), count));
+        self
+    }
+
+    /// Lets the meeting run for `duration`.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - Exact time that passes.
+    #[must_use]
+    pub fn run_for(mut self, duration: Duration) -> Self {
+        self.steps.push(Step::Run(duration));
+        self
+    }
+
+    /// Leaves the meeting running after the last step, so advancing the
+    /// returned clock adds to its cost.
+    #[must_use]
+    pub fn running(mut self) -> Self {
+        self.keep_running = true;
+        self
+    }
+
+    /// Replays the steps on a new meeting.
+    ///
+    /// # Returns
+    ///
+    /// The meeting and a handle to its clock.
+    #[must_use]
+    pub fn build(self) -> (Meeting, ManualClock) {
+        let clock = ManualClock::new();
+        let mut meeting = Meeting::with_clock(clock.clone());
+        meeting.start();
+        for step in self.steps {
+            match step {
+                Step::Join(category, count) => meeting.add_attendee(&category, count),
+                Step::Leave(title, count) => meeting.remove_attendee(&title, count),
+                Step::Run(duration) => clock.advance(duration),
+            }
+        }
+        if !self.keep_running {
+            meeting.stop();
+        }
+        (meeting, clock)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clones_share_the_time() {
+        let clock = ManualClock::new();
+        let handle = clock.clone();
+        let start = clock.now();
+        handle.advance(Duration::from_secs(5));
+        assert_eq!(clock.now() - start, Duration::from_secs(5));
+        assert_eq!(clock.elapsed(), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn fixture_bills_late_joiners_and_leavers() {
+        let (meeting, clock) = MeetingFixture::new()
+            .attendees(&engineer(), 1)
+            .attendees(&director(), 1)
+            .run_for(Duration::from_mins(10))
+            .leave("Director", 1)
+            .attendees(&guest(), 4)
+            .run_for(Duration::from_mins(5))
+            .build();
+        assert!(!meeting.is_running());
+        assert_eq!(meeting.duration(), Duration::from_mins(15));
+        assert_eq!(meeting.observer_count(), 4);
+        assert_cost_eq(meeting.total_cost(), 55.0, 1e-9);
+        clock.advance(Duration::from_mins(5));
+        assert_cost_eq(meeting.total_cost(), 55.0, 1e-9);
+    }
+
+    #[test]
+    #[should_panic(expected = "differs from expected 1")]
+    fn assert_cost_eq_reports_mismatch() {
+        assert_cost_eq(1.02, 1.0, CENT);
+    }
+
+    #[test]
+    #[should_panic(expected = "NaN")]
+    fn assert_cost_eq_rejects_nan() {
+        assert_cost_eq(f64::NAN, 1.0, CENT);
+    }
+}
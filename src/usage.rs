@@ -0,0 +1,245 @@
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
+
+use crate::meeting::Meeting;
+use crate::model::EmployeeCategory;
+
+/// Remembers when each category was last used so the category list can be
+/// kept short by evicting the least recently used entries.
+///
+/// Times are wall-clock timestamps, so they can be saved with the categories
+/// through [`CategoryUsage::record_in`] and restored with
+/// [`CategoryUsage::from_categories`]; eviction then follows the same order
+/// after a restart. Each touch is stamped strictly later than the previous
+/// one, so quick successive touches keep their order.
+#[derive(Debug, Clone, Default)]
+pub struct CategoryUsage {
+    latest: Option<SystemTime>,
+    last_used: HashMap<String, SystemTime>,
+}
+
+impl CategoryUsage {
+    /// Creates a tracker in which no category has been used yet.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a tracker from the last-used times saved with `categories`.
+    ///
+    /// ## Example
+    /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
+    /// use meeting_cost_tracker::{CategoryUsage, EmployeeCategory, Meeting};
+    /// let mut dev = EmployeeCategory::new("Dev", 100_000).unwrap();
+    /// dev.set_last_used(Some(UNIX_EPOCH + Duration::from_secs(60)));
+    /// let mut categories = vec![dev, EmployeeCategory::new("PM", 110_000).unwrap()];
+    /// let mut usage = CategoryUsage::from_categories(&categories);
+    /// let evicted = usage.evict(&mut categories, 1, &Meeting::new());
+    /// assert_eq!(evicted[0].title(), "PM");
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `categories` - Categories as loaded from storage.
+    ///
+    /// # Returns
+    ///
+    /// A tracker that knows every recorded [`EmployeeCategory::last_used`].
+    #[must_use]
+    pub fn from_categories(categories: &[EmployeeCategory]) -> Self {
+        let last_used: HashMap<String, SystemTime> = categories
+            .iter()
+            .filter_map(|c| Some((c.title().to_string(), c.last_used()?)))
+            .collect();
+        Self {
+            latest: last_used.values().max().copied(),
+            last_used,
+        }
+    }
+
+    /// Returns when the category titled `title` was last used, if known.
+    #[must_use]
+    pub fn last_used(&self, title: &str) -> Option<SystemTime> {
+        self.last_used.get(title).copied()
+    }
+
+    /// Writes the last-used times into `categories`, so that saving them
+    /// keeps the usage order.
+    ///
+    /// # Arguments
+    ///
+    /// * `categories` - Categories about to be saved.
+    ///
+    /// # Returns
+    ///
+    /// Nothing.
+    ///
+    /// # See Also
+    /// * [`CategoryUsage::from_categories`]
+    pub fn record_in(&self, categories: &mut [EmployeeCategory]) {
+        for cat in categories {
+            if let Some(at) = self.last_used(cat.title()) {
+                cat.set_last_used(Some(at));
+            }
+        }
+    }
+
+    /// Marks the category titled `title` as used just now.
+    ///
+    /// Call this when a category is created or attendees of it are added.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - Title of the category that was used.
+    ///
+    /// # Returns
+    ///
+    /// Nothing.
+    ///
+    /// # See Also
+    /// * [`CategoryUsage::evict`]
+    pub fn touch(&mut self, title: &str) {
+        let now = SystemTime::now();
+        let at = match self.latest {
+            Some(latest) if now <= latest => latest + Duration::from_nanos(1),
+            _ => now,
+        };
+        self.latest = Some(at);
+        self.last_used.insert(title.to_string(), at);
+    }
+
+    /// Removes least recently used categories until at most `max` remain.
+    ///
+    /// Categories with attendees in `meeting` are never evicted, so the list
+    /// may stay above `max` when every remaining category is in use.
+    /// Categories that were never touched count as the oldest, and ties keep
+    /// the earlier entry of the list as the older one.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{CategoryUsage, EmployeeCategory, Meeting};
+    /// let mut categories = vec![
+    ///     EmployeeCategory::new("Dev", 100_000).unwrap(),
+    ///     EmployeeCategory::new("PM", 110_000).unwrap(),
+    /// ];
+    /// let mut usage = CategoryUsage::new();
+    /// usage.touch("PM");
+    /// let evicted = usage.evict(&mut categories, 1, &Meeting::new());
+    /// assert_eq!(evicted[0].title(), "Dev");
+    /// assert_eq!(categories[0].title(), "PM");
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `categories` - Category list to shorten in place.
+    /// * `max` - Largest number of categories to keep.
+    /// * `meeting` - Meeting whose attendees protect their categories.
+    ///
+    /// # Returns
+    ///
+    /// The evicted categories, least recently used first.
+    ///
+    /// # See Also
+    /// * [`CategoryUsage::touch`]
+    pub fn evict(
+        &mut self,
+        categories: &mut Vec<EmployeeCategory>,
+        max: usize,
+        meeting: &Meeting,
+    ) -> Vec<EmployeeCategory> {
+        let excess = categories.len().saturating_sub(max);
+        let mut candidates: Vec<(Option<SystemTime>, usize)> = categories
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| meeting.attendee_count(c.title()).is_none())
+            .map(|(i, c)| (self.last_used(c.title()), i))
+            .collect();
+        candidates.sort_unstable();
+        let doomed: Vec<String> = candidates
+            .iter()
+            .take(excess)
+            .map(|&(_, i)| categories[i].title().to_string())
+            .collect();
+        let mut evicted = Vec::with_capacity(doomed.len());
+        for title in doomed {
+            if let Some(pos) = categories.iter().position(|c| c.title() == title) {
+                evicted.push(categories.remove(pos));
+            }
+            self.last_used.remove(&title);
+        }
+        evicted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn categories(titles: &[&str]) -> Vec<EmployeeCategory> {
+        titles
+            .iter()
+            .map(|t| EmployeeCategory::new(*t, 100_000).unwrap())
+            .collect()
+    }
+
+    fn titles(categories: &[EmployeeCategory]) -> Vec<&str> {
+        categories.iter().map(EmployeeCategory::title).collect()
+    }
+
+    #[test]
+    fn evicts_least_recently_used_first() {
+        let mut list = categories(&["a", "b", "c", "d"]);
+        let mut usage = CategoryUsage::new();
+        for title in ["c", "a", "d", "b"] {
+            usage.touch(title);
+        }
+        let evicted = usage.evict(&mut list, 2, &Meeting::new());
+        assert_eq!(titles(&evicted), vec!["c", "a"]);
+        assert_eq!(titles(&list), vec!["b", "d"]);
+    }
+
+    #[test]
+    fn never_evicts_categories_in_the_meeting() {
+        let mut list = categories(&["a", "b", "c"]);
+        let mut usage = CategoryUsage::new();
+        usage.touch("c");
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&list[0], 1);
+        meeting.add_attendee(&list[1], 1);
+
+        let evicted = usage.evict(&mut list, 1, &meeting);
+        assert_eq!(titles(&evicted), vec!["c"]);
+        assert_eq!(titles(&list), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn eviction_order_survives_a_reload() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("categories.toml");
+        let mut list = categories(&["a", "b", "c", "d"]);
+        let mut usage = CategoryUsage::new();
+        for title in ["c", "a", "d", "b"] {
+            usage.touch(title);
+        }
+        usage.record_in(&mut list);
+        crate::storage::save_categories(&path, &list).unwrap();
+
+        let mut reloaded = crate::storage::load_categories(&path).unwrap();
+        let mut usage = CategoryUsage::from_categories(&reloaded);
+        let evicted = usage.evict(&mut reloaded, 2, &Meeting::new());
+        assert_eq!(titles(&evicted), vec!["c", "a"]);
+        assert_eq!(titles(&reloaded), vec!["b", "d"]);
+
+        usage.touch("b");
+        assert!(usage.last_used("b") > usage.last_used("d"));
+    }
+
+    #[test]
+    fn within_limit_keeps_everything() {
+        let mut list = categories(&["a", "b"]);
+        let evicted = CategoryUsage::new().evict(&mut list, 2, &Meeting::new());
+        assert!(evicted.is_empty());
+        assert_eq!(list.len(), 2);
+    }
+}
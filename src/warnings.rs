@@ -0,0 +1,226 @@
+use serde::{Deserialize, Serialize};
+
+use crate::meeting::Meeting;
+use crate::model::EmployeeCategory;
+
+/// Limits above which a roster is considered suspicious.
+///
+/// Each limit is optional; `None` disables that check.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Thresholds {
+    /// Maximum burn rate in dollars per minute.
+    pub max_burn_rate_per_minute: Option<f64>,
+    /// Maximum number of attendees across all categories.
+    pub max_headcount: Option<u32>,
+}
+
+/// Result of checking a meeting's roster against [`Thresholds`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RosterWarnings {
+    /// Burn rate of the roster in dollars per minute.
+    pub burn_rate_per_minute: f64,
+    /// Total number of attendees in the roster.
+    pub headcount: u32,
+    /// Whether the burn rate exceeds [`Thresholds::max_burn_rate_per_minute`].
+    pub burn_rate_exceeded: bool,
+    /// Whether the headcount exceeds [`Thresholds::max_headcount`].
+    pub headcount_exceeded: bool,
+}
+
+impl RosterWarnings {
+    /// Checks the roster of `meeting` against `thresholds`.
+    ///
+    /// Limits are exclusive: a roster exactly at a limit does not trigger a
+    /// warning.
+    ///
+    /// ## Example
+    /// ```
+    /// use meeting_cost_tracker::{EmployeeCategory, Meeting, RosterWarnings, Thresholds};
+    /// let cat = EmployeeCategory::new("Engineer", 120_000).unwrap();
+    /// let mut meeting = Meeting::new();
+    /// meeting.add_attendee(&cat, 40);
+    /// let thresholds = Thresholds {
+    ///     max_burn_rate_per_minute: None,
+    ///     max_headcount: Some(10),
+    /// };
+    /// let warnings = RosterWarnings::evaluate(&meeting, &thresholds);
+    /// assert!(warnings.headcount_exceeded);
+    /// assert!(warnings.any());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `meeting` - Meeting whose roster is checked.
+    /// * `thresholds` - Limits to check against.
+    ///
+    /// # Returns
+    ///
+    /// The observed burn rate and headcount along with which limits were exceeded.
+    ///
+    /// # See Also
+    /// * [`Meeting::burn_rate_per_minute`]
+    /// * [`Meeting::total_attendees`]
+    /// * [`Meeting::roster_snapshot`]
+    #[must_use]
+    pub fn evaluate(meeting: &Meeting, thresholds: &Thresholds) -> Self {
+        let burn_rate_per_minute = meeting.burn_rate_per_minute();
+        let headcount = meeting.total_attendees();
+        Self {
+            burn_rate_per_minute,
+            headcount,
+            burn_rate_exceeded: thresholds
+                .max_burn_rate_per_minute
+                .is_some_and(|max| burn_rate_per_minute > max),
+            headcount_exceeded: thresholds.max_headcount.is_some_and(|max| headcount > max),
+        }
+    }
+
+    /// Returns `true` if any threshold was exceeded.
+    #[must_use]
+    pub fn any(&self) -> bool {
+        self.burn_rate_exceeded || self.headcount_exceeded
+    }
+}
+
+/// Finds categories whose salary is suspiciously far above the others, such
+/// as a salary typed with an extra zero.
+///
+/// A category is flagged when its salary exceeds `factor` times the median
+/// salary of `categories`. Observers have no salary and are neither flagged
+/// nor counted towards the median.
+///
+/// ## Example
+/// ```
+/// use meeting_cost_tracker::{flag_outlier_categories, EmployeeCategory};
+/// let categories = vec![
+///     EmployeeCategory::new("Engineer", 120_000).unwrap(),
+///     EmployeeCategory::new("Designer", 100_000).unwrap(),
+///     EmployeeCategory::new("Manager", 1_400_000).unwrap(),
+/// ];
+/// let outliers = flag_outlier_categories(&categories, 3.0);
+/// assert_eq!(outliers.len(), 1);
+/// assert_eq!(outliers[0].title(), "Manager");
+/// ```
+///
+/// # Arguments
+///
+/// * `categories` - Categories to check.
+/// * `factor` - How many times the median a salary may be before it is
+///   flagged.
+///
+/// # Returns
+///
+/// The flagged categories in their original order.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn flag_outlier_categories(
+    categories: &[EmployeeCategory],
+    factor: f64,
+) -> Vec<&EmployeeCategory> {
+    let mut salaries: Vec<u64> = categories
+        .iter()
+        .filter(|c| !c.is_observer())
+        .map(EmployeeCategory::salary)
+        .collect();
+    if salaries.is_empty() {
+        return Vec::new();
+    }
+    salaries.sort_unstable();
+    let mid = salaries.len() / 2;
+    let median = if salaries.len().is_multiple_of(2) {
+        f64::midpoint(salaries[mid - 1] as f64, salaries[mid] as f64)
+    } else {
+        salaries[mid] as f64
+    };
+    categories
+        .iter()
+        .filter(|c| !c.is_observer() && c.salary() as f64 > factor * median)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_cost_eq;
+
+    fn meeting_with(count: u32) -> Meeting {
+        // 120,000 over 2,000 hours is $1 per minute per attendee.
+        let cat = EmployeeCategory::new("dev", 120_000).unwrap();
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&cat, count);
+        meeting
+    }
+
+    #[test]
+    fn no_thresholds_never_warn() {
+        let warnings = RosterWarnings::evaluate(&meeting_with(100), &Thresholds::default());
+        assert!(!warnings.any());
+        assert_eq!(warnings.headcount, 100);
+    }
+
+    #[test]
+    fn burn_rate_threshold_is_exclusive() {
+        let thresholds = Thresholds {
+            max_burn_rate_per_minute: Some(3.0),
+            max_headcount: None,
+        };
+        assert!(!RosterWarnings::evaluate(&meeting_with(3), &thresholds).any());
+        let warnings = RosterWarnings::evaluate(&meeting_with(4), &thresholds);
+        assert!(warnings.burn_rate_exceeded);
+        assert!(!warnings.headcount_exceeded);
+        assert_cost_eq(warnings.burn_rate_per_minute, 4.0, 1e-9);
+    }
+
+    #[test]
+    fn headcount_threshold_is_exclusive() {
+        let thresholds = Thresholds {
+            max_burn_rate_per_minute: None,
+            max_headcount: Some(2),
+        };
+        assert!(!RosterWarnings::evaluate(&meeting_with(2), &thresholds).any());
+        let warnings = RosterWarnings::evaluate(&meeting_with(40), &thresholds);
+        assert!(warnings.headcount_exceeded);
+        assert!(!warnings.burn_rate_exceeded);
+    }
+
+    fn categories(salaries: &[u64]) -> Vec<EmployeeCategory> {
+        salaries
+            .iter()
+            .enumerate()
+            .map(|(i, &salary)| EmployeeCategory::new(format!("c{i}"), salary).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn clear_outlier_is_flagged() {
+        let cats = categories(&[90_000, 1_200_000, 110_000, 100_000]);
+        let outliers = flag_outlier_categories(&cats, 3.0);
+        assert_eq!(outliers, vec![&cats[1]]);
+        assert!(flag_outlier_categories(&cats, 15.0).is_empty());
+    }
+
+    #[test]
+    fn uniform_salaries_have_no_outliers() {
+        let cats = categories(&[100_000, 100_000, 100_000]);
+        assert!(flag_outlier_categories(&cats, 1.0).is_empty());
+        assert!(flag_outlier_categories(&[], 3.0).is_empty());
+    }
+
+    #[test]
+    fn observers_are_ignored() {
+        let mut cats = categories(&[100_000, 120_000]);
+        cats.push(EmployeeCategory::observer("guest").unwrap());
+        cats.push(EmployeeCategory::observer("customer").unwrap());
+        assert!(flag_outlier_categories(&cats, 3.0).is_empty());
+    }
+
+    #[test]
+    fn empty_meeting_is_quiet() {
+        let thresholds = Thresholds {
+            max_burn_rate_per_minute: Some(0.0),
+            max_headcount: Some(0),
+        };
+        assert!(!RosterWarnings::evaluate(&Meeting::new(), &thresholds).any());
+    }
+}
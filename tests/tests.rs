@@ -3,9 +3,10 @@ mod tests {
     use std::path::PathBuf;
     use std::time::Duration;
 
+    use meeting_cost_tracker::testing::{assert_cost_eq, engineer, MeetingFixture, CENT};
     use meeting_cost_tracker::{
         load_attendees, load_categories, save_attendees, save_categories, AttendeeInfo,
-        EmployeeCategory, Meeting,
+        EmployeeCategory, Meeting, MeetingError,
     };
 
     #[test]
@@ -22,29 +23,38 @@ mod tests {
 
     #[test]
     fn test_meeting_cost_accumulation() {
-        let cat = EmployeeCategory::new("Dev", 120_000).unwrap();
-        let mut meeting = Meeting::new();
-        meeting.add_attendee(&cat, 2);
-        meeting.start();
-        std::thread::sleep(Duration::from_millis(50));
-        meeting.stop();
+        let (meeting, clock) = MeetingFixture::new()
+            .attendees(&engineer(), 2)
+            .run_for(Duration::from_secs(30))
+            .running()
+            .build();
+        assert_cost_eq(meeting.total_cost(), 1.0, 1e-9);
+        clock.advance(Duration::from_mins(1));
+        assert_cost_eq(meeting.total_cost(), 3.0, 1e-9);
+    }
 
-        let cost = meeting.total_cost();
-        assert!(cost > 0.0);
+    #[test]
+    fn test_manual_clock_cost() {
+        let (meeting, _clock) = MeetingFixture::new()
+            .attendees(&engineer(), 3)
+            .run_for(Duration::from_mins(20))
+            .build();
+        assert_eq!(meeting.duration(), Duration::from_mins(20));
+        assert_eq!(meeting.total_cost_cents(), 6_000);
+        assert_cost_eq(meeting.total_cost(), 60.0, CENT);
     }
 
     #[test]
-    #[allow(clippy::float_cmp)]
     fn test_meeting_reset() {
-        let cat = EmployeeCategory::new("Analyst", 90_000).unwrap();
-        let mut meeting = Meeting::new();
-        meeting.add_attendee(&cat, 1);
-        meeting.start();
-        std::thread::sleep(Duration::from_millis(20));
-        meeting.stop();
+        let analyst = EmployeeCategory::new("Analyst", 90_000).unwrap();
+        let (mut meeting, _clock) = MeetingFixture::new()
+            .attendees(&analyst, 1)
+            .run_for(Duration::from_mins(20))
+            .build();
+        assert_cost_eq(meeting.total_cost(), 15.0, CENT);
         meeting.reset();
 
-        assert_eq!(meeting.total_cost(), 0.0);
+        assert_cost_eq(meeting.total_cost(), 0.0, 0.0);
     }
 
     #[test]
@@ -78,6 +88,9 @@ mod tests {
         let attendees = vec![AttendeeInfo {
             title: "Dev".into(),
             count: 2,
+            name: None,
+            salary: None,
+            attention: 1.0,
         }];
         save_attendees(tmp.path(), &attendees).unwrap();
         let loaded = load_attendees(tmp.path()).unwrap();
@@ -100,4 +113,119 @@ mod tests {
         meeting.add_attendee(&cat, 4);
         assert_eq!(meeting.attendee_count(cat.title()), Some(4));
     }
+
+    #[test]
+    fn test_try_remove_attendee_reports_errors() {
+        let cat = EmployeeCategory::new("Dev", 60_000).unwrap();
+        let mut meeting = Meeting::new();
+        meeting.add_attendee(&cat, 2);
+        assert_eq!(meeting.try_remove_attendee("Dev", 3), Ok(2));
+        assert_eq!(
+            meeting.try_remove_attendee("Dev", 1),
+            Err(MeetingError::AttendeeNotFound("Dev".into()))
+        );
+    }
+
+    #[cfg(feature = "http")]
+    fn http(addr: std::net::SocketAddr, request: &str) -> (String, serde_json::Value) {
+        use std::io::{Read, Write};
+        let mut stream = std::net::TcpStream::connect(addr).unwrap();
+        stream.write_all(request.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let status_line = head.lines().next().unwrap().to_string();
+        (status_line, serde_json::from_str(body).unwrap())
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn test_daemon_status_and_control() {
+        let meeting = meeting_cost_tracker::SharedMeeting::default();
+        meeting.add_attendee(&EmployeeCategory::new("Dev", 120_000).unwrap(), 3);
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let served = meeting.clone();
+        std::thread::spawn(move || meeting_cost_tracker::serve(&listener, &served));
+
+        let (line, status) = http(addr, "GET /status HTTP/1.1\r\nHost: x\r\n\r\n");
+        assert_eq!(line, "HTTP/1.1 200 OK");
+        assert_eq!(status["running"], false);
+        assert_eq!(status["headcount"], 3);
+        assert!(status["started_at"].is_null());
+
+        let control = |action: &str| {
+            let body = format!("{{\"action\":\"{action}\"}}");
+            http(
+                addr,
+                &format!(
+                    "POST /control HTTP/1.1\r\nContent-Length: {}\r\n\r\n{body}",
+                    body.len()
+                ),
+            )
+        };
+        let (line, status) = control("start");
+        assert_eq!(line, "HTTP/1.1 200 OK");
+        assert_eq!(status["running"], true);
+        assert!(meeting.is_running());
+
+        let (_, status) = control("stop");
+        assert_eq!(status["running"], false);
+        assert!(status["started_at"]["secs_since_epoch"].is_u64());
+        assert!(status["ended_at"]["secs_since_epoch"].is_u64());
+
+        let (_, status) = control("reset");
+        assert_eq!(status["headcount"], 0);
+        assert_eq!(meeting.with(|m| m.total_attendees()), 0);
+
+        let (line, status) = control("explode");
+        assert_eq!(line, "HTTP/1.1 400 Bad Request");
+        assert!(status["error"].is_string());
+    }
+
+    #[test]
+    fn test_concurrent_readers_see_monotonic_cost() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::{Arc, RwLock};
+        use std::thread;
+
+        let meeting = Arc::new(RwLock::new(Meeting::new()));
+        meeting.write().unwrap().add_attendee(&engineer(), 1);
+        meeting.write().unwrap().start();
+        let done = Arc::new(AtomicBool::new(false));
+
+        let readers: Vec<_> = (0..4)
+            .map(|_| {
+                let meeting = Arc::clone(&meeting);
+                let done = Arc::clone(&done);
+                thread::spawn(move || {
+                    let mut last = 0.0;
+                    let mut reads = 0_u32;
+                    while !done.load(Ordering::Acquire) || reads == 0 {
+                        let cost = meeting.read().unwrap().total_cost();
+                        assert!(cost >= last, "cost fell from {last} to {cost}");
+                        last = cost;
+                        reads += 1;
+                    }
+                    last
+                })
+            })
+            .collect();
+
+        let manager = EmployeeCategory::new("Manager", 240_000).unwrap();
+        for round in 0..500 {
+            let mut meeting = meeting.write().unwrap();
+            if round % 3 == 2 {
+                meeting.remove_attendee("Manager", 1);
+            } else {
+                meeting.add_attendee(&manager, 1);
+            }
+        }
+        done.store(true, Ordering::Release);
+
+        for reader in readers {
+            let last = reader.join().unwrap();
+            assert!(meeting.read().unwrap().total_cost() >= last);
+        }
+    }
 }